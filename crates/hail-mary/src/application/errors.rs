@@ -39,6 +39,9 @@ pub enum ApplicationError {
     #[error("Spec directory not found: {0}")]
     SpecNotFound(String),
 
+    #[error("Ambiguous spec name '{0}': matches {candidates}", candidates = .1.join(", "))]
+    AmbiguousSpecName(String, Vec<String>),
+
     #[error("Invalid spec directory: {0}")]
     InvalidSpecDirectory(String),
 
@@ -47,6 +50,30 @@ pub enum ApplicationError {
 
     #[error("Process launch error: {0}")]
     ProcessLaunchError(String),
+
+    #[error("Steering backup not found: {0}")]
+    BackupNotFound(String),
+
+    #[error("Steering type already exists: {0}")]
+    SteeringTypeAlreadyExists(String),
+
+    #[error("Steering type not found: {0}")]
+    SteeringTypeNotFound(String),
+
+    #[error("Steering type {0} is defined in user config at {1}; change it there")]
+    SteeringTypeInUserConfig(String, String),
+
+    #[error("Invalid steering type: {0}")]
+    InvalidSteeringType(String),
+
+    #[error("Steering lint found {0} issue(s)")]
+    SteeringLintFailed(usize),
+
+    #[error("config.toml has {0} problem(s)")]
+    ConfigValidationFailed(usize),
+
+    #[error("{0} specification(s) could not be restored from archive")]
+    UnarchiveFailed(usize),
 }
 
 impl ApplicationError {
@@ -87,6 +114,21 @@ mod tests {
         assert_eq!(error.to_string(), "Invalid spec name: Invalid_Name");
     }
 
+    #[test]
+    fn test_ambiguous_spec_name_error() {
+        let error = ApplicationError::AmbiguousSpecName(
+            "login".to_string(),
+            vec![
+                "2025-01-01-login-a".to_string(),
+                "2025-01-02-login-b".to_string(),
+            ],
+        );
+        assert_eq!(
+            error.to_string(),
+            "Ambiguous spec name 'login': matches 2025-01-01-login-a, 2025-01-02-login-b"
+        );
+    }
+
     #[test]
     fn test_database_error() {
         let error = ApplicationError::database_error("Connection failed");
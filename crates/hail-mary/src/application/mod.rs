@@ -2,5 +2,5 @@ pub mod errors;
 pub mod repositories;
 pub mod use_cases;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "test-util"))]
 pub mod test_helpers;
@@ -1,5 +1,11 @@
 use crate::application::errors::ApplicationError;
-use crate::domain::value_objects::steering::{SpecConfig, SteeringBackupConfig, SteeringConfig};
+use crate::domain::value_objects::agent::AgentConfig;
+use crate::domain::value_objects::config_diagnostic::ConfigFileDiagnostics;
+use crate::domain::value_objects::config_source::EffectiveSetting;
+use crate::domain::value_objects::file_change::FileChange;
+use crate::domain::value_objects::steering::{
+    SpecConfig, SteeringBackupConfig, SteeringConfig, SteeringType,
+};
 
 /// Repository interface for managing project configuration
 pub trait ConfigRepositoryInterface {
@@ -10,17 +16,48 @@ pub trait ConfigRepositoryInterface {
     fn load_steering_backup_config(&self) -> Result<SteeringBackupConfig, ApplicationError>;
 
     /// Ensure steering configuration exists, adding defaults if missing
-    fn ensure_steering_config(&self) -> Result<(), ApplicationError>;
+    fn ensure_steering_config(&self) -> Result<Vec<FileChange>, ApplicationError>;
 
     /// Ensure steering backup configuration exists, adding defaults if missing
-    fn ensure_steering_backup_config(&self) -> Result<(), ApplicationError>;
+    fn ensure_steering_backup_config(&self) -> Result<Vec<FileChange>, ApplicationError>;
 
     /// Ensure allowed_operations exists for all steering types, adding defaults if missing
-    fn ensure_allowed_operations(&self) -> Result<(), ApplicationError>;
+    fn ensure_allowed_operations(&self) -> Result<Vec<FileChange>, ApplicationError>;
 
     /// Load only the spec configuration section
     fn load_spec_config(&self) -> Result<SpecConfig, ApplicationError>;
 
     /// Ensure spec configuration exists, adding defaults if missing
-    fn ensure_spec_config(&self) -> Result<(), ApplicationError>;
+    fn ensure_spec_config(&self) -> Result<Vec<FileChange>, ApplicationError>;
+
+    /// Load the agent section (Claude Code when absent)
+    fn load_agent_config(&self) -> Result<AgentConfig, ApplicationError>;
+
+    /// Append a `[[steering.types]]` entry to the project config, leaving the rest of
+    /// the file as written; fails when a config layer already defines the name
+    fn add_steering_type(&self, steering_type: &SteeringType) -> Result<(), ApplicationError>;
+
+    /// Replace the steering type called `name` (possibly renaming it), rewriting only
+    /// the keys whose values change. A type from the user-level config is copied into
+    /// the project config, where it takes precedence; it cannot be renamed.
+    fn update_steering_type(
+        &self,
+        name: &str,
+        steering_type: &SteeringType,
+    ) -> Result<(), ApplicationError>;
+
+    /// Fails unless `remove_steering_type` can remove `name`: it must be defined in
+    /// the project config and not in the user-level config
+    fn check_steering_type_removable(&self, name: &str) -> Result<(), ApplicationError>;
+
+    /// Remove the steering type called `name` from the project config
+    fn remove_steering_type(&self, name: &str) -> Result<(), ApplicationError>;
+
+    /// Check every config file (user-level first, then project) against the schema,
+    /// reporting each problem with its location; absent files are skipped
+    fn validate_config(&self) -> Result<Vec<ConfigFileDiagnostics>, ApplicationError>;
+
+    /// Every setting of the merged user-level and project config, defaults included,
+    /// with the layer each value comes from
+    fn load_effective_config(&self) -> Result<Vec<EffectiveSetting>, ApplicationError>;
 }
@@ -0,0 +1,9 @@
+use crate::application::errors::ApplicationError;
+use crate::domain::value_objects::launch_command::LaunchCommand;
+
+/// Runs an agent CLI session for a resolved command line
+pub trait LauncherInterface {
+    /// Start the session. The real launcher replaces the current process on Unix,
+    /// so a successful call may never return.
+    fn launch(&self, command: &LaunchCommand) -> Result<(), ApplicationError>;
+}
@@ -1,8 +1,14 @@
 pub mod config_repository;
+pub mod launcher;
+pub mod overridden_config_repository;
+pub mod spec_prompt;
 pub mod spec_repository;
 pub mod steering_repository;
 
 // Re-export traits and types for easier access
 pub use config_repository::ConfigRepositoryInterface;
-pub use spec_repository::SpecRepositoryInterface;
-pub use steering_repository::{BackupInfo, SteeringRepositoryInterface};
+pub use launcher::LauncherInterface;
+pub use overridden_config_repository::OverriddenConfigRepository;
+pub use spec_prompt::{SpecPromptInterface, SpecSelectionResult};
+pub use spec_repository::{CollisionPolicy, RestoredSpec, SpecFileInfo, SpecRepositoryInterface};
+pub use steering_repository::{BackupInfo, SteeringFileScaffold, SteeringRepositoryInterface};
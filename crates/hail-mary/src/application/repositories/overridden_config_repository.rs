@@ -0,0 +1,251 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::ConfigRepositoryInterface;
+use crate::domain::value_objects::agent::AgentConfig;
+use crate::domain::value_objects::config_diagnostic::ConfigFileDiagnostics;
+use crate::domain::value_objects::config_override::ConfigOverrides;
+use crate::domain::value_objects::config_source::EffectiveSetting;
+use crate::domain::value_objects::file_change::FileChange;
+use crate::domain::value_objects::steering::{
+    SpecConfig, SteeringBackupConfig, SteeringConfig, SteeringType,
+};
+
+/// Applies `HAIL_MARY_*` / `--config` overrides to the loaders of another config
+/// repository; everything that writes config.toml goes to the inner repository untouched
+pub struct OverriddenConfigRepository<'a> {
+    inner: &'a dyn ConfigRepositoryInterface,
+    overrides: ConfigOverrides,
+}
+
+impl<'a> OverriddenConfigRepository<'a> {
+    pub fn new(inner: &'a dyn ConfigRepositoryInterface, overrides: ConfigOverrides) -> Self {
+        Self { inner, overrides }
+    }
+}
+
+impl ConfigRepositoryInterface for OverriddenConfigRepository<'_> {
+    fn load_steering_config(&self) -> Result<SteeringConfig, ApplicationError> {
+        let mut config = self.inner.load_steering_config()?;
+        self.overrides
+            .apply_to_steering(&mut config)
+            .map_err(ApplicationError::ConfigurationError)?;
+        Ok(config)
+    }
+
+    fn load_steering_backup_config(&self) -> Result<SteeringBackupConfig, ApplicationError> {
+        let mut config = self.inner.load_steering_backup_config()?;
+        self.overrides.apply_to_backup(&mut config);
+        Ok(config)
+    }
+
+    fn ensure_steering_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        self.inner.ensure_steering_config()
+    }
+
+    fn ensure_steering_backup_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        self.inner.ensure_steering_backup_config()
+    }
+
+    fn ensure_allowed_operations(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        self.inner.ensure_allowed_operations()
+    }
+
+    fn load_spec_config(&self) -> Result<SpecConfig, ApplicationError> {
+        let mut config = self.inner.load_spec_config()?;
+        self.overrides.apply_to_spec(&mut config);
+        Ok(config)
+    }
+
+    fn ensure_spec_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        self.inner.ensure_spec_config()
+    }
+
+    fn load_agent_config(&self) -> Result<AgentConfig, ApplicationError> {
+        let mut config = self.inner.load_agent_config()?;
+        self.overrides.apply_to_agent(&mut config);
+        Ok(config)
+    }
+
+    fn add_steering_type(&self, steering_type: &SteeringType) -> Result<(), ApplicationError> {
+        self.inner.add_steering_type(steering_type)
+    }
+
+    fn update_steering_type(
+        &self,
+        name: &str,
+        steering_type: &SteeringType,
+    ) -> Result<(), ApplicationError> {
+        self.inner.update_steering_type(name, steering_type)
+    }
+
+    fn check_steering_type_removable(&self, name: &str) -> Result<(), ApplicationError> {
+        self.inner.check_steering_type_removable(name)
+    }
+
+    fn remove_steering_type(&self, name: &str) -> Result<(), ApplicationError> {
+        self.inner.remove_steering_type(name)
+    }
+
+    fn validate_config(&self) -> Result<Vec<ConfigFileDiagnostics>, ApplicationError> {
+        self.inner.validate_config()
+    }
+
+    fn load_effective_config(&self) -> Result<Vec<EffectiveSetting>, ApplicationError> {
+        // Fails the same way the loaders do for overrides of unknown steering types
+        self.load_steering_config()?;
+
+        let mut settings = self.inner.load_effective_config()?;
+        for config_override in self.overrides.iter() {
+            let setting = EffectiveSetting {
+                key: config_override.key.clone(),
+                value: config_override.toml_value(),
+                source: config_override.source,
+            };
+            match settings.iter_mut().find(|s| s.key == setting.key) {
+                Some(existing) => *existing = setting,
+                None => settings.push(setting),
+            }
+        }
+        Ok(settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockConfigRepository;
+    use crate::domain::value_objects::agent::AgentBackend;
+    use crate::domain::value_objects::config_source::ConfigSource;
+
+    fn overrides(env: &[(&str, &str)], assignments: &[&str]) -> ConfigOverrides {
+        ConfigOverrides::new(
+            env.iter()
+                .map(|(name, value)| (name.to_string(), value.to_string())),
+            &assignments
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_loaders_apply_overrides() {
+        let inner = MockConfigRepository::new();
+        let repo = OverriddenConfigRepository::new(
+            &inner,
+            overrides(
+                &[("HAIL_MARY_SPEC__LANG", "en")],
+                &[
+                    "steering.backup.max=3",
+                    "steering.types.product.include_in_prompt=false",
+                    "agent.backend=gemini",
+                ],
+            ),
+        );
+
+        assert_eq!(repo.load_spec_config().unwrap().lang, "en");
+        assert_eq!(repo.load_steering_backup_config().unwrap().max, 3);
+
+        let steering = repo.load_steering_config().unwrap();
+        assert_eq!(steering.backup.max, 3);
+        assert!(!steering.types[0].include_in_prompt);
+        assert!(steering.types[1].include_in_prompt);
+
+        assert_eq!(
+            repo.load_agent_config().unwrap().backend,
+            AgentBackend::Gemini
+        );
+
+        // The wrapped repository is not modified
+        assert_eq!(inner.load_spec_config().unwrap().lang, "ja");
+        assert_eq!(inner.load_steering_backup_config().unwrap().max, 10);
+    }
+
+    #[test]
+    fn test_no_overrides_is_transparent() {
+        let inner = MockConfigRepository::new();
+        let repo = OverriddenConfigRepository::new(&inner, ConfigOverrides::default());
+
+        assert_eq!(
+            repo.load_steering_config().unwrap(),
+            inner.load_steering_config().unwrap()
+        );
+        assert_eq!(
+            repo.load_spec_config().unwrap().lang,
+            inner.load_spec_config().unwrap().lang
+        );
+    }
+
+    #[test]
+    fn test_override_of_unknown_steering_type_fails() {
+        let inner = MockConfigRepository::new();
+        let repo = OverriddenConfigRepository::new(
+            &inner,
+            overrides(&[], &["steering.types.security.purpose=Policies"]),
+        );
+
+        assert!(matches!(
+            repo.load_steering_config(),
+            Err(ApplicationError::ConfigurationError(_))
+        ));
+        // Other loaders are unaffected
+        assert!(repo.load_spec_config().is_ok());
+    }
+
+    #[test]
+    fn test_effective_config_reports_override_source() {
+        let inner = MockConfigRepository::new();
+        inner.set_effective_config(vec![EffectiveSetting {
+            key: "spec.lang".to_string(),
+            value: "\"ja\"".to_string(),
+            source: ConfigSource::Project,
+        }]);
+        let repo = OverriddenConfigRepository::new(
+            &inner,
+            overrides(
+                &[("HAIL_MARY_SPEC__LANG", "en")],
+                &["agent.command=/opt/bin/claude"],
+            ),
+        );
+
+        assert_eq!(
+            repo.load_effective_config().unwrap(),
+            vec![
+                EffectiveSetting {
+                    key: "spec.lang".to_string(),
+                    value: "\"en\"".to_string(),
+                    source: ConfigSource::Environment,
+                },
+                EffectiveSetting {
+                    key: "agent.command".to_string(),
+                    value: "\"/opt/bin/claude\"".to_string(),
+                    source: ConfigSource::CommandLine,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_writes_go_to_inner_repository() {
+        let inner = MockConfigRepository::new();
+        let repo = OverriddenConfigRepository::new(
+            &inner,
+            overrides(&[], &["steering.types.tech.include_in_prompt=false"]),
+        );
+
+        repo.remove_steering_type("structure").unwrap();
+
+        let names: Vec<_> = inner
+            .load_steering_config()
+            .unwrap()
+            .types
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(names, vec!["product", "tech"]);
+        assert!(
+            inner.load_steering_config().unwrap().types[1].include_in_prompt,
+            "overrides must not be persisted"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SpecRepositoryInterface;
+
+/// What the user chose in the spec selector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecSelectionResult {
+    SingleSpec(String),
+    Pbi(String),
+    Sbi(String, String), // (pbi_name, sbi_name)
+    CreateNew,
+    CreateNewSbi(String), // pbi_name
+    NoSpec,
+    Cancelled,
+}
+
+/// User interaction needed to choose or name a spec before launching a session
+pub trait SpecPromptInterface {
+    /// Let the user pick a spec, an SBI, or one of the create/no-spec options
+    fn select_spec(
+        &self,
+        spec_repo: &dyn SpecRepositoryInterface,
+    ) -> Result<SpecSelectionResult, ApplicationError>;
+
+    /// Ask for the name of a new spec
+    fn ask_spec_name(&self) -> Result<String, ApplicationError>;
+
+    /// Ask for the name of a new SBI
+    fn ask_sbi_name(&self) -> Result<String, ApplicationError>;
+}
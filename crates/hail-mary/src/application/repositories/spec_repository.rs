@@ -1,5 +1,36 @@
 use crate::application::errors::ApplicationError;
 use std::path::PathBuf;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone)]
+pub struct SpecFileInfo {
+    pub name: String,
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+/// An archived spec moved back into `.kiro/specs`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoredSpec {
+    /// Name the spec was restored under
+    pub name: String,
+    /// Archive name of the active spec it replaced (Overwrite only)
+    pub replaced: Option<String>,
+}
+
+/// How to handle an existing destination when moving a spec between
+/// `.kiro/specs` and `.kiro/archive`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollisionPolicy {
+    /// Refuse to move the spec and report the collision
+    #[default]
+    Fail,
+    /// Replace the existing destination directory; an active spec replaced
+    /// by an unarchived one is moved to the archive rather than deleted
+    Overwrite,
+    /// Keep both by moving the spec under a numbered name (`name-2`, `name-3`, ...)
+    KeepBoth,
+}
 
 /// Repository interface for managing specifications
 pub trait SpecRepositoryInterface {
@@ -11,7 +42,36 @@ pub trait SpecRepositoryInterface {
     fn list_spec_directories(&self) -> Result<Vec<(String, bool)>, ApplicationError>;
 
     /// Mark a specification as complete by moving it to archive
-    fn mark_spec_complete(&self, name: &str) -> Result<(), ApplicationError>;
+    /// Returns the name the spec was archived under
+    fn mark_spec_complete(
+        &self,
+        name: &str,
+        policy: CollisionPolicy,
+    ) -> Result<String, ApplicationError>;
+
+    /// Move an archived specification back into the specs directory
+    fn unarchive_spec(
+        &self,
+        name: &str,
+        policy: CollisionPolicy,
+    ) -> Result<RestoredSpec, ApplicationError>;
+
+    /// Rename an active specification directory
+    fn rename_spec(&self, name: &str, new_name: &str) -> Result<(), ApplicationError>;
+
+    /// Delete an active specification directory and all of its contents
+    fn delete_spec(&self, name: &str) -> Result<(), ApplicationError>;
+
+    /// List the files directly inside a specification directory, sorted by name
+    /// (`name` may be `pbi/sbi` to address an SBI)
+    fn list_spec_files(&self, name: &str) -> Result<Vec<SpecFileInfo>, ApplicationError>;
+
+    /// Read a file inside a specification directory, returning None if it doesn't exist
+    fn read_spec_file(
+        &self,
+        name: &str,
+        file_name: &str,
+    ) -> Result<Option<String>, ApplicationError>;
 
     /// Get the path to a specification directory
     fn get_spec_path(&self, name: &str) -> Result<PathBuf, ApplicationError>;
@@ -33,7 +93,7 @@ pub trait SpecRepositoryInterface {
         lang: &str,
     ) -> Result<(), ApplicationError>;
 
-    /// Ensure SBI has tasks.md and memo.md files (used when selecting existing SBI)
+    /// Ensure SBI has every file of the SBI template set (used when selecting existing SBI)
     fn ensure_sbi_files(
         &self,
         pbi_name: &str,
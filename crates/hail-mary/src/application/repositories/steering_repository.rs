@@ -1,6 +1,10 @@
 use crate::application::errors::ApplicationError;
+use crate::domain::value_objects::deployment::DeploymentReport;
+use crate::domain::value_objects::file_change::FileChange;
+use crate::domain::value_objects::slash_command::SlashCommand;
 use crate::domain::value_objects::steering::{Steering, SteeringConfig};
-use std::path::PathBuf;
+use crate::domain::value_objects::steering_backup::{BackupManifest, BackupReason};
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 #[derive(Debug, Clone)]
@@ -10,13 +14,25 @@ pub struct BackupInfo {
     pub path: PathBuf,
 }
 
+/// A steering file considered by `create_steering_files`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteeringFileScaffold {
+    /// Path relative to the steering directory
+    pub file: PathBuf,
+    /// false when the file already existed and was left untouched
+    pub created: bool,
+}
+
 /// Repository interface for managing steering system
 pub trait SteeringRepositoryInterface {
     /// Initialize steering system directories
-    fn initialize_steering(&self) -> Result<(), ApplicationError>;
+    fn initialize_steering(&self) -> Result<Vec<FileChange>, ApplicationError>;
 
-    /// Create steering files from configuration
-    fn create_steering_files(&self, config: &SteeringConfig) -> Result<(), ApplicationError>;
+    /// Create missing steering files for every configured type, never overwriting
+    fn create_steering_files(
+        &self,
+        config: &SteeringConfig,
+    ) -> Result<Vec<SteeringFileScaffold>, ApplicationError>;
 
     /// List all steering markdown files (excluding backup/ and draft/)
     fn list_steering_files(&self) -> Result<Vec<PathBuf>, ApplicationError>;
@@ -24,12 +40,22 @@ pub trait SteeringRepositoryInterface {
     /// Get the path for a specific steering file
     fn get_steering_path(&self, name: &str) -> Result<PathBuf, ApplicationError>;
 
-    /// Create a backup of steering files
+    /// Create a backup of steering files and record its manifest.
+    ///
+    /// If a backup called `name` already exists, a `-2`, `-3`, ... suffix is
+    /// appended; the returned manifest carries the name actually used.
     fn create_steering_backup(
         &self,
-        timestamp: &str,
+        name: &str,
         files: &[PathBuf],
-    ) -> Result<(), ApplicationError>;
+        reason: BackupReason,
+    ) -> Result<BackupManifest, ApplicationError>;
+
+    /// Load the manifest of a backup, or None for backups created before manifests existed
+    fn load_backup_manifest(
+        &self,
+        backup: &str,
+    ) -> Result<Option<BackupManifest>, ApplicationError>;
 
     /// List all existing steering backups sorted by creation time (oldest first)
     fn list_steering_backups(&self) -> Result<Vec<BackupInfo>, ApplicationError>;
@@ -37,11 +63,31 @@ pub trait SteeringRepositoryInterface {
     /// Delete the oldest steering backups
     fn delete_oldest_steering_backups(&self, count: usize) -> Result<(), ApplicationError>;
 
-    /// Deploy embedded slash command markdown files
-    fn deploy_slash_commands(&self) -> Result<(), ApplicationError>;
+    /// List the files stored in a backup (relative paths, sorted)
+    fn list_backup_files(&self, backup: &str) -> Result<Vec<PathBuf>, ApplicationError>;
+
+    /// Read a file stored in a backup
+    fn read_backup_file(&self, backup: &str, file: &Path) -> Result<String, ApplicationError>;
+
+    /// Read a current steering file, or None if it does not exist
+    fn read_steering_file(&self, file: &Path) -> Result<Option<String>, ApplicationError>;
+
+    /// Write (create or replace) a current steering file
+    fn write_steering_file(&self, file: &Path, content: &str) -> Result<(), ApplicationError>;
+
+    /// Delete a current steering file, returning whether it existed
+    fn remove_steering_file(&self, file: &Path) -> Result<bool, ApplicationError>;
+
+    /// The slash commands to deploy: the embedded ones overlaid with `.kiro/commands/`
+    fn list_slash_commands(&self) -> Result<Vec<SlashCommand>, ApplicationError>;
+
+    /// Deploy the slash commands from `list_slash_commands` and the embedded skills,
+    /// never overwriting or removing files that were edited locally or not deployed
+    /// by hail-mary
+    fn deploy_claude_resources(&self) -> Result<DeploymentReport, ApplicationError>;
 
     /// Update .gitignore file with necessary entries
-    fn update_gitignore(&self) -> Result<(), ApplicationError>;
+    fn update_gitignore(&self) -> Result<Vec<FileChange>, ApplicationError>;
 
     /// Check if project exists
     fn exists(&self) -> Result<bool, ApplicationError>;
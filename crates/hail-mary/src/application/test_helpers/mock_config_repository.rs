@@ -2,13 +2,24 @@
 
 use crate::application::errors::ApplicationError;
 use crate::application::repositories::ConfigRepositoryInterface;
-use crate::domain::value_objects::steering::{SpecConfig, SteeringBackupConfig, SteeringConfig};
+use crate::domain::value_objects::agent::AgentConfig;
+use crate::domain::value_objects::config_diagnostic::{ConfigDiagnostic, ConfigFileDiagnostics};
+use crate::domain::value_objects::config_source::EffectiveSetting;
+use crate::domain::value_objects::file_change::FileChange;
+use crate::domain::value_objects::steering::{
+    SpecConfig, SteeringBackupConfig, SteeringConfig, SteeringType,
+};
 use std::collections::HashMap;
 use std::sync::RwLock;
 
 #[derive(Debug, Default)]
 pub struct MockConfigRepository {
     steering_config: RwLock<Option<SteeringConfig>>,
+    agent_config: RwLock<AgentConfig>,
+    diagnostics: RwLock<Vec<ConfigDiagnostic>>,
+    effective_config: RwLock<Vec<EffectiveSetting>>,
+    ensure_changes: RwLock<Vec<FileChange>>,
+    user_types: RwLock<Vec<String>>,
     operations_to_fail: RwLock<HashMap<String, bool>>,
 }
 
@@ -16,6 +27,11 @@ impl MockConfigRepository {
     pub fn new() -> Self {
         Self {
             steering_config: RwLock::new(None),
+            agent_config: RwLock::new(AgentConfig::default()),
+            diagnostics: RwLock::new(Vec::new()),
+            effective_config: RwLock::new(Vec::new()),
+            ensure_changes: RwLock::new(Vec::new()),
+            user_types: RwLock::new(Vec::new()),
             operations_to_fail: RwLock::new(HashMap::new()),
         }
     }
@@ -23,6 +39,11 @@ impl MockConfigRepository {
     pub fn with_steering_config(config: SteeringConfig) -> Self {
         Self {
             steering_config: RwLock::new(Some(config)),
+            agent_config: RwLock::new(AgentConfig::default()),
+            diagnostics: RwLock::new(Vec::new()),
+            effective_config: RwLock::new(Vec::new()),
+            ensure_changes: RwLock::new(Vec::new()),
+            user_types: RwLock::new(Vec::new()),
             operations_to_fail: RwLock::new(HashMap::new()),
         }
     }
@@ -31,6 +52,29 @@ impl MockConfigRepository {
         *self.steering_config.write().unwrap() = Some(config);
     }
 
+    pub fn set_agent_config(&self, config: AgentConfig) {
+        *self.agent_config.write().unwrap() = config;
+    }
+
+    /// Mark a configured steering type as coming from the user-level config
+    pub fn set_user_type(&self, name: &str) {
+        self.user_types.write().unwrap().push(name.to_string());
+    }
+
+    /// Problems reported for the project config
+    pub fn set_diagnostics(&self, diagnostics: Vec<ConfigDiagnostic>) {
+        *self.diagnostics.write().unwrap() = diagnostics;
+    }
+
+    pub fn set_effective_config(&self, settings: Vec<EffectiveSetting>) {
+        *self.effective_config.write().unwrap() = settings;
+    }
+
+    /// Changes reported by every `ensure_*` call
+    pub fn set_ensure_changes(&self, changes: Vec<FileChange>) {
+        *self.ensure_changes.write().unwrap() = changes;
+    }
+
     pub fn set_operation_to_fail(&self, operation: &str) {
         self.operations_to_fail
             .write()
@@ -79,37 +123,34 @@ impl ConfigRepositoryInterface for MockConfigRepository {
         Ok(steering_config.backup)
     }
 
-    fn ensure_steering_config(&self) -> Result<(), ApplicationError> {
+    fn ensure_steering_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
         if self.should_fail("ensure_steering_config") {
             return Err(ApplicationError::ConfigurationError(
                 "Mock ensure steering failure".to_string(),
             ));
         }
 
-        // For testing purposes, this is a no-op
-        Ok(())
+        Ok(self.ensure_changes.read().unwrap().clone())
     }
 
-    fn ensure_steering_backup_config(&self) -> Result<(), ApplicationError> {
+    fn ensure_steering_backup_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
         if self.should_fail("ensure_steering_backup_config") {
             return Err(ApplicationError::ConfigurationError(
                 "Mock ensure backup failure".to_string(),
             ));
         }
 
-        // For testing purposes, this is a no-op
-        Ok(())
+        Ok(self.ensure_changes.read().unwrap().clone())
     }
 
-    fn ensure_allowed_operations(&self) -> Result<(), ApplicationError> {
+    fn ensure_allowed_operations(&self) -> Result<Vec<FileChange>, ApplicationError> {
         if self.should_fail("ensure_allowed_operations") {
             return Err(ApplicationError::ConfigurationError(
                 "Mock ensure allowed operations failure".to_string(),
             ));
         }
 
-        // For testing purposes, this is a no-op
-        Ok(())
+        Ok(self.ensure_changes.read().unwrap().clone())
     }
 
     fn load_spec_config(&self) -> Result<SpecConfig, ApplicationError> {
@@ -123,14 +164,114 @@ impl ConfigRepositoryInterface for MockConfigRepository {
         Ok(SpecConfig::default())
     }
 
-    fn ensure_spec_config(&self) -> Result<(), ApplicationError> {
+    fn ensure_spec_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
         if self.should_fail("ensure_spec_config") {
             return Err(ApplicationError::ConfigurationError(
                 "Mock ensure spec config failure".to_string(),
             ));
         }
 
-        // For testing purposes, this is a no-op
+        Ok(self.ensure_changes.read().unwrap().clone())
+    }
+
+    fn load_agent_config(&self) -> Result<AgentConfig, ApplicationError> {
+        if self.should_fail("load_agent_config") {
+            return Err(ApplicationError::ConfigurationError(
+                "Mock load agent config failure".to_string(),
+            ));
+        }
+
+        Ok(self.agent_config.read().unwrap().clone())
+    }
+
+    fn add_steering_type(&self, steering_type: &SteeringType) -> Result<(), ApplicationError> {
+        if self.should_fail("add_steering_type") {
+            return Err(ApplicationError::ConfigurationError(
+                "Mock add steering type failure".to_string(),
+            ));
+        }
+
+        let mut config = self.load_steering_config()?;
+        if config.types.iter().any(|t| t.name == steering_type.name) {
+            return Err(ApplicationError::SteeringTypeAlreadyExists(
+                steering_type.name.clone(),
+            ));
+        }
+        config.types.push(steering_type.clone());
+        self.set_steering_config(config);
         Ok(())
     }
+
+    fn update_steering_type(
+        &self,
+        name: &str,
+        steering_type: &SteeringType,
+    ) -> Result<(), ApplicationError> {
+        if self.should_fail("update_steering_type") {
+            return Err(ApplicationError::ConfigurationError(
+                "Mock update steering type failure".to_string(),
+            ));
+        }
+
+        let mut config = self.load_steering_config()?;
+        let existing = config
+            .types
+            .iter_mut()
+            .find(|t| t.name == name)
+            .ok_or_else(|| ApplicationError::SteeringTypeNotFound(name.to_string()))?;
+        *existing = steering_type.clone();
+        self.set_steering_config(config);
+        Ok(())
+    }
+
+    fn check_steering_type_removable(&self, name: &str) -> Result<(), ApplicationError> {
+        if self.user_types.read().unwrap().iter().any(|t| t == name) {
+            return Err(ApplicationError::SteeringTypeInUserConfig(
+                name.to_string(),
+                "~/.config/hail-mary/config.toml".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn remove_steering_type(&self, name: &str) -> Result<(), ApplicationError> {
+        self.check_steering_type_removable(name)?;
+        if self.should_fail("remove_steering_type") {
+            return Err(ApplicationError::ConfigurationError(
+                "Mock remove steering type failure".to_string(),
+            ));
+        }
+
+        let mut config = self.load_steering_config()?;
+        let before = config.types.len();
+        config.types.retain(|t| t.name != name);
+        if config.types.len() == before {
+            return Err(ApplicationError::SteeringTypeNotFound(name.to_string()));
+        }
+        self.set_steering_config(config);
+        Ok(())
+    }
+
+    fn validate_config(&self) -> Result<Vec<ConfigFileDiagnostics>, ApplicationError> {
+        if self.should_fail("validate_config") {
+            return Err(ApplicationError::ConfigurationError(
+                "Mock validate failure".to_string(),
+            ));
+        }
+
+        Ok(vec![ConfigFileDiagnostics {
+            path: ".kiro/config.toml".into(),
+            diagnostics: self.diagnostics.read().unwrap().clone(),
+        }])
+    }
+
+    fn load_effective_config(&self) -> Result<Vec<EffectiveSetting>, ApplicationError> {
+        if self.should_fail("load_effective_config") {
+            return Err(ApplicationError::ConfigurationError(
+                "Mock load effective config failure".to_string(),
+            ));
+        }
+
+        Ok(self.effective_config.read().unwrap().clone())
+    }
 }
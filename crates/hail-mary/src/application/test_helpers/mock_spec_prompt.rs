@@ -0,0 +1,55 @@
+//! Mock implementation of SpecPromptInterface for testing
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{
+    SpecPromptInterface, SpecRepositoryInterface, SpecSelectionResult,
+};
+use std::collections::VecDeque;
+use std::sync::RwLock;
+
+/// Returns a fixed selector result and answers name prompts from a queue
+#[derive(Debug)]
+pub struct MockSpecPrompt {
+    selection: SpecSelectionResult,
+    answers: RwLock<VecDeque<String>>,
+}
+
+impl MockSpecPrompt {
+    pub fn selecting(selection: SpecSelectionResult) -> Self {
+        Self {
+            selection,
+            answers: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    /// Queue an answer for the next name prompt
+    pub fn with_answer(self, answer: &str) -> Self {
+        self.answers.write().unwrap().push_back(answer.to_string());
+        self
+    }
+
+    fn next_answer(&self) -> Result<String, ApplicationError> {
+        self.answers
+            .write()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| ApplicationError::FileSystemError("No answer queued".to_string()))
+    }
+}
+
+impl SpecPromptInterface for MockSpecPrompt {
+    fn select_spec(
+        &self,
+        _spec_repo: &dyn SpecRepositoryInterface,
+    ) -> Result<SpecSelectionResult, ApplicationError> {
+        Ok(self.selection.clone())
+    }
+
+    fn ask_spec_name(&self) -> Result<String, ApplicationError> {
+        self.next_answer()
+    }
+
+    fn ask_sbi_name(&self) -> Result<String, ApplicationError> {
+        self.next_answer()
+    }
+}
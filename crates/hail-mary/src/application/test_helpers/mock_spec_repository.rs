@@ -1,15 +1,20 @@
 //! Mock implementation of SpecRepositoryInterface for testing
 
 use crate::application::errors::ApplicationError;
-use crate::application::repositories::SpecRepositoryInterface;
-use std::collections::{HashMap, HashSet};
+use crate::application::repositories::{
+    CollisionPolicy, RestoredSpec, SpecFileInfo, SpecRepositoryInterface,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::RwLock;
+use std::time::SystemTime;
 
 #[derive(Debug, Default)]
 pub struct MockSpecRepository {
     specs: RwLock<HashSet<String>>,
     archived_specs: RwLock<HashSet<String>>,
+    sbis: RwLock<HashMap<String, Vec<String>>>,
+    files: RwLock<HashMap<String, BTreeMap<String, String>>>,
     operations_to_fail: RwLock<HashMap<String, bool>>,
 }
 
@@ -18,6 +23,8 @@ impl MockSpecRepository {
         Self {
             specs: RwLock::new(HashSet::new()),
             archived_specs: RwLock::new(HashSet::new()),
+            sbis: RwLock::new(HashMap::new()),
+            files: RwLock::new(HashMap::new()),
             operations_to_fail: RwLock::new(HashMap::new()),
         }
     }
@@ -26,10 +33,40 @@ impl MockSpecRepository {
         Self {
             specs: RwLock::new(specs.into_iter().collect()),
             archived_specs: RwLock::new(HashSet::new()),
+            sbis: RwLock::new(HashMap::new()),
+            files: RwLock::new(HashMap::new()),
             operations_to_fail: RwLock::new(HashMap::new()),
         }
     }
 
+    /// Register an archived spec
+    pub fn add_archived_spec(&self, name: &str) {
+        self.archived_specs
+            .write()
+            .unwrap()
+            .insert(name.to_string());
+    }
+
+    /// Add a file to a spec (`spec_name` may be `pbi/sbi`)
+    pub fn add_file(&self, spec_name: &str, file_name: &str, content: &str) {
+        self.files
+            .write()
+            .unwrap()
+            .entry(spec_name.to_string())
+            .or_default()
+            .insert(file_name.to_string(), content.to_string());
+    }
+
+    /// Register an SBI under a PBI, turning the spec into a PBI
+    pub fn add_sbi(&self, pbi_name: &str, sbi_name: &str) {
+        self.sbis
+            .write()
+            .unwrap()
+            .entry(pbi_name.to_string())
+            .or_default()
+            .push(sbi_name.to_string());
+    }
+
     pub fn set_operation_to_fail(&self, operation: &str) {
         self.operations_to_fail
             .write()
@@ -53,6 +90,24 @@ impl MockSpecRepository {
     pub fn get_created_specs(&self) -> Vec<String> {
         self.specs.read().unwrap().iter().cloned().collect()
     }
+
+    fn resolve_destination(
+        existing: &HashSet<String>,
+        name: &str,
+        policy: CollisionPolicy,
+    ) -> Option<String> {
+        if !existing.contains(name) {
+            return Some(name.to_string());
+        }
+
+        match policy {
+            CollisionPolicy::Fail => None,
+            CollisionPolicy::Overwrite => Some(name.to_string()),
+            CollisionPolicy::KeepBoth => (2..)
+                .map(|n| format!("{}-{}", name, n))
+                .find(|candidate| !existing.contains(candidate)),
+        }
+    }
 }
 
 impl SpecRepositoryInterface for MockSpecRepository {
@@ -98,7 +153,11 @@ impl SpecRepositoryInterface for MockSpecRepository {
         Ok(specs)
     }
 
-    fn mark_spec_complete(&self, name: &str) -> Result<(), ApplicationError> {
+    fn mark_spec_complete(
+        &self,
+        name: &str,
+        policy: CollisionPolicy,
+    ) -> Result<String, ApplicationError> {
         if self.should_fail("mark_spec_complete") {
             return Err(ApplicationError::FileSystemError(
                 "Mock complete failure".to_string(),
@@ -108,14 +167,136 @@ impl SpecRepositoryInterface for MockSpecRepository {
         let mut specs = self.specs.write().unwrap();
         let mut archived = self.archived_specs.write().unwrap();
 
-        if specs.remove(name) {
-            archived.insert(name.to_string());
+        if !specs.contains(name) {
+            return Err(ApplicationError::SpecNotFound(name.to_string()));
+        }
+
+        let dest_name = Self::resolve_destination(&archived, name, policy)
+            .ok_or_else(|| ApplicationError::ArchiveAlreadyExists(name.to_string()))?;
+
+        specs.remove(name);
+        archived.insert(dest_name.clone());
+        Ok(dest_name)
+    }
+
+    fn unarchive_spec(
+        &self,
+        name: &str,
+        policy: CollisionPolicy,
+    ) -> Result<RestoredSpec, ApplicationError> {
+        if self.should_fail("unarchive_spec") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock unarchive failure".to_string(),
+            ));
+        }
+
+        let mut specs = self.specs.write().unwrap();
+        let mut archived = self.archived_specs.write().unwrap();
+
+        if !archived.contains(name) {
+            return Err(ApplicationError::SpecNotFound(name.to_string()));
+        }
+
+        let dest_name = Self::resolve_destination(&specs, name, policy)
+            .ok_or_else(|| ApplicationError::SpecAlreadyExists(name.to_string()))?;
+
+        // An overwritten active spec is archived under a numbered name
+        let replaced = if specs.remove(&dest_name) {
+            let replaced_name =
+                Self::resolve_destination(&archived, name, CollisionPolicy::KeepBoth);
+            archived.extend(replaced_name.clone());
+            replaced_name
+        } else {
+            None
+        };
+
+        archived.remove(name);
+        specs.insert(dest_name.clone());
+        Ok(RestoredSpec {
+            name: dest_name,
+            replaced,
+        })
+    }
+
+    fn rename_spec(&self, name: &str, new_name: &str) -> Result<(), ApplicationError> {
+        if self.should_fail("rename_spec") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock rename failure".to_string(),
+            ));
+        }
+
+        let mut specs = self.specs.write().unwrap();
+        if !specs.contains(name) {
+            return Err(ApplicationError::SpecNotFound(name.to_string()));
+        }
+        if specs.contains(new_name) {
+            return Err(ApplicationError::SpecAlreadyExists(new_name.to_string()));
+        }
+
+        specs.remove(name);
+        specs.insert(new_name.to_string());
+        Ok(())
+    }
+
+    fn delete_spec(&self, name: &str) -> Result<(), ApplicationError> {
+        if self.should_fail("delete_spec") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock delete failure".to_string(),
+            ));
+        }
+
+        if self.specs.write().unwrap().remove(name) {
+            self.files.write().unwrap().remove(name);
             Ok(())
         } else {
             Err(ApplicationError::SpecNotFound(name.to_string()))
         }
     }
 
+    fn list_spec_files(&self, name: &str) -> Result<Vec<SpecFileInfo>, ApplicationError> {
+        if self.should_fail("list_spec_files") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock list files failure".to_string(),
+            ));
+        }
+
+        Ok(self
+            .files
+            .read()
+            .unwrap()
+            .get(name)
+            .map(|files| {
+                files
+                    .iter()
+                    .map(|(file_name, content)| SpecFileInfo {
+                        name: file_name.clone(),
+                        size: content.len() as u64,
+                        modified: SystemTime::UNIX_EPOCH,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    fn read_spec_file(
+        &self,
+        name: &str,
+        file_name: &str,
+    ) -> Result<Option<String>, ApplicationError> {
+        if self.should_fail("read_spec_file") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock read file failure".to_string(),
+            ));
+        }
+
+        Ok(self
+            .files
+            .read()
+            .unwrap()
+            .get(name)
+            .and_then(|files| files.get(file_name).cloned()))
+    }
+
     fn get_spec_path(&self, name: &str) -> Result<PathBuf, ApplicationError> {
         if self.should_fail("get_spec_path") {
             return Err(ApplicationError::FileSystemError(
@@ -124,7 +305,14 @@ impl SpecRepositoryInterface for MockSpecRepository {
         }
 
         let specs = self.specs.read().unwrap();
-        if specs.contains(name) {
+        let is_sbi = name.split_once('/').is_some_and(|(pbi, sbi)| {
+            self.sbis
+                .read()
+                .unwrap()
+                .get(pbi)
+                .is_some_and(|sbis| sbis.iter().any(|s| s == sbi))
+        });
+        if specs.contains(name) || is_sbi {
             Ok(PathBuf::from(format!(".kiro/specs/{}", name)))
         } else {
             Err(ApplicationError::SpecNotFound(name.to_string()))
@@ -148,14 +336,20 @@ impl SpecRepositoryInterface for MockSpecRepository {
         Ok(archived)
     }
 
-    fn is_pbi(&self, _spec_name: &str) -> Result<bool, ApplicationError> {
-        // Mock: always return false for simplicity
-        Ok(false)
+    fn is_pbi(&self, spec_name: &str) -> Result<bool, ApplicationError> {
+        Ok(self.sbis.read().unwrap().contains_key(spec_name))
     }
 
-    fn list_sbis(&self, _pbi_name: &str) -> Result<Vec<String>, ApplicationError> {
-        // Mock: return empty vec
-        Ok(Vec::new())
+    fn list_sbis(&self, pbi_name: &str) -> Result<Vec<String>, ApplicationError> {
+        let mut sbis = self
+            .sbis
+            .read()
+            .unwrap()
+            .get(pbi_name)
+            .cloned()
+            .unwrap_or_default();
+        sbis.sort();
+        Ok(sbis)
     }
 
     fn create_sbi(
@@ -2,18 +2,27 @@
 
 use crate::application::errors::ApplicationError;
 use crate::application::repositories::steering_repository::{
-    BackupInfo, SteeringRepositoryInterface,
+    BackupInfo, SteeringFileScaffold, SteeringRepositoryInterface,
 };
+use crate::domain::value_objects::deployment::DeploymentReport;
+use crate::domain::value_objects::file_change::FileChange;
+use crate::domain::value_objects::slash_command::SlashCommand;
 use crate::domain::value_objects::steering::{Steering, SteeringConfig};
+use crate::domain::value_objects::steering_backup::{
+    BackupFileEntry, BackupManifest, BackupReason,
+};
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
 use std::time::SystemTime;
 
 #[derive(Debug, Default)]
 pub struct MockSteeringRepository {
     steering_files: RwLock<HashSet<PathBuf>>,
+    steering_contents: RwLock<HashMap<String, String>>,
     backups: RwLock<Vec<BackupInfo>>,
+    backup_contents: RwLock<HashMap<String, HashMap<PathBuf, String>>>,
+    backup_manifests: RwLock<HashMap<String, BackupManifest>>,
     operations_to_fail: RwLock<HashMap<String, bool>>,
     project_exists: RwLock<bool>,
 }
@@ -22,7 +31,10 @@ impl MockSteeringRepository {
     pub fn new() -> Self {
         Self {
             steering_files: RwLock::new(HashSet::new()),
+            steering_contents: RwLock::new(HashMap::new()),
             backups: RwLock::new(Vec::new()),
+            backup_contents: RwLock::new(HashMap::new()),
+            backup_manifests: RwLock::new(HashMap::new()),
             operations_to_fail: RwLock::new(HashMap::new()),
             project_exists: RwLock::new(false),
         }
@@ -31,12 +43,56 @@ impl MockSteeringRepository {
     pub fn with_steering_files(files: Vec<PathBuf>) -> Self {
         Self {
             steering_files: RwLock::new(files.into_iter().collect()),
+            steering_contents: RwLock::new(HashMap::new()),
             backups: RwLock::new(Vec::new()),
+            backup_contents: RwLock::new(HashMap::new()),
+            backup_manifests: RwLock::new(HashMap::new()),
             operations_to_fail: RwLock::new(HashMap::new()),
             project_exists: RwLock::new(true),
         }
     }
 
+    /// Create a mock whose steering files for every type in `config` have content
+    pub fn with_steerings(config: &SteeringConfig, content: impl Fn(&str) -> String) -> Self {
+        let repo = Self::new();
+        for steering_type in &config.types {
+            repo.set_steering_content(&steering_type.name, &content(&steering_type.name));
+        }
+        repo.set_project_exists(true);
+        repo
+    }
+
+    pub fn set_steering_content(&self, name: &str, content: &str) {
+        self.steering_files
+            .write()
+            .unwrap()
+            .insert(PathBuf::from(format!("{}.md", name)));
+        self.steering_contents
+            .write()
+            .unwrap()
+            .insert(name.to_string(), content.to_string());
+    }
+
+    /// Content of a current steering file (`name` without `.md`)
+    pub fn steering_content(&self, name: &str) -> Option<String> {
+        self.steering_contents.read().unwrap().get(name).cloned()
+    }
+
+    /// Names of the recorded backups, oldest first
+    pub fn backup_names(&self) -> Vec<String> {
+        self.backups
+            .read()
+            .unwrap()
+            .iter()
+            .map(|backup| backup.name.clone())
+            .collect()
+    }
+
+    /// Drop all manifests, as for backups made before manifests were recorded
+    pub fn forget_backup_manifests(&self) {
+        self.backup_manifests.write().unwrap().clear();
+    }
+
     pub fn set_operation_to_fail(&self, operation: &str) {
         self.operations_to_fail
             .write()
@@ -63,7 +119,7 @@ impl MockSteeringRepository {
 }
 
 impl SteeringRepositoryInterface for MockSteeringRepository {
-    fn initialize_steering(&self) -> Result<(), ApplicationError> {
+    fn initialize_steering(&self) -> Result<Vec<FileChange>, ApplicationError> {
         if self.should_fail("initialize_steering") {
             return Err(ApplicationError::FileSystemError(
                 "Mock initialize failure".to_string(),
@@ -71,21 +127,31 @@ impl SteeringRepositoryInterface for MockSteeringRepository {
         }
 
         // For testing purposes, this is a no-op
-        Ok(())
+        Ok(Vec::new())
     }
 
-    fn create_steering_files(&self, config: &SteeringConfig) -> Result<(), ApplicationError> {
+    fn create_steering_files(
+        &self,
+        config: &SteeringConfig,
+    ) -> Result<Vec<SteeringFileScaffold>, ApplicationError> {
         if self.should_fail("create_steering_files") {
             return Err(ApplicationError::FileSystemError(
                 "Mock create files failure".to_string(),
             ));
         }
 
+        let contents = self.steering_contents.read().unwrap();
         let mut files = self.steering_files.write().unwrap();
-        for steering_type in &config.types {
-            files.insert(PathBuf::from(format!("{}.md", steering_type.name)));
-        }
-        Ok(())
+        Ok(config
+            .types
+            .iter()
+            .map(|steering_type| {
+                let file = PathBuf::from(format!("{}.md", steering_type.name));
+                let created =
+                    !contents.contains_key(&steering_type.name) && files.insert(file.clone());
+                SteeringFileScaffold { file, created }
+            })
+            .collect())
     }
 
     fn list_steering_files(&self) -> Result<Vec<PathBuf>, ApplicationError> {
@@ -121,22 +187,81 @@ impl SteeringRepositoryInterface for MockSteeringRepository {
 
     fn create_steering_backup(
         &self,
-        timestamp: &str,
-        _files: &[PathBuf],
-    ) -> Result<(), ApplicationError> {
+        name: &str,
+        files: &[PathBuf],
+        reason: BackupReason,
+    ) -> Result<BackupManifest, ApplicationError> {
         if self.should_fail("create_steering_backup") {
             return Err(ApplicationError::FileSystemError(
                 "Mock backup failure".to_string(),
             ));
         }
 
+        // Mirror the real repository's collision suffixes
+        let name = {
+            let taken = self.backup_contents.read().unwrap();
+            let mut attempt = 1;
+            let mut candidate = name.to_string();
+            while taken.contains_key(&candidate) {
+                attempt += 1;
+                candidate = format!("{}-{}", name, attempt);
+            }
+            candidate
+        };
+
+        // Snapshot the content of the backed up files
+        let contents = self.steering_contents.read().unwrap();
+        let snapshot: HashMap<PathBuf, String> = files
+            .iter()
+            .map(|file| {
+                let content = contents.get(&file_stem(file)).cloned().unwrap_or_default();
+                (file.clone(), content)
+            })
+            .collect();
+
+        let manifest = BackupManifest {
+            name: name.clone(),
+            created_at: chrono::Local::now().to_rfc3339(),
+            reason,
+            files: files
+                .iter()
+                .map(|file| BackupFileEntry::from_content(file.clone(), snapshot[file].as_bytes()))
+                .collect(),
+        };
+
+        self.backup_contents
+            .write()
+            .unwrap()
+            .insert(name.clone(), snapshot);
+        self.backup_manifests
+            .write()
+            .unwrap()
+            .insert(name.clone(), manifest.clone());
+
         let backup_info = BackupInfo {
-            name: timestamp.to_string(),
+            name: name.clone(),
             created_at: SystemTime::now(),
-            path: PathBuf::from(format!(".kiro/steering/backup/{}", timestamp)),
+            path: PathBuf::from(format!(".kiro/steering/backup/{}", name)),
         };
         self.backups.write().unwrap().push(backup_info);
-        Ok(())
+        Ok(manifest)
+    }
+
+    fn load_backup_manifest(
+        &self,
+        backup: &str,
+    ) -> Result<Option<BackupManifest>, ApplicationError> {
+        if self.should_fail("load_backup_manifest") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock load manifest failure".to_string(),
+            ));
+        }
+
+        if !self.backup_contents.read().unwrap().contains_key(backup) {
+            return Err(ApplicationError::BackupNotFound(backup.to_string()));
+        }
+
+        Ok(self.backup_manifests.read().unwrap().get(backup).cloned())
     }
 
     fn list_steering_backups(&self) -> Result<Vec<BackupInfo>, ApplicationError> {
@@ -158,23 +283,112 @@ impl SteeringRepositoryInterface for MockSteeringRepository {
 
         let mut backups = self.backups.write().unwrap();
         for _ in 0..std::cmp::min(count, backups.len()) {
-            backups.remove(0);
+            let removed = backups.remove(0);
+            self.backup_contents.write().unwrap().remove(&removed.name);
+            self.backup_manifests.write().unwrap().remove(&removed.name);
         }
         Ok(())
     }
 
-    fn deploy_slash_commands(&self) -> Result<(), ApplicationError> {
-        if self.should_fail("deploy_slash_commands") {
+    fn list_backup_files(&self, backup: &str) -> Result<Vec<PathBuf>, ApplicationError> {
+        if self.should_fail("list_backup_files") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock list backup files failure".to_string(),
+            ));
+        }
+
+        let backups = self.backup_contents.read().unwrap();
+        let files = backups
+            .get(backup)
+            .ok_or_else(|| ApplicationError::BackupNotFound(backup.to_string()))?;
+
+        let mut files: Vec<PathBuf> = files.keys().cloned().collect();
+        files.sort();
+        Ok(files)
+    }
+
+    fn read_backup_file(&self, backup: &str, file: &Path) -> Result<String, ApplicationError> {
+        if self.should_fail("read_backup_file") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock read backup file failure".to_string(),
+            ));
+        }
+
+        self.backup_contents
+            .read()
+            .unwrap()
+            .get(backup)
+            .and_then(|files| files.get(file).cloned())
+            .ok_or_else(|| {
+                ApplicationError::FileSystemError(format!(
+                    "File {} not found in backup {}",
+                    file.display(),
+                    backup
+                ))
+            })
+    }
+
+    fn read_steering_file(&self, file: &Path) -> Result<Option<String>, ApplicationError> {
+        if self.should_fail("read_steering_file") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock read steering file failure".to_string(),
+            ));
+        }
+
+        // Listed files without explicit content exist but are empty
+        let exists = self.steering_files.read().unwrap().contains(file);
+        Ok(self
+            .steering_content(&file_stem(file))
+            .or_else(|| exists.then(String::new)))
+    }
+
+    fn write_steering_file(&self, file: &Path, content: &str) -> Result<(), ApplicationError> {
+        if self.should_fail("write_steering_file") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock write steering file failure".to_string(),
+            ));
+        }
+
+        self.set_steering_content(&file_stem(file), content);
+        Ok(())
+    }
+
+    fn remove_steering_file(&self, file: &Path) -> Result<bool, ApplicationError> {
+        if self.should_fail("remove_steering_file") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock remove steering file failure".to_string(),
+            ));
+        }
+
+        self.steering_contents
+            .write()
+            .unwrap()
+            .remove(&file_stem(file));
+        Ok(self.steering_files.write().unwrap().remove(file))
+    }
+
+    fn list_slash_commands(&self) -> Result<Vec<SlashCommand>, ApplicationError> {
+        if self.should_fail("list_slash_commands") {
+            return Err(ApplicationError::FileSystemError(
+                "Mock list failure".to_string(),
+            ));
+        }
+
+        Ok(Vec::new())
+    }
+
+    fn deploy_claude_resources(&self) -> Result<DeploymentReport, ApplicationError> {
+        if self.should_fail("deploy_claude_resources") {
             return Err(ApplicationError::FileSystemError(
                 "Mock deploy failure".to_string(),
             ));
         }
 
         // For testing purposes, this is a no-op
-        Ok(())
+        Ok(DeploymentReport::default())
     }
 
-    fn update_gitignore(&self) -> Result<(), ApplicationError> {
+    fn update_gitignore(&self) -> Result<Vec<FileChange>, ApplicationError> {
         if self.should_fail("update_gitignore") {
             return Err(ApplicationError::FileSystemError(
                 "Mock gitignore failure".to_string(),
@@ -182,7 +396,7 @@ impl SteeringRepositoryInterface for MockSteeringRepository {
         }
 
         // For testing purposes, this is a no-op
-        Ok(())
+        Ok(Vec::new())
     }
 
     fn exists(&self) -> Result<bool, ApplicationError> {
@@ -197,7 +411,7 @@ impl SteeringRepositoryInterface for MockSteeringRepository {
 
     fn load_steering_files(
         &self,
-        _config: &SteeringConfig,
+        config: &SteeringConfig,
     ) -> Result<Vec<Steering>, ApplicationError> {
         if self.should_fail("load_steering_files") {
             return Err(ApplicationError::FileSystemError(
@@ -205,7 +419,23 @@ impl SteeringRepositoryInterface for MockSteeringRepository {
             ));
         }
 
-        // For testing purposes, return empty vector
-        Ok(vec![])
+        // Mirror the real repository: skip types without content
+        let contents = self.steering_contents.read().unwrap();
+        Ok(config
+            .types
+            .iter()
+            .filter_map(|steering_type| {
+                contents.get(&steering_type.name).map(|content| Steering {
+                    steering_type: steering_type.clone(),
+                    content: content.clone(),
+                })
+            })
+            .collect())
     }
 }
+
+fn file_stem(file: &Path) -> String {
+    file.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
@@ -5,6 +5,9 @@
 #[cfg(test)]
 pub mod mock_config_repository;
 
+#[cfg(any(test, feature = "test-util"))]
+pub mod mock_spec_prompt;
+
 #[cfg(test)]
 pub mod mock_spec_repository;
 
@@ -17,6 +20,9 @@ pub mod test_directory;
 #[cfg(test)]
 pub use mock_config_repository::MockConfigRepository;
 
+#[cfg(any(test, feature = "test-util"))]
+pub use mock_spec_prompt::MockSpecPrompt;
+
 #[cfg(test)]
 pub use mock_spec_repository::MockSpecRepository;
 
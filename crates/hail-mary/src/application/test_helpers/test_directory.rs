@@ -5,6 +5,7 @@
 //! in a thread-safe manner during parallel test execution.
 
 use std::env;
+use std::ffi::OsString;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, MutexGuard};
@@ -22,6 +23,9 @@ static TEST_DIR_MUTEX: Mutex<()> = Mutex::new(());
 /// This is essential because `env::set_current_dir()` modifies global process state,
 /// which can cause tests to interfere with each other when running in parallel.
 ///
+/// While it holds the lock, `XDG_CONFIG_HOME` also points into the temporary
+/// directory, so no user-level hail-mary config leaks into the test.
+///
 /// # Examples
 ///
 /// ```
@@ -40,6 +44,8 @@ static TEST_DIR_MUTEX: Mutex<()> = Mutex::new(());
 pub struct TestDirectory {
     _temp_dir: TempDir,
     original_dir: Option<PathBuf>,
+    /// XDG_CONFIG_HOME before it was pointed into the temp directory
+    original_xdg_config_home: Option<Option<OsString>>,
     _guard: Option<MutexGuard<'static, ()>>,
 }
 
@@ -77,10 +83,12 @@ impl TestDirectory {
 
         // Change to temporary directory
         env::set_current_dir(temp_dir.path()).expect("Failed to change to temp directory");
+        let original_xdg_config_home = isolate_user_config(temp_dir.path());
 
         Self {
             _temp_dir: temp_dir,
             original_dir: Some(original_dir),
+            original_xdg_config_home: Some(original_xdg_config_home),
             _guard: Some(guard),
         }
     }
@@ -103,6 +111,7 @@ impl TestDirectory {
         Self {
             _temp_dir: temp_dir,
             original_dir: None,
+            original_xdg_config_home: None,
             _guard: None,
         }
     }
@@ -132,10 +141,12 @@ impl TestDirectory {
 
         // Change to temporary directory
         env::set_current_dir(temp_dir.path()).expect("Failed to change to temp directory");
+        let original_xdg_config_home = isolate_user_config(temp_dir.path());
 
         Self {
             _temp_dir: temp_dir,
             original_dir: Some(original_dir),
+            original_xdg_config_home: Some(original_xdg_config_home),
             _guard: Some(guard),
         }
     }
@@ -180,6 +191,17 @@ impl TestDirectory {
     }
 }
 
+/// Point the user-level config (`$XDG_CONFIG_HOME/hail-mary/config.toml`) into `dir`,
+/// so commands under test never read the developer's own config. Returns the
+/// previous value for restoring.
+fn isolate_user_config(dir: &Path) -> Option<OsString> {
+    let original = env::var_os("XDG_CONFIG_HOME");
+    // SAFETY: callers hold TEST_DIR_MUTEX, which every test that reads the
+    // user config through a command also holds
+    unsafe { env::set_var("XDG_CONFIG_HOME", dir.join(".config")) };
+    original
+}
+
 impl Drop for TestDirectory {
     /// Restores the original directory when the TestDirectory is dropped.
     ///
@@ -194,6 +216,15 @@ impl Drop for TestDirectory {
             // and don't want to panic during drop
             let _ = env::set_current_dir(original_dir);
         }
+        if let Some(xdg_config_home) = self.original_xdg_config_home.take() {
+            // SAFETY: only changed while TEST_DIR_MUTEX is held, which it still is here
+            unsafe {
+                match xdg_config_home {
+                    Some(value) => env::set_var("XDG_CONFIG_HOME", value),
+                    None => env::remove_var("XDG_CONFIG_HOME"),
+                }
+            }
+        }
 
         // Mutex guard is automatically released when _guard is dropped (if Some)
     }
@@ -1,18 +1,64 @@
 use crate::application::errors::ApplicationError;
 use crate::application::repositories::{ConfigRepositoryInterface, SteeringRepositoryInterface};
+use crate::domain::value_objects::steering_backup::{
+    BackupFileEntry, BackupManifest, BackupReason, same_content,
+};
 use chrono::Local;
+use std::path::PathBuf;
+
+/// Result of trying to back up the current steering files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BackupOutcome {
+    /// A new backup was created
+    Created(BackupManifest),
+    /// The files match the named latest backup, so no backup was created
+    Unchanged(String),
+    /// There were no steering files to back up
+    NoFiles,
+}
+
+impl BackupOutcome {
+    /// Name of the backup holding the current state, if any
+    pub fn backup_name(&self) -> Option<&str> {
+        match self {
+            BackupOutcome::Created(manifest) => Some(&manifest.name),
+            BackupOutcome::Unchanged(name) => Some(name),
+            BackupOutcome::NoFiles => None,
+        }
+    }
+}
 
 /// Creates a backup of all steering files with automatic rotation
 ///
 /// This function:
 /// 1. Lists all steering markdown files (excluding backup directory)
-/// 2. Creates a timestamped backup directory
-/// 3. Copies all files to the backup
-/// 4. Enforces the maximum backup limit by deleting oldest backups
+/// 2. Skips the backup when the files are identical to the latest backup
+/// 3. Enforces the maximum backup limit by deleting oldest backups
+/// 4. Copies all files to a new timestamped backup with a manifest
 pub fn backup_steering(
     config_repo: &dyn ConfigRepositoryInterface,
     steering_repo: &dyn SteeringRepositoryInterface,
 ) -> Result<String, ApplicationError> {
+    match snapshot_steering(config_repo, steering_repo, BackupReason::Manual)? {
+        BackupOutcome::Created(manifest) => Ok(format!(
+            "Created backup '{}' with {} files",
+            manifest.name,
+            manifest.files.len()
+        )),
+        BackupOutcome::Unchanged(name) => Ok(format!(
+            "Steering files unchanged since backup '{}'; no new backup created",
+            name
+        )),
+        BackupOutcome::NoFiles => Ok("No steering files found to backup".to_string()),
+    }
+}
+
+/// Backs up the current steering files unless they match the latest backup
+pub(crate) fn snapshot_steering(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+    reason: BackupReason,
+) -> Result<BackupOutcome, ApplicationError> {
     // Ensure steering backup config exists
     config_repo.ensure_steering_backup_config()?;
 
@@ -23,12 +69,20 @@ pub fn backup_steering(
     let files = steering_repo.list_steering_files()?;
 
     if files.is_empty() {
-        return Ok("No steering files found to backup".to_string());
+        return Ok(BackupOutcome::NoFiles);
     }
 
     // Check current backup count BEFORE creating new backup
     let existing_backups = steering_repo.list_steering_backups()?;
 
+    // Nothing to do if the latest backup already holds exactly these files
+    if let Some(latest) = existing_backups.last() {
+        let current = current_entries(steering_repo, &files)?;
+        if same_content(&latest_entries(steering_repo, &latest.name)?, &current) {
+            return Ok(BackupOutcome::Unchanged(latest.name.clone()));
+        }
+    }
+
     // If we're at or above the limit, delete oldest to make room
     if existing_backups.len() >= config.max {
         // Calculate how many to delete (at least 1 to make room for new backup)
@@ -36,17 +90,46 @@ pub fn backup_steering(
         steering_repo.delete_oldest_steering_backups(excess_count)?;
     }
 
-    // Generate timestamp for backup directory
-    let timestamp = Local::now().format("%Y-%m-%d-%H-%M").to_string();
+    // Second resolution; the repository suffixes the name if it is still taken
+    let timestamp = Local::now().format("%Y-%m-%d-%H-%M-%S").to_string();
 
     // Create the backup (now we have room)
-    steering_repo.create_steering_backup(&timestamp, &files)?;
+    let manifest = steering_repo.create_steering_backup(&timestamp, &files, reason)?;
+
+    Ok(BackupOutcome::Created(manifest))
+}
+
+fn current_entries(
+    steering_repo: &dyn SteeringRepositoryInterface,
+    files: &[PathBuf],
+) -> Result<Vec<BackupFileEntry>, ApplicationError> {
+    let mut entries = Vec::new();
+    for file in files {
+        if let Some(content) = steering_repo.read_steering_file(file)? {
+            entries.push(BackupFileEntry::from_content(
+                file.clone(),
+                content.as_bytes(),
+            ));
+        }
+    }
+    Ok(entries)
+}
 
-    Ok(format!(
-        "Created backup '{}' with {} files",
-        timestamp,
-        files.len()
-    ))
+/// File entries of a backup, from its manifest or by hashing its files
+fn latest_entries(
+    steering_repo: &dyn SteeringRepositoryInterface,
+    backup: &str,
+) -> Result<Vec<BackupFileEntry>, ApplicationError> {
+    if let Some(manifest) = steering_repo.load_backup_manifest(backup)? {
+        return Ok(manifest.files);
+    }
+
+    let mut entries = Vec::new();
+    for file in steering_repo.list_backup_files(backup)? {
+        let content = steering_repo.read_backup_file(backup, &file)?;
+        entries.push(BackupFileEntry::from_content(file, content.as_bytes()));
+    }
+    Ok(entries)
 }
 
 #[cfg(test)]
@@ -72,6 +155,60 @@ mod tests {
         assert!(message.contains("files"));
     }
 
+    #[test]
+    fn test_backup_steering_skips_unchanged_content() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("tech", "# Tech v1");
+
+        let first = snapshot_steering(&config_repo, &steering_repo, BackupReason::Manual).unwrap();
+        let BackupOutcome::Created(manifest) = first else {
+            panic!("Expected a new backup, got {:?}", first);
+        };
+        assert_eq!(manifest.files.len(), 1);
+
+        let message = backup_steering(&config_repo, &steering_repo).unwrap();
+        assert_eq!(
+            message,
+            format!(
+                "Steering files unchanged since backup '{}'; no new backup created",
+                manifest.name
+            )
+        );
+        assert_eq!(steering_repo.backup_names(), vec![manifest.name.clone()]);
+
+        // Any change produces a distinct backup, even within the same second
+        steering_repo.set_steering_content("tech", "# Tech v2");
+        let second =
+            snapshot_steering(&config_repo, &steering_repo, BackupReason::BeforeRestore).unwrap();
+        let BackupOutcome::Created(second) = second else {
+            panic!("Expected a new backup, got {:?}", second);
+        };
+        assert_ne!(second.name, manifest.name);
+        assert_eq!(second.reason, BackupReason::BeforeRestore);
+        assert_eq!(steering_repo.backup_names().len(), 2);
+    }
+
+    #[test]
+    fn test_backup_steering_compares_legacy_backup_contents() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("tech", "# Tech");
+        let files = steering_repo.list_steering_files().unwrap();
+        steering_repo
+            .create_steering_backup("2025-01-01-10-00", &files, BackupReason::Manual)
+            .unwrap();
+        steering_repo.forget_backup_manifests();
+
+        let outcome =
+            snapshot_steering(&config_repo, &steering_repo, BackupReason::Manual).unwrap();
+
+        assert_eq!(
+            outcome,
+            BackupOutcome::Unchanged("2025-01-01-10-00".to_string())
+        );
+    }
+
     #[test]
     fn test_backup_steering_handles_no_files() {
         let config_repo = MockConfigRepository::new();
@@ -1,14 +1,16 @@
 use crate::application::errors::ApplicationError;
-use crate::application::repositories::SpecRepositoryInterface;
+use crate::application::repositories::{CollisionPolicy, SpecRepositoryInterface};
 
+/// Moves specs to the archive, returning the names they were archived under
 pub fn complete_specs(
     spec_repo: &dyn SpecRepositoryInterface,
     spec_names: &[String],
-) -> Result<(), ApplicationError> {
-    for name in spec_names {
-        spec_repo.mark_spec_complete(name)?;
-    }
-    Ok(())
+    policy: CollisionPolicy,
+) -> Result<Vec<String>, ApplicationError> {
+    spec_names
+        .iter()
+        .map(|name| spec_repo.mark_spec_complete(name, policy))
+        .collect()
 }
 
 #[cfg(test)]
@@ -32,7 +34,11 @@ mod tests {
         fs::create_dir_all(specs_dir.join("2025-01-02-feature-b")).unwrap();
 
         // Complete one feature
-        let result = complete_specs(&spec_repo, &["2025-01-01-feature-a".to_string()]);
+        let result = complete_specs(
+            &spec_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::Fail,
+        );
         assert!(result.is_ok());
 
         // Verify it was moved to archive
@@ -61,6 +67,7 @@ mod tests {
                 "2025-01-01-feature-a".to_string(),
                 "2025-01-02-feature-b".to_string(),
             ],
+            CollisionPolicy::Fail,
         );
         assert!(result.is_ok());
 
@@ -79,7 +86,11 @@ mod tests {
         let spec_repo = ConcreteSpecRepository::new(path_manager);
 
         // Try to complete non-existent feature (no need to initialize)
-        let result = complete_specs(&spec_repo, &["non-existent".to_string()]);
+        let result = complete_specs(
+            &spec_repo,
+            &["non-existent".to_string()],
+            CollisionPolicy::Fail,
+        );
         assert!(result.is_err());
         match result.unwrap_err() {
             ApplicationError::SpecNotFound(name) => assert_eq!(name, "non-existent"),
@@ -94,7 +105,11 @@ mod tests {
             "2025-01-02-feature-b".to_string(),
         ]);
 
-        let result = complete_specs(&mock_repo, &["2025-01-01-feature-a".to_string()]);
+        let result = complete_specs(
+            &mock_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::Fail,
+        );
         assert!(result.is_ok());
 
         // Verify spec is in archived list
@@ -129,8 +144,12 @@ mod tests {
         )
         .unwrap();
 
-        // Complete feature that already exists in archive - should overwrite
-        let result = complete_specs(&spec_repo, &["2025-01-01-feature-a".to_string()]);
+        // Complete feature that already exists in archive with the overwrite policy
+        let result = complete_specs(
+            &spec_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::Overwrite,
+        );
         assert!(result.is_ok());
 
         // Verify the new spec replaced the old archive
@@ -154,4 +173,65 @@ mod tests {
             fs::read_to_string(archive_dir.join("2025-01-01-feature-a").join("new.txt")).unwrap();
         assert_eq!(new_content, "new content");
     }
+
+    #[test]
+    fn test_complete_specs_collision_fails_by_default() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        let specs_dir = path_manager.specs_dir(true);
+        let archive_dir = path_manager.archive_dir(true);
+        fs::create_dir_all(specs_dir.join("2025-01-01-feature-a")).unwrap();
+        fs::create_dir_all(archive_dir.join("2025-01-01-feature-a")).unwrap();
+        fs::write(
+            archive_dir.join("2025-01-01-feature-a").join("old.txt"),
+            "old content",
+        )
+        .unwrap();
+
+        let result = complete_specs(
+            &spec_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::default(),
+        );
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ArchiveAlreadyExists(name)) if name == "2025-01-01-feature-a"
+        ));
+
+        // Nothing was moved or deleted
+        assert!(specs_dir.join("2025-01-01-feature-a").exists());
+        assert!(
+            archive_dir
+                .join("2025-01-01-feature-a")
+                .join("old.txt")
+                .exists()
+        );
+    }
+
+    #[test]
+    fn test_complete_specs_collision_keep_both() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        let specs_dir = path_manager.specs_dir(true);
+        let archive_dir = path_manager.archive_dir(true);
+        fs::create_dir_all(specs_dir.join("2025-01-01-feature-a")).unwrap();
+        fs::create_dir_all(archive_dir.join("2025-01-01-feature-a")).unwrap();
+        fs::create_dir_all(archive_dir.join("2025-01-01-feature-a-2")).unwrap();
+
+        let result = complete_specs(
+            &spec_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::KeepBoth,
+        );
+        assert_eq!(result.unwrap(), vec!["2025-01-01-feature-a-3".to_string()]);
+
+        assert!(!specs_dir.join("2025-01-01-feature-a").exists());
+        assert!(archive_dir.join("2025-01-01-feature-a").exists());
+        assert!(archive_dir.join("2025-01-01-feature-a-2").exists());
+        assert!(archive_dir.join("2025-01-01-feature-a-3").exists());
+    }
 }
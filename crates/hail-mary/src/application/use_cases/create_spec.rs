@@ -0,0 +1,79 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SpecRepositoryInterface;
+use crate::domain::value_objects::spec::SpecValidator;
+
+/// Creates a new specification and returns its date-prefixed directory name
+pub fn create_new_spec(
+    spec_repo: &dyn SpecRepositoryInterface,
+    name: &str,
+    lang: &str,
+) -> Result<String, ApplicationError> {
+    SpecValidator::validate_spec_name(name)?;
+    spec_repo.create_spec(name, lang)?;
+
+    // Generate the actual directory name with date prefix
+    let date = chrono::Utc::now().format("%Y-%m-%d");
+    Ok(format!("{}-{}", date, name))
+}
+
+/// Creates a new auto-numbered SBI (`sbi-N-title`) in a PBI and returns its name
+pub fn create_new_sbi(
+    spec_repo: &dyn SpecRepositoryInterface,
+    pbi_name: &str,
+    sbi_title: &str,
+    lang: &str,
+) -> Result<String, ApplicationError> {
+    SpecValidator::validate_spec_name(sbi_title)?;
+
+    // Auto-number SBI
+    let existing_sbis = spec_repo.list_sbis(pbi_name)?;
+    let next_number = existing_sbis.len() + 1;
+    let sbi_name = format!("sbi-{}-{}", next_number, sbi_title);
+
+    // Create SBI (generates tasks.md and memo.md only)
+    spec_repo.create_sbi(pbi_name, &sbi_name, lang)?;
+
+    Ok(sbi_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSpecRepository;
+    use crate::domain::errors::DomainError;
+
+    #[test]
+    fn test_create_new_spec_returns_date_prefixed_name() {
+        let repo = MockSpecRepository::new();
+
+        let name = create_new_spec(&repo, "new-feature", "en").unwrap();
+
+        let date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        assert_eq!(name, format!("{}-new-feature", date));
+        assert!(repo.get_created_specs().contains(&name));
+    }
+
+    #[test]
+    fn test_create_new_spec_rejects_invalid_name() {
+        let repo = MockSpecRepository::new();
+
+        let result = create_new_spec(&repo, "Invalid_Name", "en");
+        assert!(matches!(
+            result,
+            Err(ApplicationError::DomainError(DomainError::InvalidSpecName(
+                _
+            )))
+        ));
+        assert!(repo.get_created_specs().is_empty());
+    }
+
+    #[test]
+    fn test_create_new_sbi_auto_numbers() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-01-01-pbi".to_string()]);
+        repo.add_sbi("2025-01-01-pbi", "sbi-1-setup");
+        repo.add_sbi("2025-01-01-pbi", "sbi-2-api");
+
+        let name = create_new_sbi(&repo, "2025-01-01-pbi", "ui", "en").unwrap();
+        assert_eq!(name, "sbi-3-ui");
+    }
+}
@@ -0,0 +1,141 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SteeringRepositoryInterface;
+use crate::application::use_cases::resolve_backup::resolve_backup_name;
+use similar::TextDiff;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// How a steering file differs between a backup and the current state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SteeringFileChange {
+    Modified,
+    /// Exists now but not in the backup
+    Added,
+    /// Exists in the backup but not now
+    Removed,
+}
+
+/// Unified diff of one steering file, from the backup to the current state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteeringFileDiff {
+    pub file: PathBuf,
+    pub change: SteeringFileChange,
+    pub diff: String,
+}
+
+/// Compares a backup (exact name or `latest`) with the current steering files.
+/// Returns the resolved backup name and one diff per changed file; unchanged
+/// files are omitted.
+pub fn diff_steering_backup(
+    steering_repo: &dyn SteeringRepositoryInterface,
+    backup_query: &str,
+) -> Result<(String, Vec<SteeringFileDiff>), ApplicationError> {
+    let backup = resolve_backup_name(steering_repo, backup_query)?;
+
+    let backup_files = steering_repo.list_backup_files(&backup)?;
+    let files: BTreeSet<PathBuf> = backup_files
+        .iter()
+        .cloned()
+        .chain(steering_repo.list_steering_files()?)
+        .collect();
+
+    let mut diffs = Vec::new();
+
+    for file in files {
+        let old = if backup_files.contains(&file) {
+            Some(steering_repo.read_backup_file(&backup, &file)?)
+        } else {
+            None
+        };
+        let new = steering_repo.read_steering_file(&file)?;
+
+        let change = match (&old, &new) {
+            (Some(old), Some(new)) if old == new => continue,
+            (Some(_), Some(_)) => SteeringFileChange::Modified,
+            (None, Some(_)) => SteeringFileChange::Added,
+            (Some(_), None) => SteeringFileChange::Removed,
+            (None, None) => continue,
+        };
+
+        let diff = unified_diff(
+            old.as_deref().unwrap_or_default(),
+            new.as_deref().unwrap_or_default(),
+            &format!("backup/{}/{}", backup, file.display()),
+            &file.display().to_string(),
+        );
+
+        diffs.push(SteeringFileDiff { file, change, diff });
+    }
+
+    Ok((backup, diffs))
+}
+
+fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(old_label, new_label)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSteeringRepository;
+    use crate::domain::value_objects::steering_backup::BackupReason;
+
+    fn repo_with_backup() -> MockSteeringRepository {
+        let repo = MockSteeringRepository::new();
+        repo.set_steering_content("product", "# Product\n\nSame\n");
+        repo.set_steering_content("tech", "# Tech\n\n- Rust\n");
+        repo.set_steering_content("old", "# Old\n");
+        let files = repo.list_steering_files().unwrap();
+        repo.create_steering_backup("2025-01-01-10-00", &files, BackupReason::Manual)
+            .unwrap();
+        repo
+    }
+
+    #[test]
+    fn test_diff_reports_modified_files_as_unified_diff() {
+        let repo = repo_with_backup();
+        repo.set_steering_content("tech", "# Tech\n\n- Rust\n- TypeScript\n");
+
+        let (backup, diffs) = diff_steering_backup(&repo, "latest").unwrap();
+
+        assert_eq!(backup, "2025-01-01-10-00");
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].file, PathBuf::from("tech.md"));
+        assert_eq!(diffs[0].change, SteeringFileChange::Modified);
+        assert_eq!(
+            diffs[0].diff,
+            "--- backup/2025-01-01-10-00/tech.md\n+++ tech.md\n@@ -1,3 +1,4 @@\n # Tech\n \n - Rust\n+- TypeScript\n"
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_added_files() {
+        let repo = repo_with_backup();
+        repo.set_steering_content("security", "# Security\n");
+
+        let (_, diffs) = diff_steering_backup(&repo, "2025-01-01-10-00").unwrap();
+
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].file, PathBuf::from("security.md"));
+        assert_eq!(diffs[0].change, SteeringFileChange::Added);
+        assert!(diffs[0].diff.contains("+# Security"));
+    }
+
+    #[test]
+    fn test_diff_without_changes() {
+        let repo = repo_with_backup();
+        let (_, diffs) = diff_steering_backup(&repo, "latest").unwrap();
+        assert!(diffs.is_empty());
+    }
+
+    #[test]
+    fn test_diff_unknown_backup() {
+        let repo = repo_with_backup();
+        let result = diff_steering_backup(&repo, "2024-12-31-10-00");
+        assert!(matches!(result, Err(ApplicationError::BackupNotFound(_))));
+    }
+}
@@ -1,40 +1,90 @@
 use crate::application::errors::ApplicationError;
 use crate::application::repositories::{
-    ConfigRepositoryInterface, SpecRepositoryInterface, SteeringRepositoryInterface,
+    ConfigRepositoryInterface, SpecRepositoryInterface, SteeringFileScaffold,
+    SteeringRepositoryInterface,
 };
-use crate::domain::value_objects::steering::SteeringConfig;
+use crate::domain::value_objects::deployment::{DeploymentWarning, SkillUpgrade};
+use crate::domain::value_objects::file_change::FileChange;
+
+/// What `initialize_project` did to the project (or, with dry-run repositories, would do)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InitializationReport {
+    /// Files and directories created, modified or deleted, steering files excepted;
+    /// a path touched by several steps appears once with its first change
+    pub changes: Vec<FileChange>,
+    /// One entry per configured steering type
+    pub steering_files: Vec<SteeringFileScaffold>,
+    /// Deployed files left alone to protect local edits
+    pub warnings: Vec<DeploymentWarning>,
+    /// Deployed skills moved to the version this build ships
+    pub upgrades: Vec<SkillUpgrade>,
+}
+
+impl InitializationReport {
+    /// Steering files created by this run
+    pub fn created_steering_files(&self) -> impl Iterator<Item = &SteeringFileScaffold> {
+        self.steering_files
+            .iter()
+            .filter(|scaffold| scaffold.created)
+    }
+
+    /// Steering files that already existed and were left untouched
+    pub fn present_steering_files(&self) -> impl Iterator<Item = &SteeringFileScaffold> {
+        self.steering_files
+            .iter()
+            .filter(|scaffold| !scaffold.created)
+    }
+
+    /// Whether initialization changed (or would change) nothing
+    pub fn is_unchanged(&self) -> bool {
+        self.changes.is_empty() && self.created_steering_files().next().is_none()
+    }
+
+    fn record(&mut self, changes: Vec<FileChange>) {
+        for change in changes {
+            if !self.changes.iter().any(|c| c.path == change.path) {
+                self.changes.push(change);
+            }
+        }
+    }
+}
 
 pub fn initialize_project(
     config_repo: &dyn ConfigRepositoryInterface,
     _spec_repo: &dyn SpecRepositoryInterface,
     steering_repo: &dyn SteeringRepositoryInterface,
-) -> Result<(), ApplicationError> {
+) -> Result<InitializationReport, ApplicationError> {
+    let mut report = InitializationReport::default();
+
     // Initialize steering directories (idempotent)
-    steering_repo.initialize_steering()?;
+    report.record(steering_repo.initialize_steering()?);
 
     // Ensure steering configuration exists (idempotent)
-    config_repo.ensure_steering_config()?;
+    report.record(config_repo.ensure_steering_config()?);
 
     // Ensure steering backup configuration exists (idempotent)
-    config_repo.ensure_steering_backup_config()?;
+    report.record(config_repo.ensure_steering_backup_config()?);
 
     // Ensure allowed_operations exists for all steering types (idempotent)
-    config_repo.ensure_allowed_operations()?;
+    report.record(config_repo.ensure_allowed_operations()?);
 
     // Ensure spec configuration exists (idempotent)
-    config_repo.ensure_spec_config()?;
+    report.record(config_repo.ensure_spec_config()?);
 
-    // Create steering files (idempotent)
-    let steering_config = SteeringConfig::default_for_new_project();
-    steering_repo.create_steering_files(&steering_config)?;
+    // Create steering files for the configured types, custom ones included (idempotent)
+    let steering_config = config_repo.load_steering_config()?;
+    report.steering_files = steering_repo.create_steering_files(&steering_config)?;
 
     // Update .gitignore (idempotent)
-    steering_repo.update_gitignore()?;
+    report.record(steering_repo.update_gitignore()?);
 
-    // Deploy slash command markdown files (always overwrites)
-    steering_repo.deploy_slash_commands()?;
+    // Deploy slash commands and skills (updates only files hail-mary deployed)
+    let deployment = steering_repo.deploy_claude_resources()?;
+    report.record(deployment.changes);
+    report.warnings = deployment.warnings;
+    report.upgrades = deployment.upgrades;
 
-    Ok(())
+    Ok(report)
 }
 
 #[cfg(test)]
@@ -43,6 +93,8 @@ mod tests {
     use crate::application::test_helpers::{
         MockConfigRepository, MockSpecRepository, MockSteeringRepository,
     };
+    use crate::domain::value_objects::steering::SteeringConfig;
+    use std::path::PathBuf;
 
     #[test]
     fn test_initialize_project_success() {
@@ -54,6 +106,60 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_initialize_project_creates_files_for_configured_types() {
+        let mut steering_config = SteeringConfig::default_for_new_project();
+        steering_config.types.truncate(1);
+        let mut custom = steering_config.types[0].clone();
+        custom.name = "rust-dev".to_string();
+        steering_config.types.push(custom);
+
+        let config_repo = MockConfigRepository::with_steering_config(steering_config);
+        let spec_repo = MockSpecRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("product", "# product\n");
+
+        let report = initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+        assert_eq!(
+            report.steering_files,
+            vec![
+                SteeringFileScaffold {
+                    file: PathBuf::from("product.md"),
+                    created: false,
+                },
+                SteeringFileScaffold {
+                    file: PathBuf::from("rust-dev.md"),
+                    created: true,
+                },
+            ]
+        );
+        assert_eq!(report.created_steering_files().count(), 1);
+    }
+
+    #[test]
+    fn test_initialize_project_reports_each_path_once() {
+        let config_repo = MockConfigRepository::new();
+        config_repo.set_ensure_changes(vec![
+            FileChange::create(".kiro/"),
+            FileChange::create(".kiro/config.toml"),
+        ]);
+        let spec_repo = MockSpecRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+
+        let report = initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+        // Every ensure_* step reports config.toml, but it is listed once
+        assert_eq!(
+            report.changes,
+            vec![
+                FileChange::create(".kiro/"),
+                FileChange::create(".kiro/config.toml"),
+            ]
+        );
+        assert!(!report.is_unchanged());
+    }
+
     #[test]
     fn test_initialize_project_idempotent() {
         let config_repo = MockConfigRepository::new();
@@ -171,7 +277,7 @@ mod tests {
             let config_repo = MockConfigRepository::new();
             let spec_repo = MockSpecRepository::new();
             let steering_repo = MockSteeringRepository::new();
-            steering_repo.set_operation_to_fail("deploy_slash_commands");
+            steering_repo.set_operation_to_fail("deploy_claude_resources");
 
             let result = initialize_project(&config_repo, &spec_repo, &steering_repo);
             assert!(result.is_err());
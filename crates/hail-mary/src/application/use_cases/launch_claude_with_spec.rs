@@ -1,39 +1,77 @@
 use anyhow::Result;
-use std::io::{self, Write};
 use std::path::Path;
 
 use crate::application::errors::ApplicationError;
 use crate::application::repositories::{
-    ConfigRepositoryInterface, SpecRepositoryInterface,
-    steering_repository::SteeringRepositoryInterface,
+    ConfigRepositoryInterface, LauncherInterface, SpecPromptInterface, SpecRepositoryInterface,
+    SpecSelectionResult, steering_repository::SteeringRepositoryInterface,
 };
-use crate::domain::value_objects::spec::SpecValidator;
+use crate::application::use_cases::create_spec::{create_new_sbi, create_new_spec};
+use crate::application::use_cases::resolve_spec::{resolve_sbi_name, resolve_spec_name};
+use crate::domain::value_objects::launch_command::{LaunchCommand, SessionFlags};
+use crate::domain::value_objects::steering::Steerings;
 use crate::domain::value_objects::system_prompt::SystemPrompt;
-use crate::infrastructure::process::claude_launcher::ClaudeProcessLauncher;
-use crate::infrastructure::tui::spec_selector::{SpecSelectionResult, SpecSelectorTui};
+
+/// How the spec for a Claude session is chosen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecSelection {
+    /// Open the spec selector TUI
+    Interactive,
+    /// Use an existing spec (or an SBI within a PBI), resolved by name
+    Existing { spec: String, sbi: Option<String> },
+    /// Create a new spec with the given kebab-case name
+    New(String),
+    /// Launch without a spec
+    NoSpec,
+}
+
+impl SpecSelection {
+    /// Build a selection from `code` command flags; no flags means interactive
+    pub fn from_flags(
+        spec: Option<String>,
+        sbi: Option<String>,
+        new: Option<String>,
+        no_spec: bool,
+    ) -> Self {
+        match (spec, new) {
+            (Some(spec), _) => Self::Existing { spec, sbi },
+            (None, Some(name)) => Self::New(name),
+            (None, None) if no_spec => Self::NoSpec,
+            (None, None) => Self::Interactive,
+        }
+    }
+}
+
+/// Session flags and the project root the spec paths are made relative to
+#[derive(Debug, Clone, Copy)]
+pub struct LaunchOptions<'a> {
+    pub flags: SessionFlags,
+    pub project_root: &'a Path,
+}
 
 pub fn launch_claude_with_spec(
     spec_repo: &dyn SpecRepositoryInterface,
     config_repo: &dyn ConfigRepositoryInterface,
-    _steering_repo: &dyn SteeringRepositoryInterface,
-    no_danger: bool,
-    continue_conversation: bool,
-    project_root: &Path,
+    steering_repo: &dyn SteeringRepositoryInterface,
+    prompt: &dyn SpecPromptInterface,
+    launcher: &dyn LauncherInterface,
+    selection: SpecSelection,
+    options: LaunchOptions,
 ) -> Result<(), ApplicationError> {
     // 1. Load spec configuration
     let spec_config = config_repo.load_spec_config()?;
     let lang = &spec_config.lang;
 
-    // 2. Get list of specifications
-    let specs = spec_repo.list_spec_directories().map_err(|e| {
-        ApplicationError::FileSystemError(format!("Failed to list specifications: {}", e))
-    })?;
-
-    // 3. Run TUI for spec selection (includes new spec and SBI options)
-    let mut tui = SpecSelectorTui::new(specs, spec_repo);
-    let selection_result = tui
-        .run()
-        .map_err(|e| ApplicationError::FileSystemError(format!("TUI error: {}", e)))?;
+    // 2. Select the spec: run the TUI or resolve the requested names
+    let (selection_result, new_spec_name) = match selection {
+        SpecSelection::Interactive => (prompt.select_spec(spec_repo)?, None),
+        SpecSelection::Existing { spec, sbi } => (
+            resolve_existing_spec(spec_repo, &spec, sbi.as_deref())?,
+            None,
+        ),
+        SpecSelection::New(name) => (SpecSelectionResult::CreateNew, Some(name)),
+        SpecSelection::NoSpec => (SpecSelectionResult::NoSpec, None),
+    };
 
     let (spec_name, spec_path) = match selection_result {
         SpecSelectionResult::SingleSpec(name) => {
@@ -53,28 +91,19 @@ pub fn launch_claude_with_spec(
             (Some(sbi_name), Some(sbi_path))
         }
         SpecSelectionResult::CreateNew => {
-            // Prompt for name and create new spec
-            let name = prompt_for_spec_name()?;
-            SpecValidator::validate_spec_name(&name)?;
-            spec_repo.create_spec(&name, lang)?;
-
-            // Generate the actual directory name with date prefix
-            let date = chrono::Utc::now().format("%Y-%m-%d");
-            let full_name = format!("{}-{}", date, name);
+            // Use the requested name or prompt for one, then create new spec
+            let name = match new_spec_name {
+                Some(name) => name,
+                None => prompt.ask_spec_name()?,
+            };
+            let full_name = create_new_spec(spec_repo, &name, lang)?;
             let path = spec_repo.get_spec_path(&full_name)?;
             (Some(full_name), Some(path))
         }
         SpecSelectionResult::CreateNewSbi(pbi_name) => {
-            // Prompt for SBI name
-            let sbi_title = prompt_for_sbi_name()?;
-
-            // Auto-number SBI
-            let existing_sbis = spec_repo.list_sbis(&pbi_name)?;
-            let next_number = existing_sbis.len() + 1;
-            let sbi_name = format!("sbi-{}-{}", next_number, sbi_title);
-
-            // Create SBI (generates tasks.md and memo.md only)
-            spec_repo.create_sbi(&pbi_name, &sbi_name, lang)?;
+            // Prompt for SBI name and create it with the next number
+            let sbi_title = prompt.ask_sbi_name()?;
+            let sbi_name = create_new_sbi(spec_repo, &pbi_name, &sbi_title, lang)?;
 
             // Get SBI path
             let pbi_path = spec_repo.get_spec_path(&pbi_name)?;
@@ -87,64 +116,87 @@ pub fn launch_claude_with_spec(
         }
     };
 
-    // 4. Generate system prompt if spec is selected
+    // 3. Load steering files enabled for the system prompt
+    let steerings = load_prompt_steerings(config_repo, steering_repo)?;
+
+    // 4. Generate system prompt with spec context and/or steering sections
     let system_prompt = match (&spec_name, &spec_path) {
-        (Some(name), Some(path)) => Some(SystemPrompt::new(name, path)),
+        (Some(name), Some(path)) => Some(SystemPrompt::new(name, path, &steerings)),
+        _ if !steerings.0.is_empty() => Some(SystemPrompt::base(&steerings)),
         _ => None,
     };
 
     // 5. Compute relative spec path for plansDirectory (<spec-path>/plans)
     let plans_directory = spec_path
         .as_ref()
-        .and_then(|p| p.strip_prefix(project_root).ok())
+        .and_then(|p| p.strip_prefix(options.project_root).ok())
         .map(|p| p.join("plans").display().to_string());
 
-    // 6. Launch Claude with optional system prompt
-    let launcher = ClaudeProcessLauncher::new();
-    launcher
-        .launch(
-            system_prompt.as_ref().map(|p| p.as_str()),
-            no_danger,
-            continue_conversation,
-            plans_directory.as_deref(),
-        )
-        .map_err(|e| ApplicationError::ProcessLaunchError(e.to_string()))?;
-
-    Ok(())
+    // 6. Launch the configured agent with optional system prompt
+    let agent_config = config_repo.load_agent_config()?;
+    let command = LaunchCommand::for_agent(
+        &agent_config,
+        system_prompt.as_ref().map(|p| p.as_str()),
+        plans_directory.as_deref(),
+        options.flags,
+    );
+    launcher.launch(&command)
 }
 
-fn prompt_for_spec_name() -> Result<String, ApplicationError> {
-    print!("Enter specification name: ");
-    io::stdout()
-        .flush()
-        .map_err(|e| ApplicationError::FileSystemError(format!("Failed to flush stdout: {}", e)))?;
-
-    let mut name = String::new();
-    io::stdin()
-        .read_line(&mut name)
-        .map_err(|e| ApplicationError::FileSystemError(format!("Failed to read input: {}", e)))?;
+fn resolve_existing_spec(
+    spec_repo: &dyn SpecRepositoryInterface,
+    spec_query: &str,
+    sbi_query: Option<&str>,
+) -> Result<SpecSelectionResult, ApplicationError> {
+    let spec_name = resolve_spec_name(spec_repo, spec_query)?;
 
-    Ok(name.trim().to_string())
+    match sbi_query {
+        Some(sbi_query) => {
+            let sbi_name = resolve_sbi_name(spec_repo, &spec_name, sbi_query)?;
+            Ok(SpecSelectionResult::Sbi(spec_name, sbi_name))
+        }
+        None if spec_repo.is_pbi(&spec_name)? => Ok(SpecSelectionResult::Pbi(spec_name)),
+        None => Ok(SpecSelectionResult::SingleSpec(spec_name)),
+    }
 }
 
-fn prompt_for_sbi_name() -> Result<String, ApplicationError> {
-    print!("Enter SBI name (kebab-case): ");
-    io::stdout()
-        .flush()
-        .map_err(|e| ApplicationError::FileSystemError(format!("Failed to flush stdout: {}", e)))?;
-
-    let mut name = String::new();
-    io::stdin()
-        .read_line(&mut name)
-        .map_err(|e| ApplicationError::FileSystemError(format!("Failed to read input: {}", e)))?;
-
-    Ok(name.trim().to_string())
+fn load_prompt_steerings(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+) -> Result<Steerings, ApplicationError> {
+    let steering_config = config_repo.load_steering_config()?;
+    let steerings = steering_repo.load_steering_files(&steering_config.prompt_types())?;
+    Ok(Steerings(steerings))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::application::test_helpers::MockSpecRepository;
+    use crate::application::test_helpers::{
+        MockConfigRepository, MockSpecPrompt, MockSpecRepository, MockSteeringRepository,
+    };
+    use crate::domain::value_objects::steering::SteeringConfig;
+    use crate::infrastructure::process::recording_launcher::RecordingLauncher;
+
+    fn launch(
+        spec_repo: &MockSpecRepository,
+        prompt: &MockSpecPrompt,
+        launcher: &RecordingLauncher,
+        selection: SpecSelection,
+    ) -> Result<(), ApplicationError> {
+        launch_claude_with_spec(
+            spec_repo,
+            &MockConfigRepository::new(),
+            &MockSteeringRepository::new(),
+            prompt,
+            launcher,
+            selection,
+            LaunchOptions {
+                flags: SessionFlags::default(),
+                project_root: Path::new(""),
+            },
+        )
+    }
 
     #[test]
     fn test_launch_claude_with_existing_spec() {
@@ -171,4 +223,171 @@ mod tests {
             _ => panic!("Expected SpecNotFound error"),
         }
     }
+
+    #[test]
+    fn test_spec_selection_from_flags() {
+        assert_eq!(
+            SpecSelection::from_flags(None, None, None, false),
+            SpecSelection::Interactive
+        );
+        assert_eq!(
+            SpecSelection::from_flags(
+                Some("pbi".to_string()),
+                Some("sbi-2".to_string()),
+                None,
+                false
+            ),
+            SpecSelection::Existing {
+                spec: "pbi".to_string(),
+                sbi: Some("sbi-2".to_string()),
+            }
+        );
+        assert_eq!(
+            SpecSelection::from_flags(None, None, Some("new-feature".to_string()), false),
+            SpecSelection::New("new-feature".to_string())
+        );
+        assert_eq!(
+            SpecSelection::from_flags(None, None, None, true),
+            SpecSelection::NoSpec
+        );
+    }
+
+    #[test]
+    fn test_resolve_existing_spec_single_spec() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-09-09-test-spec".to_string()]);
+
+        let result = resolve_existing_spec(&repo, "test", None).unwrap();
+        assert_eq!(
+            result,
+            SpecSelectionResult::SingleSpec("2025-09-09-test-spec".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_existing_spec_pbi_and_sbi() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-09-09-pbi".to_string()]);
+        repo.add_sbi("2025-09-09-pbi", "sbi-1-setup");
+        repo.add_sbi("2025-09-09-pbi", "sbi-2-foo");
+
+        let result = resolve_existing_spec(&repo, "pbi", None).unwrap();
+        assert_eq!(
+            result,
+            SpecSelectionResult::Pbi("2025-09-09-pbi".to_string())
+        );
+
+        let result = resolve_existing_spec(&repo, "pbi", Some("sbi-2-foo")).unwrap();
+        assert_eq!(
+            result,
+            SpecSelectionResult::Sbi("2025-09-09-pbi".to_string(), "sbi-2-foo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_existing_spec_unknown_sbi() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-09-09-pbi".to_string()]);
+        repo.add_sbi("2025-09-09-pbi", "sbi-1-setup");
+
+        let result = resolve_existing_spec(&repo, "pbi", Some("missing"));
+        assert!(matches!(result, Err(ApplicationError::SpecNotFound(_))));
+    }
+
+    #[test]
+    fn test_load_prompt_steerings_respects_include_in_prompt() {
+        let mut config = SteeringConfig::default_for_new_project();
+        config.types[1].include_in_prompt = false;
+        let config_repo = MockConfigRepository::with_steering_config(config.clone());
+        let steering_repo =
+            MockSteeringRepository::with_steerings(&config, |name| format!("{} content", name));
+
+        let steerings = load_prompt_steerings(&config_repo, &steering_repo).unwrap();
+
+        let names: Vec<&str> = steerings
+            .0
+            .iter()
+            .map(|s| s.steering_type.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["product", "structure"]);
+        assert_eq!(steerings.0[0].content, "product content");
+    }
+
+    #[test]
+    fn test_load_prompt_steerings_propagates_errors() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_operation_to_fail("load_steering_files");
+
+        let result = load_prompt_steerings(&config_repo, &steering_repo);
+        assert!(matches!(result, Err(ApplicationError::FileSystemError(_))));
+    }
+
+    #[test]
+    fn test_launch_existing_spec_passes_plans_directory() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-09-09-test-spec".to_string()]);
+        let launcher = RecordingLauncher::new();
+        let prompt = MockSpecPrompt::selecting(SpecSelectionResult::Cancelled);
+
+        let selection = SpecSelection::Existing {
+            spec: "test-spec".to_string(),
+            sbi: None,
+        };
+        launch(&repo, &prompt, &launcher, selection).unwrap();
+
+        let command = launcher.last().unwrap();
+        assert_eq!(
+            command.settings(),
+            Some(serde_json::json!({
+                "plansDirectory": ".kiro/specs/2025-09-09-test-spec/plans"
+            }))
+        );
+        assert!(
+            command
+                .arg_value("--append-system-prompt")
+                .unwrap()
+                .contains("2025-09-09-test-spec")
+        );
+    }
+
+    #[test]
+    fn test_launch_cancelled_does_not_launch() {
+        let repo = MockSpecRepository::new();
+        let launcher = RecordingLauncher::new();
+        let prompt = MockSpecPrompt::selecting(SpecSelectionResult::Cancelled);
+
+        launch(&repo, &prompt, &launcher, SpecSelection::Interactive).unwrap();
+
+        assert!(launcher.launches().is_empty());
+    }
+
+    #[test]
+    fn test_launch_prompts_for_new_spec_name() {
+        let repo = MockSpecRepository::new();
+        let launcher = RecordingLauncher::new();
+        let prompt = MockSpecPrompt::selecting(SpecSelectionResult::CreateNew)
+            .with_answer("prompted-feature");
+
+        launch(&repo, &prompt, &launcher, SpecSelection::Interactive).unwrap();
+
+        let plans = launcher.last().unwrap().settings().unwrap();
+        assert!(
+            plans["plansDirectory"]
+                .as_str()
+                .unwrap()
+                .ends_with("-prompted-feature/plans")
+        );
+    }
+
+    #[test]
+    fn test_launch_propagates_launcher_error() {
+        let repo = MockSpecRepository::new();
+        let launcher = RecordingLauncher::failing("claude not found");
+        let prompt = MockSpecPrompt::selecting(SpecSelectionResult::NoSpec);
+
+        let result = launch(&repo, &prompt, &launcher, SpecSelection::Interactive);
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ProcessLaunchError(_))
+        ));
+        assert_eq!(launcher.launches().len(), 1);
+    }
 }
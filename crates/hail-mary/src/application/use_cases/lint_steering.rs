@@ -0,0 +1,109 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{ConfigRepositoryInterface, SteeringRepositoryInterface};
+use crate::domain::value_objects::steering_lint::{SteeringLintIssue, lint_steering_content};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Lint result for one steering file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteeringFileLint {
+    pub file: PathBuf,
+    pub issues: Vec<SteeringLintIssue>,
+}
+
+/// Checks every configured steering file against its criteria and flags
+/// steering files without a config entry. Results are sorted by file name.
+pub fn lint_steering(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+) -> Result<Vec<SteeringFileLint>, ApplicationError> {
+    let config = config_repo.load_steering_config()?;
+    let mut results = BTreeMap::new();
+
+    for steering_type in &config.types {
+        let file = PathBuf::from(format!("{}.md", steering_type.name));
+        let issues = match steering_repo.read_steering_file(&file)? {
+            Some(content) => lint_steering_content(steering_type, &content),
+            None => vec![SteeringLintIssue::MissingFile],
+        };
+        results.insert(file, issues);
+    }
+
+    for file in steering_repo.list_steering_files()? {
+        results
+            .entry(file)
+            .or_insert_with(|| vec![SteeringLintIssue::StaleFile]);
+    }
+
+    Ok(results
+        .into_iter()
+        .map(|(file, issues)| SteeringFileLint { file, issues })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockConfigRepository, MockSteeringRepository};
+    use crate::domain::value_objects::steering::SteeringConfig;
+
+    fn config_with_product_only() -> SteeringConfig {
+        let mut config = SteeringConfig::default_for_new_project();
+        config
+            .types
+            .retain(|steering_type| steering_type.name == "product");
+        config
+    }
+
+    #[test]
+    fn test_lint_steering_reports_per_file() {
+        let config = config_with_product_only();
+        let product = &config.types[0];
+        let content = product
+            .criteria
+            .iter()
+            .map(|criterion| format!("## {}\n\n{}\n", criterion.name, criterion.description))
+            .collect::<String>();
+
+        let config_repo = MockConfigRepository::with_steering_config(config.clone());
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("product", &format!("# Product\n\n{}", content));
+        steering_repo.set_steering_content("legacy", "# Legacy\n");
+
+        let results = lint_steering(&config_repo, &steering_repo).unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                SteeringFileLint {
+                    file: PathBuf::from("legacy.md"),
+                    issues: vec![SteeringLintIssue::StaleFile],
+                },
+                SteeringFileLint {
+                    file: PathBuf::from("product.md"),
+                    issues: vec![],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_steering_missing_file() {
+        let config_repo = MockConfigRepository::with_steering_config(config_with_product_only());
+        let steering_repo = MockSteeringRepository::new();
+
+        let results = lint_steering(&config_repo, &steering_repo).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].issues, vec![SteeringLintIssue::MissingFile]);
+    }
+
+    #[test]
+    fn test_lint_steering_config_error() {
+        let config_repo = MockConfigRepository::new();
+        config_repo.set_operation_to_fail("load_steering_config");
+        let steering_repo = MockSteeringRepository::new();
+
+        assert!(lint_steering(&config_repo, &steering_repo).is_err());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SpecRepositoryInterface;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecListEntry {
+    pub name: String,
+    /// SBIs when the spec is a PBI, empty otherwise
+    pub sbis: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SpecListing {
+    pub active: Vec<SpecListEntry>,
+    pub archived: Vec<String>,
+}
+
+/// Lists active specifications (with PBI→SBI nesting) and archived specifications
+pub fn list_specs(
+    spec_repo: &dyn SpecRepositoryInterface,
+) -> Result<SpecListing, ApplicationError> {
+    let mut active = Vec::new();
+
+    for (name, is_archived) in spec_repo.list_spec_directories()? {
+        if is_archived {
+            continue;
+        }
+
+        let sbis = spec_repo.list_sbis(&name)?;
+        active.push(SpecListEntry { name, sbis });
+    }
+
+    let archived = spec_repo.list_archived_specs()?;
+
+    Ok(SpecListing { active, archived })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockSpecRepository, TestDirectory};
+    use crate::infrastructure::filesystem::path_manager::PathManager;
+    use crate::infrastructure::repositories::spec::SpecRepository as ConcreteSpecRepository;
+    use std::fs;
+
+    #[test]
+    fn test_list_specs_with_pbi_and_archive() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        let specs_dir = path_manager.specs_dir(true);
+        fs::create_dir_all(specs_dir.join("2025-01-01-single")).unwrap();
+        fs::create_dir_all(specs_dir.join("2025-01-02-pbi").join("sbi-1-api")).unwrap();
+        fs::create_dir_all(specs_dir.join("2025-01-02-pbi").join("sbi-2-ui")).unwrap();
+        fs::create_dir_all(path_manager.archive_dir(true).join("2024-12-01-old")).unwrap();
+
+        let listing = list_specs(&spec_repo).unwrap();
+
+        assert_eq!(
+            listing.active,
+            vec![
+                SpecListEntry {
+                    name: "2025-01-02-pbi".to_string(),
+                    sbis: vec!["sbi-1-api".to_string(), "sbi-2-ui".to_string()],
+                },
+                SpecListEntry {
+                    name: "2025-01-01-single".to_string(),
+                    sbis: vec![],
+                },
+            ]
+        );
+        assert_eq!(listing.archived, vec!["2024-12-01-old".to_string()]);
+    }
+
+    #[test]
+    fn test_list_specs_propagates_errors() {
+        let spec_repo = MockSpecRepository::new();
+        spec_repo.set_operation_to_fail("list_archived_specs");
+
+        let result = list_specs(&spec_repo);
+        assert!(matches!(result, Err(ApplicationError::FileSystemError(_))));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SteeringRepositoryInterface;
+use crate::domain::value_objects::steering_backup::BackupReason;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// A steering backup with the files it contains
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteeringBackupEntry {
+    pub name: String,
+    pub created_at: SystemTime,
+    /// Why the backup was made (None for backups without a manifest)
+    pub reason: Option<BackupReason>,
+    pub files: Vec<PathBuf>,
+}
+
+/// Lists steering backups, oldest first
+pub fn list_steering_backups(
+    steering_repo: &dyn SteeringRepositoryInterface,
+) -> Result<Vec<SteeringBackupEntry>, ApplicationError> {
+    steering_repo
+        .list_steering_backups()?
+        .into_iter()
+        .map(|backup| {
+            Ok(SteeringBackupEntry {
+                reason: steering_repo
+                    .load_backup_manifest(&backup.name)?
+                    .map(|manifest| manifest.reason),
+                files: steering_repo.list_backup_files(&backup.name)?,
+                name: backup.name,
+                created_at: backup.created_at,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSteeringRepository;
+
+    #[test]
+    fn test_list_steering_backups_with_files() {
+        let repo = MockSteeringRepository::new();
+        repo.set_steering_content("product", "# Product");
+        repo.set_steering_content("tech", "# Tech");
+        let files = repo.list_steering_files().unwrap();
+        repo.create_steering_backup("2025-01-01-10-00", &files, BackupReason::Manual)
+            .unwrap();
+
+        let backups = list_steering_backups(&repo).unwrap();
+
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].name, "2025-01-01-10-00");
+        assert_eq!(backups[0].reason, Some(BackupReason::Manual));
+        assert_eq!(
+            backups[0].files,
+            vec![PathBuf::from("product.md"), PathBuf::from("tech.md")]
+        );
+    }
+
+    #[test]
+    fn test_list_steering_backups_empty() {
+        let repo = MockSteeringRepository::new();
+        assert!(list_steering_backups(&repo).unwrap().is_empty());
+    }
+}
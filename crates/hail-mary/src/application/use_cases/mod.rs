@@ -1,10 +1,39 @@
 pub mod backup_steering;
 pub mod complete_specs;
+pub mod create_spec;
+pub mod diff_steering_backup;
 pub mod initialize_project;
 pub mod launch_claude_with_spec;
+pub mod lint_steering;
+pub mod list_specs;
+pub mod list_steering_backups;
+pub mod rename_spec;
+pub mod resolve_backup;
+pub mod resolve_spec;
+pub mod restore_steering_backup;
+pub mod show_spec;
+pub mod spec_status;
+pub mod steering_types;
+pub mod unarchive_specs;
 
 // Re-export use case functions for easier access
 pub use backup_steering::backup_steering;
 pub use complete_specs::complete_specs;
-pub use initialize_project::initialize_project;
-pub use launch_claude_with_spec::launch_claude_with_spec;
+pub use create_spec::{create_new_sbi, create_new_spec};
+pub use diff_steering_backup::{SteeringFileChange, SteeringFileDiff, diff_steering_backup};
+pub use initialize_project::{InitializationReport, initialize_project};
+pub use launch_claude_with_spec::{LaunchOptions, SpecSelection, launch_claude_with_spec};
+pub use lint_steering::{SteeringFileLint, lint_steering};
+pub use list_specs::{SpecListEntry, SpecListing, list_specs};
+pub use list_steering_backups::{SteeringBackupEntry, list_steering_backups};
+pub use rename_spec::rename_spec;
+pub use resolve_backup::resolve_backup_name;
+pub use resolve_spec::{resolve_archived_spec_name, resolve_sbi_name, resolve_spec_name};
+pub use restore_steering_backup::{RestoreReport, restore_steering_backup};
+pub use show_spec::{SpecOverview, show_spec};
+pub use spec_status::{SpecStatus, spec_status};
+pub use steering_types::{
+    SteeringTypeEdit, SteeringTypeEntry, SteeringTypeRemoval, add_steering_type,
+    edit_steering_type, list_steering_types, remove_steering_type,
+};
+pub use unarchive_specs::{UnarchiveReport, unarchive_specs};
@@ -0,0 +1,76 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SpecRepositoryInterface;
+use crate::application::use_cases::resolve_spec::resolve_spec_name;
+use crate::domain::value_objects::spec::{SpecMatcher, SpecValidator};
+
+/// Renames an active specification, keeping its date prefix
+///
+/// Returns `(old_name, new_name)` as full directory names.
+pub fn rename_spec(
+    spec_repo: &dyn SpecRepositoryInterface,
+    query: &str,
+    new_name: &str,
+) -> Result<(String, String), ApplicationError> {
+    SpecValidator::validate_spec_name(new_name)?;
+
+    let old_name = resolve_spec_name(spec_repo, query)?;
+    let date_prefix = &old_name[..old_name.len() - SpecMatcher::strip_date_prefix(&old_name).len()];
+    let new_full_name = format!("{}{}", date_prefix, new_name);
+
+    spec_repo.rename_spec(&old_name, &new_full_name)?;
+
+    Ok((old_name, new_full_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSpecRepository;
+    use crate::domain::errors::DomainError;
+
+    #[test]
+    fn test_rename_spec_keeps_date_prefix() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-01-01-old-name".to_string()]);
+
+        let (old, new) = rename_spec(&repo, "old", "new-name").unwrap();
+
+        assert_eq!(old, "2025-01-01-old-name");
+        assert_eq!(new, "2025-01-01-new-name");
+        assert_eq!(repo.get_created_specs(), vec!["2025-01-01-new-name"]);
+    }
+
+    #[test]
+    fn test_rename_spec_without_date_prefix() {
+        let repo = MockSpecRepository::with_specs(vec!["legacy".to_string()]);
+
+        let (_, new) = rename_spec(&repo, "legacy", "modern").unwrap();
+        assert_eq!(new, "modern");
+    }
+
+    #[test]
+    fn test_rename_spec_rejects_invalid_name() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-01-01-old-name".to_string()]);
+
+        let result = rename_spec(&repo, "old", "Bad_Name");
+        assert!(matches!(
+            result,
+            Err(ApplicationError::DomainError(DomainError::InvalidSpecName(
+                _
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_rename_spec_collision() {
+        let repo = MockSpecRepository::with_specs(vec![
+            "2025-01-01-old-name".to_string(),
+            "2025-01-01-taken".to_string(),
+        ]);
+
+        let result = rename_spec(&repo, "old", "taken");
+        assert!(matches!(
+            result,
+            Err(ApplicationError::SpecAlreadyExists(_))
+        ));
+    }
+}
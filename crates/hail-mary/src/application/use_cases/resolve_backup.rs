@@ -0,0 +1,65 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SteeringRepositoryInterface;
+
+/// Resolves a backup reference: an exact backup name, or `latest` for the newest backup
+pub fn resolve_backup_name(
+    steering_repo: &dyn SteeringRepositoryInterface,
+    query: &str,
+) -> Result<String, ApplicationError> {
+    let backups = steering_repo.list_steering_backups()?;
+
+    let found = if query == "latest" {
+        backups.last()
+    } else {
+        backups.iter().find(|backup| backup.name == query)
+    };
+
+    found
+        .map(|backup| backup.name.clone())
+        .ok_or_else(|| ApplicationError::BackupNotFound(query.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSteeringRepository;
+    use crate::domain::value_objects::steering_backup::BackupReason;
+    use std::path::PathBuf;
+
+    fn repo_with_backups(names: &[&str]) -> MockSteeringRepository {
+        let repo = MockSteeringRepository::with_steering_files(vec![PathBuf::from("tech.md")]);
+        for name in names {
+            repo.create_steering_backup(name, &[PathBuf::from("tech.md")], BackupReason::Manual)
+                .unwrap();
+        }
+        repo
+    }
+
+    #[test]
+    fn test_resolve_backup_name_exact_and_latest() {
+        let repo = repo_with_backups(&["2025-01-01-10-00", "2025-01-02-10-00"]);
+
+        assert_eq!(
+            resolve_backup_name(&repo, "2025-01-01-10-00").unwrap(),
+            "2025-01-01-10-00"
+        );
+        assert_eq!(
+            resolve_backup_name(&repo, "latest").unwrap(),
+            "2025-01-02-10-00"
+        );
+    }
+
+    #[test]
+    fn test_resolve_backup_name_not_found() {
+        let repo = repo_with_backups(&[]);
+
+        assert!(matches!(
+            resolve_backup_name(&repo, "latest"),
+            Err(ApplicationError::BackupNotFound(_))
+        ));
+        assert!(matches!(
+            resolve_backup_name(&repo, "2025-01-01"),
+            Err(ApplicationError::BackupNotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SpecRepositoryInterface;
+use crate::domain::value_objects::spec::SpecMatcher;
+
+/// Resolve a user-supplied query to a single active spec directory name
+///
+/// Matches exact names, prefixes, substrings and fuzzy subsequences against
+/// both the full date-prefixed name and the name without its date prefix.
+pub fn resolve_spec_name(
+    spec_repo: &dyn SpecRepositoryInterface,
+    query: &str,
+) -> Result<String, ApplicationError> {
+    let specs: Vec<String> = spec_repo
+        .list_spec_directories()?
+        .into_iter()
+        .filter(|(_, is_archived)| !is_archived)
+        .map(|(name, _)| name)
+        .collect();
+
+    pick_single_match(query, &specs)
+}
+
+/// Resolve a user-supplied query to a single archived spec directory name
+pub fn resolve_archived_spec_name(
+    spec_repo: &dyn SpecRepositoryInterface,
+    query: &str,
+) -> Result<String, ApplicationError> {
+    let archived = spec_repo.list_archived_specs()?;
+    pick_single_match(query, &archived)
+}
+
+/// Resolve a user-supplied query to a single SBI name within a PBI
+pub fn resolve_sbi_name(
+    spec_repo: &dyn SpecRepositoryInterface,
+    pbi_name: &str,
+    query: &str,
+) -> Result<String, ApplicationError> {
+    let sbis = spec_repo.list_sbis(pbi_name)?;
+    pick_single_match(query, &sbis)
+}
+
+fn pick_single_match(query: &str, candidates: &[String]) -> Result<String, ApplicationError> {
+    let mut matches = SpecMatcher::find_matches(query, candidates);
+    match matches.len() {
+        0 => Err(ApplicationError::SpecNotFound(query.to_string())),
+        1 => Ok(matches.remove(0)),
+        _ => Err(ApplicationError::AmbiguousSpecName(
+            query.to_string(),
+            matches,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSpecRepository;
+
+    fn repo() -> MockSpecRepository {
+        let repo = MockSpecRepository::with_specs(vec![
+            "2025-10-01-login".to_string(),
+            "2025-10-02-login-v2".to_string(),
+            "2025-10-03-payment-flow".to_string(),
+            "2025-10-04-payment-refund".to_string(),
+        ]);
+        repo.add_sbi("2025-10-03-payment-flow", "sbi-1-checkout");
+        repo.add_sbi("2025-10-03-payment-flow", "sbi-2-invoice");
+        repo
+    }
+
+    #[test]
+    fn test_resolve_spec_name_exact_without_date() {
+        let result = resolve_spec_name(&repo(), "login");
+        assert_eq!(result.unwrap(), "2025-10-01-login");
+    }
+
+    #[test]
+    fn test_resolve_spec_name_prefix() {
+        let result = resolve_spec_name(&repo(), "payment-f");
+        assert_eq!(result.unwrap(), "2025-10-03-payment-flow");
+    }
+
+    #[test]
+    fn test_resolve_spec_name_ambiguous() {
+        let result = resolve_spec_name(&repo(), "payment");
+        match result.unwrap_err() {
+            ApplicationError::AmbiguousSpecName(query, candidates) => {
+                assert_eq!(query, "payment");
+                assert_eq!(candidates.len(), 2);
+            }
+            other => panic!("Expected AmbiguousSpecName, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolve_spec_name_not_found() {
+        let result = resolve_spec_name(&repo(), "nonexistent");
+        assert!(matches!(result, Err(ApplicationError::SpecNotFound(_))));
+    }
+
+    #[test]
+    fn test_resolve_archived_spec_name() {
+        let repo = repo();
+        repo.add_archived_spec("2025-09-01-old-login");
+
+        let result = resolve_archived_spec_name(&repo, "old");
+        assert_eq!(result.unwrap(), "2025-09-01-old-login");
+
+        // Active specs are not considered
+        let result = resolve_archived_spec_name(&repo, "payment-flow");
+        assert!(matches!(result, Err(ApplicationError::SpecNotFound(_))));
+    }
+
+    #[test]
+    fn test_resolve_sbi_name() {
+        let result = resolve_sbi_name(&repo(), "2025-10-03-payment-flow", "invoice");
+        assert_eq!(result.unwrap(), "sbi-2-invoice");
+
+        let result = resolve_sbi_name(&repo(), "2025-10-03-payment-flow", "sbi");
+        assert!(matches!(
+            result,
+            Err(ApplicationError::AmbiguousSpecName(_, _))
+        ));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{ConfigRepositoryInterface, SteeringRepositoryInterface};
+use crate::application::use_cases::backup_steering::snapshot_steering;
+use crate::application::use_cases::resolve_backup::resolve_backup_name;
+use crate::domain::value_objects::steering_backup::BackupReason;
+use std::path::PathBuf;
+
+/// Outcome of restoring steering files from a backup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreReport {
+    /// Backup the files were restored from
+    pub backup: String,
+    /// Backup holding the state before restoring: a new one, or the latest backup when
+    /// nothing had changed since (None when there were no steering files)
+    pub safety_backup: Option<String>,
+    pub restored: Vec<PathBuf>,
+}
+
+/// Restores steering files from a backup (exact name or `latest`), either all of
+/// them or only `file` (`tech` or `tech.md`). The current files are backed up
+/// first; current files missing from the backup are left untouched.
+pub fn restore_steering_backup(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+    backup_query: &str,
+    file: Option<&str>,
+) -> Result<RestoreReport, ApplicationError> {
+    let backup = resolve_backup_name(steering_repo, backup_query)?;
+    let backup_files = steering_repo.list_backup_files(&backup)?;
+
+    let files = match file {
+        Some(file) => {
+            let file = normalize_file_name(file);
+            if !backup_files.contains(&file) {
+                return Err(ApplicationError::FileSystemError(format!(
+                    "File {} not found in backup {}",
+                    file.display(),
+                    backup
+                )));
+            }
+            vec![file]
+        }
+        None => backup_files,
+    };
+
+    // Read everything up front: rotation during the safety backup may delete the
+    // backup being restored
+    let contents = files
+        .iter()
+        .map(|file| Ok((file.clone(), steering_repo.read_backup_file(&backup, file)?)))
+        .collect::<Result<Vec<_>, ApplicationError>>()?;
+
+    let safety_backup = snapshot_steering(config_repo, steering_repo, BackupReason::BeforeRestore)?
+        .backup_name()
+        .map(str::to_string);
+
+    for (file, content) in &contents {
+        steering_repo.write_steering_file(file, content)?;
+    }
+
+    Ok(RestoreReport {
+        backup,
+        safety_backup,
+        restored: files,
+    })
+}
+
+fn normalize_file_name(file: &str) -> PathBuf {
+    if file.ends_with(".md") {
+        PathBuf::from(file)
+    } else {
+        PathBuf::from(format!("{}.md", file))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockConfigRepository, MockSteeringRepository};
+    use crate::domain::value_objects::steering::{SteeringBackupConfig, SteeringConfig};
+
+    const BACKUP: &str = "2025-01-01-10-00";
+
+    fn repo_with_backup() -> MockSteeringRepository {
+        let repo = MockSteeringRepository::new();
+        repo.set_steering_content("product", "# Product v1\n");
+        repo.set_steering_content("tech", "# Tech v1\n");
+        let files = repo.list_steering_files().unwrap();
+        repo.create_steering_backup(BACKUP, &files, BackupReason::Manual)
+            .unwrap();
+
+        repo.set_steering_content("product", "# Product v2\n");
+        repo.set_steering_content("tech", "# Tech v2\n");
+        repo
+    }
+
+    #[test]
+    fn test_restore_all_files_after_safety_backup() {
+        let config_repo = MockConfigRepository::new();
+        let repo = repo_with_backup();
+
+        let report = restore_steering_backup(&config_repo, &repo, BACKUP, None).unwrap();
+
+        assert_eq!(report.backup, BACKUP);
+        assert_eq!(
+            report.restored,
+            vec![PathBuf::from("product.md"), PathBuf::from("tech.md")]
+        );
+        assert_eq!(repo.steering_content("product").unwrap(), "# Product v1\n");
+        assert_eq!(repo.steering_content("tech").unwrap(), "# Tech v1\n");
+
+        // The pre-restore state was saved in a new backup
+        let safety = report.safety_backup.unwrap();
+        assert_eq!(repo.backup_names().len(), 2);
+        assert_eq!(
+            repo.read_backup_file(&safety, &PathBuf::from("tech.md"))
+                .unwrap(),
+            "# Tech v2\n"
+        );
+    }
+
+    #[test]
+    fn test_restore_single_file() {
+        let config_repo = MockConfigRepository::new();
+        let repo = repo_with_backup();
+
+        let report = restore_steering_backup(&config_repo, &repo, "latest", Some("tech")).unwrap();
+
+        assert_eq!(report.restored, vec![PathBuf::from("tech.md")]);
+        assert_eq!(repo.steering_content("tech").unwrap(), "# Tech v1\n");
+        assert_eq!(repo.steering_content("product").unwrap(), "# Product v2\n");
+    }
+
+    #[test]
+    fn test_restore_unknown_file_changes_nothing() {
+        let config_repo = MockConfigRepository::new();
+        let repo = repo_with_backup();
+
+        let result = restore_steering_backup(&config_repo, &repo, BACKUP, Some("security.md"));
+
+        assert!(matches!(result, Err(ApplicationError::FileSystemError(_))));
+        assert_eq!(repo.backup_names(), vec![BACKUP.to_string()]);
+        assert_eq!(repo.steering_content("tech").unwrap(), "# Tech v2\n");
+    }
+
+    #[test]
+    fn test_restore_survives_rotation_of_restored_backup() {
+        let mut config = SteeringConfig::default_for_new_project();
+        config.backup = SteeringBackupConfig { max: 1 };
+        let config_repo = MockConfigRepository::with_steering_config(config);
+        let repo = repo_with_backup();
+
+        let report = restore_steering_backup(&config_repo, &repo, BACKUP, None).unwrap();
+
+        // The only backup slot now holds the safety backup
+        assert_eq!(repo.backup_names(), vec![report.safety_backup.unwrap()]);
+        assert_eq!(repo.steering_content("tech").unwrap(), "# Tech v1\n");
+    }
+}
@@ -0,0 +1,118 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{SpecFileInfo, SpecRepositoryInterface};
+use crate::application::use_cases::resolve_spec::{resolve_sbi_name, resolve_spec_name};
+use crate::domain::value_objects::tasks::TaskProgress;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub struct SpecOverview {
+    /// Spec directory name, or `pbi/sbi` for an SBI
+    pub name: String,
+    pub path: PathBuf,
+    pub files: Vec<SpecFileInfo>,
+    pub sbis: Vec<String>,
+    /// Checkbox progress from tasks.md, None if the spec has no tasks.md
+    pub progress: Option<TaskProgress>,
+}
+
+/// Collects the file inventory and task progress of a specification (or SBI)
+pub fn show_spec(
+    spec_repo: &dyn SpecRepositoryInterface,
+    spec_query: &str,
+    sbi_query: Option<&str>,
+) -> Result<SpecOverview, ApplicationError> {
+    let spec_name = resolve_spec_name(spec_repo, spec_query)?;
+
+    let (name, sbis) = match sbi_query {
+        Some(sbi_query) => {
+            let sbi_name = resolve_sbi_name(spec_repo, &spec_name, sbi_query)?;
+            (format!("{}/{}", spec_name, sbi_name), Vec::new())
+        }
+        None => {
+            let sbis = spec_repo.list_sbis(&spec_name)?;
+            (spec_name, sbis)
+        }
+    };
+
+    let path = spec_repo.get_spec_path(&name)?;
+    let files = spec_repo.list_spec_files(&name)?;
+    let progress = spec_repo
+        .read_spec_file(&name, "tasks.md")?
+        .map(|content| TaskProgress::from_markdown(&content));
+
+    Ok(SpecOverview {
+        name,
+        path,
+        files,
+        sbis,
+        progress,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockSpecRepository, TestDirectory};
+    use crate::infrastructure::filesystem::path_manager::PathManager;
+    use crate::infrastructure::repositories::spec::SpecRepository as ConcreteSpecRepository;
+    use std::fs;
+
+    #[test]
+    fn test_show_spec_inventory_and_progress() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        let spec_dir = path_manager.specs_dir(true).join("2025-01-01-feature");
+        fs::create_dir_all(spec_dir.join("sbi-1-api")).unwrap();
+        fs::write(spec_dir.join("tasks.md"), "- [x] one\n- [ ] two\n").unwrap();
+        fs::write(spec_dir.join("memo.md"), "# Memo").unwrap();
+
+        let overview = show_spec(&spec_repo, "feature", None).unwrap();
+
+        assert_eq!(overview.name, "2025-01-01-feature");
+        assert_eq!(overview.path, spec_dir);
+        let file_names: Vec<&str> = overview.files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(file_names, vec!["memo.md", "tasks.md"]);
+        assert_eq!(overview.files[0].size, 6);
+        assert_eq!(overview.sbis, vec!["sbi-1-api".to_string()]);
+        assert_eq!(
+            overview.progress,
+            Some(TaskProgress {
+                completed: 1,
+                total: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_show_spec_sbi() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        let sbi_dir = path_manager
+            .specs_dir(true)
+            .join("2025-01-01-pbi")
+            .join("sbi-1-api");
+        fs::create_dir_all(&sbi_dir).unwrap();
+        fs::write(sbi_dir.join("tasks.md"), "- [x] done\n").unwrap();
+
+        let overview = show_spec(&spec_repo, "pbi", Some("api")).unwrap();
+
+        assert_eq!(overview.name, "2025-01-01-pbi/sbi-1-api");
+        assert_eq!(overview.path, sbi_dir);
+        assert_eq!(overview.progress.unwrap().percentage(), 100);
+    }
+
+    #[test]
+    fn test_show_spec_without_tasks() {
+        let spec_repo = MockSpecRepository::with_specs(vec!["2025-01-01-feature".to_string()]);
+        spec_repo.add_file("2025-01-01-feature", "memo.md", "# Memo");
+
+        let overview = show_spec(&spec_repo, "feature", None).unwrap();
+
+        assert_eq!(overview.files.len(), 1);
+        assert!(overview.progress.is_none());
+    }
+}
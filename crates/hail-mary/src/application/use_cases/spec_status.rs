@@ -0,0 +1,162 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::SpecRepositoryInterface;
+use crate::application::use_cases::resolve_spec::resolve_spec_name;
+use crate::domain::value_objects::tasks::{DocumentState, TaskProgress, TasksDocument};
+use serde::Serialize;
+
+/// Progress report for one spec (or `pbi/sbi`) derived from its tasks.md
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SpecStatus {
+    pub name: String,
+    /// False when the spec has no tasks.md
+    pub has_tasks: bool,
+    pub progress: TaskProgress,
+    pub percentage: u8,
+    pub next_action: Option<String>,
+    pub documents: Vec<DocumentState>,
+}
+
+impl SpecStatus {
+    fn new(name: String, tasks: Option<TasksDocument>) -> Self {
+        let document = tasks.clone().unwrap_or_default();
+        let progress = document.progress();
+
+        Self {
+            name,
+            has_tasks: tasks.is_some(),
+            progress,
+            percentage: progress.percentage(),
+            next_action: document.next_action(),
+            documents: document.states,
+        }
+    }
+}
+
+/// Collects task progress for one spec (resolved by name) or for all active specs.
+/// SBIs of a PBI are reported as separate `pbi/sbi` entries after their PBI.
+pub fn spec_status(
+    spec_repo: &dyn SpecRepositoryInterface,
+    query: Option<&str>,
+) -> Result<Vec<SpecStatus>, ApplicationError> {
+    let spec_names = match query {
+        Some(query) => vec![resolve_spec_name(spec_repo, query)?],
+        None => spec_repo
+            .list_spec_directories()?
+            .into_iter()
+            .filter(|(_, is_archived)| !is_archived)
+            .map(|(name, _)| name)
+            .collect(),
+    };
+
+    let mut statuses = Vec::new();
+
+    for spec_name in spec_names {
+        let sbis = spec_repo.list_sbis(&spec_name)?;
+        statuses.push(load_status(spec_repo, spec_name.clone())?);
+
+        for sbi in sbis {
+            statuses.push(load_status(spec_repo, format!("{}/{}", spec_name, sbi))?);
+        }
+    }
+
+    Ok(statuses)
+}
+
+fn load_status(
+    spec_repo: &dyn SpecRepositoryInterface,
+    name: String,
+) -> Result<SpecStatus, ApplicationError> {
+    let tasks = spec_repo
+        .read_spec_file(&name, "tasks.md")?
+        .map(|content| TasksDocument::parse(&content));
+
+    Ok(SpecStatus::new(name, tasks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSpecRepository;
+
+    const TASKS: &str = r#"# Tasks
+
+## State Tracking
+
+| Document | Status | Coverage | Next Action |
+|----------|--------|----------|-------------|
+| requirements.md | pending | - | Define requirements |
+
+## Timeline
+
+- [x] Spec created → feature
+- [ ] Requirements definition
+"#;
+
+    #[test]
+    fn test_spec_status_single_spec() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-01-01-feature".to_string()]);
+        repo.add_file("2025-01-01-feature", "tasks.md", TASKS);
+
+        let statuses = spec_status(&repo, Some("feature")).unwrap();
+
+        assert_eq!(statuses.len(), 1);
+        let status = &statuses[0];
+        assert_eq!(status.name, "2025-01-01-feature");
+        assert!(status.has_tasks);
+        assert_eq!(status.percentage, 50);
+        assert_eq!(status.next_action.as_deref(), Some("Define requirements"));
+        assert_eq!(status.documents.len(), 1);
+    }
+
+    #[test]
+    fn test_spec_status_all_specs_with_sbis() {
+        let repo = MockSpecRepository::with_specs(vec![
+            "2025-01-01-feature".to_string(),
+            "2025-01-02-pbi".to_string(),
+        ]);
+        repo.add_sbi("2025-01-02-pbi", "sbi-1-api");
+        repo.add_file(
+            "2025-01-02-pbi/sbi-1-api",
+            "tasks.md",
+            "## Timeline\n\n- [x] done\n",
+        );
+
+        let mut names: Vec<_> = spec_status(&repo, None)
+            .unwrap()
+            .into_iter()
+            .map(|status| (status.name, status.has_tasks, status.percentage))
+            .collect();
+        names.sort();
+
+        assert_eq!(
+            names,
+            vec![
+                ("2025-01-01-feature".to_string(), false, 0),
+                ("2025-01-02-pbi".to_string(), false, 0),
+                ("2025-01-02-pbi/sbi-1-api".to_string(), true, 100),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spec_status_unknown_spec() {
+        let repo = MockSpecRepository::new();
+        let result = spec_status(&repo, Some("missing"));
+        assert!(matches!(result, Err(ApplicationError::SpecNotFound(_))));
+    }
+
+    #[test]
+    fn test_spec_status_serializes_to_json() {
+        let status = SpecStatus::new(
+            "2025-01-01-feature".to_string(),
+            Some(TasksDocument::parse(TASKS)),
+        );
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["name"], "2025-01-01-feature");
+        assert_eq!(json["progress"]["completed"], 1);
+        assert_eq!(json["progress"]["total"], 2);
+        assert_eq!(json["percentage"], 50);
+        assert_eq!(json["documents"][0]["document"], "requirements.md");
+    }
+}
@@ -0,0 +1,389 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{ConfigRepositoryInterface, SteeringRepositoryInterface};
+use crate::application::use_cases::backup_steering::snapshot_steering;
+use crate::domain::value_objects::steering::{SteeringBackupConfig, SteeringConfig, SteeringType};
+use crate::domain::value_objects::steering_backup::BackupReason;
+use std::path::PathBuf;
+
+/// A configured steering type and whether its markdown file exists
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteeringTypeEntry {
+    pub steering_type: SteeringType,
+    pub file_exists: bool,
+}
+
+/// Changes for `steering type edit`; `None` keeps the current value
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SteeringTypeEdit {
+    pub name: Option<String>,
+    pub purpose: Option<String>,
+    /// Replacement criteria in "Name: Description" format
+    pub criteria: Option<Vec<String>>,
+    pub include_in_prompt: Option<bool>,
+}
+
+/// Outcome of removing a steering type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteeringTypeRemoval {
+    /// Backup holding the removed file (None when there was nothing to back up)
+    pub backup: Option<String>,
+    pub file_removed: bool,
+}
+
+/// Lists the configured steering types in config order
+pub fn list_steering_types(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+) -> Result<Vec<SteeringTypeEntry>, ApplicationError> {
+    let config = config_repo.load_steering_config()?;
+    let files = steering_repo.list_steering_files()?;
+
+    Ok(config
+        .types
+        .into_iter()
+        .map(|steering_type| SteeringTypeEntry {
+            file_exists: files.contains(&file_for(&steering_type.name)),
+            steering_type,
+        })
+        .collect())
+}
+
+/// Adds a steering type to config.toml and creates its markdown file
+pub fn add_steering_type(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+    name: &str,
+    purpose: &str,
+    criteria: Vec<String>,
+    include_in_prompt: bool,
+) -> Result<SteeringType, ApplicationError> {
+    SteeringType::validate_name(name).map_err(ApplicationError::InvalidSteeringType)?;
+    let mut steering_type = SteeringConfig::from_criterion_strings(name, purpose, criteria)
+        .map_err(ApplicationError::InvalidSteeringType)?;
+    steering_type.include_in_prompt = include_in_prompt;
+
+    // Materialize the default types first; otherwise the new entry would replace them
+    config_repo.ensure_steering_config()?;
+    config_repo.add_steering_type(&steering_type)?;
+    create_file(steering_repo, &steering_type)?;
+
+    Ok(steering_type)
+}
+
+/// Updates a steering type in config.toml, renaming its markdown file with it
+pub fn edit_steering_type(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+    name: &str,
+    edit: SteeringTypeEdit,
+) -> Result<SteeringType, ApplicationError> {
+    let current = find_type(config_repo, name)?;
+
+    let new_name = edit.name.unwrap_or_else(|| current.name.clone());
+    SteeringType::validate_name(&new_name).map_err(ApplicationError::InvalidSteeringType)?;
+    let criteria = edit.criteria.unwrap_or_else(|| {
+        current
+            .criteria
+            .iter()
+            .map(|criterion| criterion.to_string())
+            .collect()
+    });
+    let purpose = edit.purpose.unwrap_or_else(|| current.purpose.clone());
+
+    let mut steering_type = SteeringConfig::from_criterion_strings(&new_name, &purpose, criteria)
+        .map_err(ApplicationError::InvalidSteeringType)?;
+    steering_type.allowed_operations = current.allowed_operations.clone();
+    steering_type.include_in_prompt = edit.include_in_prompt.unwrap_or(current.include_in_prompt);
+
+    let renamed = new_name != current.name;
+    if renamed
+        && steering_repo
+            .read_steering_file(&file_for(&new_name))?
+            .is_some()
+    {
+        return Err(ApplicationError::InvalidSteeringType(format!(
+            "steering file {}.md already exists",
+            new_name
+        )));
+    }
+
+    config_repo.update_steering_type(name, &steering_type)?;
+
+    if renamed && let Some(content) = steering_repo.read_steering_file(&file_for(name))? {
+        steering_repo.write_steering_file(&file_for(&new_name), &content)?;
+        steering_repo.remove_steering_file(&file_for(name))?;
+    }
+    create_file(steering_repo, &steering_type)?;
+
+    Ok(steering_type)
+}
+
+/// Removes a steering type from config.toml and deletes its markdown file,
+/// backing up the steering files first
+pub fn remove_steering_type(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+    name: &str,
+) -> Result<SteeringTypeRemoval, ApplicationError> {
+    find_type(config_repo, name)?;
+    // Before the snapshot, so a refused removal leaves no backup behind
+    config_repo.check_steering_type_removable(name)?;
+
+    let file = file_for(name);
+    let backup = if steering_repo.read_steering_file(&file)?.is_some() {
+        snapshot_steering(config_repo, steering_repo, BackupReason::BeforeTypeRemoval)?
+            .backup_name()
+            .map(str::to_string)
+    } else {
+        None
+    };
+
+    config_repo.remove_steering_type(name)?;
+    let file_removed = steering_repo.remove_steering_file(&file)?;
+
+    Ok(SteeringTypeRemoval {
+        backup,
+        file_removed,
+    })
+}
+
+fn find_type(
+    config_repo: &dyn ConfigRepositoryInterface,
+    name: &str,
+) -> Result<SteeringType, ApplicationError> {
+    config_repo
+        .load_steering_config()?
+        .types
+        .into_iter()
+        .find(|steering_type| steering_type.name == name)
+        .ok_or_else(|| ApplicationError::SteeringTypeNotFound(name.to_string()))
+}
+
+/// Creates the markdown file for `steering_type` unless it already exists
+fn create_file(
+    steering_repo: &dyn SteeringRepositoryInterface,
+    steering_type: &SteeringType,
+) -> Result<(), ApplicationError> {
+    steering_repo.create_steering_files(&SteeringConfig {
+        types: vec![steering_type.clone()],
+        backup: SteeringBackupConfig::default(),
+    })?;
+    Ok(())
+}
+
+fn file_for(name: &str) -> PathBuf {
+    PathBuf::from(format!("{}.md", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockConfigRepository, MockSteeringRepository};
+
+    fn criteria(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn type_names(config_repo: &MockConfigRepository) -> Vec<String> {
+        config_repo
+            .load_steering_config()
+            .unwrap()
+            .types
+            .into_iter()
+            .map(|steering_type| steering_type.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_add_steering_type_creates_file() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+
+        let added = add_steering_type(
+            &config_repo,
+            &steering_repo,
+            "security",
+            "Security policies",
+            criteria(&["Threat Model: Assets and attackers"]),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(added.criteria[0].name, "Threat Model");
+        assert!(!added.include_in_prompt);
+        assert_eq!(
+            type_names(&config_repo),
+            vec!["product", "tech", "structure", "security"]
+        );
+        assert!(
+            steering_repo
+                .list_steering_files()
+                .unwrap()
+                .contains(&PathBuf::from("security.md"))
+        );
+    }
+
+    #[test]
+    fn test_add_steering_type_rejects_bad_input() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+
+        let invalid_criterion = add_steering_type(
+            &config_repo,
+            &steering_repo,
+            "security",
+            "Security",
+            criteria(&["no description"]),
+            true,
+        );
+        assert!(matches!(
+            invalid_criterion,
+            Err(ApplicationError::InvalidSteeringType(_))
+        ));
+
+        let invalid_name =
+            add_steering_type(&config_repo, &steering_repo, "../x", "X", vec![], true);
+        assert!(matches!(
+            invalid_name,
+            Err(ApplicationError::InvalidSteeringType(_))
+        ));
+
+        let duplicate = add_steering_type(&config_repo, &steering_repo, "tech", "T", vec![], true);
+        assert!(matches!(
+            duplicate,
+            Err(ApplicationError::SteeringTypeAlreadyExists(_))
+        ));
+        assert!(steering_repo.list_steering_files().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_edit_steering_type_renames_file() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("tech", "# Tech\n\nnotes\n");
+
+        let edited = edit_steering_type(
+            &config_repo,
+            &steering_repo,
+            "tech",
+            SteeringTypeEdit {
+                name: Some("stack".to_string()),
+                purpose: Some("Technology stack".to_string()),
+                ..SteeringTypeEdit::default()
+            },
+        )
+        .unwrap();
+
+        // Criteria and allowed operations carry over
+        let original = SteeringConfig::default_for_new_project().types[1].clone();
+        assert_eq!(edited.criteria, original.criteria);
+        assert_eq!(edited.allowed_operations, original.allowed_operations);
+        assert_eq!(
+            type_names(&config_repo),
+            vec!["product", "stack", "structure"]
+        );
+        assert_eq!(steering_repo.steering_content("tech"), None);
+        assert_eq!(
+            steering_repo.steering_content("stack").unwrap(),
+            "# Tech\n\nnotes\n"
+        );
+    }
+
+    #[test]
+    fn test_edit_steering_type_refuses_to_overwrite_file() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("tech", "# Tech\n");
+        steering_repo.set_steering_content("stack", "# Stale stack\n");
+
+        let result = edit_steering_type(
+            &config_repo,
+            &steering_repo,
+            "tech",
+            SteeringTypeEdit {
+                name: Some("stack".to_string()),
+                ..SteeringTypeEdit::default()
+            },
+        );
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::InvalidSteeringType(_))
+        ));
+        assert_eq!(
+            type_names(&config_repo),
+            vec!["product", "tech", "structure"]
+        );
+    }
+
+    #[test]
+    fn test_remove_steering_type_backs_up_file() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("structure", "# Structure\n");
+
+        let removal = remove_steering_type(&config_repo, &steering_repo, "structure").unwrap();
+
+        assert!(removal.file_removed);
+        let backup = removal.backup.unwrap();
+        assert_eq!(
+            steering_repo
+                .read_backup_file(&backup, &PathBuf::from("structure.md"))
+                .unwrap(),
+            "# Structure\n"
+        );
+        assert_eq!(type_names(&config_repo), vec!["product", "tech"]);
+        assert_eq!(steering_repo.steering_content("structure"), None);
+    }
+
+    #[test]
+    fn test_remove_unknown_steering_type() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+
+        let result = remove_steering_type(&config_repo, &steering_repo, "security");
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::SteeringTypeNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_remove_user_level_steering_type_is_refused_without_backup() {
+        let config_repo = MockConfigRepository::new();
+        config_repo.set_user_type("structure");
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("structure", "# Structure\n");
+
+        let result = remove_steering_type(&config_repo, &steering_repo, "structure");
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::SteeringTypeInUserConfig(..))
+        ));
+        assert!(steering_repo.list_steering_backups().unwrap().is_empty());
+        assert_eq!(
+            steering_repo.steering_content("structure").as_deref(),
+            Some("# Structure\n")
+        );
+    }
+
+    #[test]
+    fn test_list_steering_types_reports_files() {
+        let config_repo = MockConfigRepository::new();
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_steering_content("tech", "# Tech\n");
+
+        let entries = list_steering_types(&config_repo, &steering_repo).unwrap();
+
+        let files: Vec<(&str, bool)> = entries
+            .iter()
+            .map(|entry| (entry.steering_type.name.as_str(), entry.file_exists))
+            .collect();
+        assert_eq!(
+            files,
+            vec![("product", false), ("tech", true), ("structure", false)]
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{CollisionPolicy, RestoredSpec, SpecRepositoryInterface};
+
+/// Outcome of restoring several archived specs
+#[derive(Debug, Default)]
+pub struct UnarchiveReport {
+    pub restored: Vec<RestoredSpec>,
+    /// Specs that could not be restored, with the reason
+    pub failed: Vec<(String, ApplicationError)>,
+}
+
+/// Restores archived specs; a failure doesn't stop the remaining specs from being restored
+pub fn unarchive_specs(
+    spec_repo: &dyn SpecRepositoryInterface,
+    spec_names: &[String],
+    policy: CollisionPolicy,
+) -> UnarchiveReport {
+    let mut report = UnarchiveReport::default();
+    for name in spec_names {
+        match spec_repo.unarchive_spec(name, policy) {
+            Ok(restored) => report.restored.push(restored),
+            Err(e) => report.failed.push((name.clone(), e)),
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockSpecRepository, TestDirectory};
+    use crate::infrastructure::filesystem::path_manager::PathManager;
+    use crate::infrastructure::repositories::spec::SpecRepository as ConcreteSpecRepository;
+    use std::fs;
+
+    #[test]
+    fn test_unarchive_specs_success() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        let archive_dir = path_manager.archive_dir(true);
+        fs::create_dir_all(archive_dir.join("2025-01-01-feature-a")).unwrap();
+        fs::write(
+            archive_dir.join("2025-01-01-feature-a").join("tasks.md"),
+            "# Tasks",
+        )
+        .unwrap();
+
+        let report = unarchive_specs(
+            &spec_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::Fail,
+        );
+        assert!(report.failed.is_empty());
+
+        let specs_dir = path_manager.specs_dir(true);
+        assert!(
+            specs_dir
+                .join("2025-01-01-feature-a")
+                .join("tasks.md")
+                .exists()
+        );
+        assert!(!archive_dir.join("2025-01-01-feature-a").exists());
+    }
+
+    #[test]
+    fn test_unarchive_specs_refuses_to_overwrite_active_spec() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        fs::create_dir_all(path_manager.archive_dir(true).join("2025-01-01-feature-a")).unwrap();
+        fs::create_dir_all(path_manager.specs_dir(true).join("2025-01-01-feature-a")).unwrap();
+
+        let report = unarchive_specs(
+            &spec_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::Fail,
+        );
+        assert!(matches!(
+            report.failed.as_slice(),
+            [(_, ApplicationError::SpecAlreadyExists(_))]
+        ));
+        assert!(
+            path_manager
+                .archive_dir(true)
+                .join("2025-01-01-feature-a")
+                .exists()
+        );
+    }
+
+    #[test]
+    fn test_unarchive_specs_with_mock() {
+        let mock_repo = MockSpecRepository::new();
+        mock_repo.add_archived_spec("2025-01-01-feature-a");
+
+        let report = unarchive_specs(
+            &mock_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::Fail,
+        );
+        assert!(report.failed.is_empty());
+        assert!(mock_repo.list_archived_specs().unwrap().is_empty());
+        assert!(
+            mock_repo
+                .get_created_specs()
+                .contains(&"2025-01-01-feature-a".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unarchive_specs_not_found() {
+        let mock_repo = MockSpecRepository::new();
+
+        let report = unarchive_specs(&mock_repo, &["missing".to_string()], CollisionPolicy::Fail);
+        assert!(matches!(
+            report.failed.as_slice(),
+            [(_, ApplicationError::SpecNotFound(_))]
+        ));
+    }
+
+    #[test]
+    fn test_unarchive_specs_keep_both() {
+        let mock_repo = MockSpecRepository::with_specs(vec!["2025-01-01-feature-a".to_string()]);
+        mock_repo.add_archived_spec("2025-01-01-feature-a");
+
+        let report = unarchive_specs(
+            &mock_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::KeepBoth,
+        );
+        assert_eq!(
+            report.restored,
+            vec![RestoredSpec {
+                name: "2025-01-01-feature-a-2".to_string(),
+                replaced: None,
+            }]
+        );
+        assert!(mock_repo.list_archived_specs().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unarchive_specs_overwrite_archives_the_replaced_active_spec() {
+        let test_dir = TestDirectory::new_no_cd();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = ConcreteSpecRepository::new(path_manager.clone());
+
+        let archived = path_manager.archive_dir(true).join("2025-01-01-feature-a");
+        let active = path_manager.specs_dir(true).join("2025-01-01-feature-a");
+        fs::create_dir_all(&archived).unwrap();
+        fs::write(archived.join("tasks.md"), "archived").unwrap();
+        fs::create_dir_all(&active).unwrap();
+        fs::write(active.join("tasks.md"), "active").unwrap();
+
+        let report = unarchive_specs(
+            &spec_repo,
+            &["2025-01-01-feature-a".to_string()],
+            CollisionPolicy::Overwrite,
+        );
+
+        assert!(report.failed.is_empty());
+        assert_eq!(
+            report.restored,
+            vec![RestoredSpec {
+                name: "2025-01-01-feature-a".to_string(),
+                replaced: Some("2025-01-01-feature-a-2".to_string()),
+            }]
+        );
+        assert_eq!(
+            fs::read_to_string(active.join("tasks.md")).unwrap(),
+            "archived"
+        );
+        assert_eq!(
+            fs::read_to_string(
+                path_manager
+                    .archive_dir(true)
+                    .join("2025-01-01-feature-a-2")
+                    .join("tasks.md")
+            )
+            .unwrap(),
+            "active"
+        );
+        assert!(!archived.exists());
+    }
+
+    #[test]
+    fn test_unarchive_specs_reports_each_failure_and_restores_the_rest() {
+        let mock_repo = MockSpecRepository::with_specs(vec!["2025-01-02-feature-b".to_string()]);
+        mock_repo.add_archived_spec("2025-01-01-feature-a");
+        mock_repo.add_archived_spec("2025-01-02-feature-b");
+        mock_repo.add_archived_spec("2025-01-03-feature-c");
+
+        let report = unarchive_specs(
+            &mock_repo,
+            &[
+                "2025-01-01-feature-a".to_string(),
+                "2025-01-02-feature-b".to_string(),
+                "missing".to_string(),
+                "2025-01-03-feature-c".to_string(),
+            ],
+            CollisionPolicy::Fail,
+        );
+
+        let restored: Vec<&str> = report
+            .restored
+            .iter()
+            .map(|spec| spec.name.as_str())
+            .collect();
+        assert_eq!(
+            restored,
+            vec!["2025-01-01-feature-a", "2025-01-03-feature-c"]
+        );
+        assert!(matches!(
+            report.failed.as_slice(),
+            [
+                (b, ApplicationError::SpecAlreadyExists(_)),
+                (missing, ApplicationError::SpecNotFound(_)),
+            ] if b == "2025-01-02-feature-b" && missing == "missing"
+        ));
+    }
+}
@@ -1,4 +1,6 @@
-use clap::{Parser, Subcommand, ValueEnum};
+use crate::application::repositories::CollisionPolicy;
+use crate::domain::value_objects::config_override::ConfigOverrides;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(name = "hail-mary")]
@@ -9,6 +11,22 @@ pub struct Cli {
     pub command: Commands,
 }
 
+/// `--config`, accepted by the commands that read config.toml
+#[derive(Args, Debug, Clone, Default)]
+pub struct ConfigArgs {
+    /// Override a config value for this run, e.g. --config spec.lang=en (repeatable; wins
+    /// over HAIL_MARY_SPEC__LANG-style environment variables and config.toml)
+    #[arg(long = "config", value_name = "KEY=VALUE", global = true)]
+    pub config: Vec<String>,
+}
+
+impl ConfigArgs {
+    /// Overrides from `HAIL_MARY_*` environment variables and `--config`
+    pub fn overrides(&self) -> Result<ConfigOverrides, String> {
+        ConfigOverrides::from_env(std::env::vars_os(), &self.config)
+    }
+}
+
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Generate shell completion scripts
@@ -18,8 +36,19 @@ pub enum Commands {
         shell: Shell,
     },
 
-    /// Mark specifications as complete
-    Complete,
+    /// Mark specifications as complete (Tab switches to the archive to restore specs)
+    Complete {
+        /// What to do when the destination spec already exists
+        #[arg(long, value_enum, default_value_t = OnConflict::Fail)]
+        on_conflict: OnConflict,
+    },
+
+    /// Set up .kiro, steering files and slash commands (also done implicitly by `code`)
+    Init {
+        /// List the files that would be created, modified or deleted without changing any
+        #[arg(long)]
+        dry_run: bool,
+    },
 
     /// Launch Claude Code with Kiro specification context
     Code {
@@ -29,19 +58,233 @@ pub enum Commands {
         /// Continue previous Claude conversation (passes --continue flag)
         #[arg(short = 'c', long = "continue")]
         continue_conversation: bool,
+        /// Use an existing specification without the selector (exact, prefix or fuzzy name)
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["new", "no_spec"])]
+        spec: Option<String>,
+        /// Select an SBI within the PBI given by --spec
+        #[arg(long, value_name = "NAME", requires = "spec")]
+        sbi: Option<String>,
+        /// Create a new specification with the given kebab-case name
+        #[arg(long, value_name = "NAME", conflicts_with = "no_spec")]
+        new: Option<String>,
+        /// Launch without a specification, skipping the selector
+        #[arg(long)]
+        no_spec: bool,
+        #[command(flatten)]
+        overrides: ConfigArgs,
+    },
+
+    /// Show task progress and next action for specifications
+    Status {
+        /// Specification name (exact, prefix or fuzzy); all active specs if omitted
+        spec: Option<String>,
+        /// Print machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Manage specifications without the TUI
+    Spec {
+        #[command(subcommand)]
+        command: SpecCommands,
+        #[command(flatten)]
+        overrides: ConfigArgs,
     },
 
     /// Steering system management
     Steering {
         #[command(subcommand)]
         command: SteeringCommands,
+        #[command(flatten)]
+        overrides: ConfigArgs,
+    },
+
+    /// Inspect .kiro/config.toml
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+        #[command(flatten)]
+        overrides: ConfigArgs,
+    },
+
+    /// Inspect the /hm:* slash commands deployed into .claude/commands/hm
+    #[command(name = "commands")]
+    SlashCommands {
+        #[command(subcommand)]
+        command: SlashCommandCommands,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SlashCommandCommands {
+    /// List the slash commands and whether each is embedded or comes from .kiro/commands
+    List,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCommands {
+    /// Report every problem in config.toml with its line and column (exits non-zero on problems)
+    Validate,
+    /// Print the JSON Schema for config.toml
+    Schema,
+    /// Print the settings from the user-level and project config files, with the file each comes from
+    Show {
+        /// Include built-in defaults, i.e. every setting hail-mary actually uses
+        #[arg(long)]
+        effective: bool,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SpecCommands {
+    /// Create a new specification (or an SBI inside a PBI with --pbi)
+    New {
+        /// Kebab-case name of the specification or SBI title
+        name: String,
+        /// Create an SBI inside this PBI instead of a new specification
+        #[arg(long, value_name = "PBI")]
+        pbi: Option<String>,
+    },
+    /// List active and archived specifications
+    List,
+    /// Show the file inventory and task progress of a specification
+    Show {
+        /// Specification name (exact, prefix or fuzzy)
+        name: String,
+        /// Show an SBI within the PBI instead
+        #[arg(long, value_name = "NAME")]
+        sbi: Option<String>,
+    },
+    /// Move specifications to the archive
+    Archive {
+        /// Specification names (exact, prefix or fuzzy)
+        #[arg(required = true)]
+        names: Vec<String>,
+        /// What to do when the spec already exists in the archive
+        #[arg(long, value_enum, default_value_t = OnConflict::Fail)]
+        on_conflict: OnConflict,
+    },
+    /// Restore archived specifications
+    Unarchive {
+        /// Archived specification names (exact, prefix or fuzzy)
+        #[arg(required = true)]
+        names: Vec<String>,
+        /// What to do when an active spec with the same name exists
+        #[arg(long, value_enum, default_value_t = OnConflict::Fail)]
+        on_conflict: OnConflict,
+    },
+    /// Rename a specification, keeping its date prefix
+    Rename {
+        /// Specification name (exact, prefix or fuzzy)
+        name: String,
+        /// New kebab-case name without the date prefix
+        new_name: String,
+    },
+    /// Delete a specification permanently
+    Delete {
+        /// Specification name (exact, prefix or fuzzy)
+        name: String,
+        /// Skip the confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
     },
 }
 
 #[derive(Subcommand, Debug)]
 pub enum SteeringCommands {
     /// Create a backup of steering files
-    Backup,
+    Backup {
+        #[command(subcommand)]
+        command: Option<SteeringBackupCommands>,
+    },
+    /// Show a unified diff between a backup and the current steering files
+    Diff {
+        /// Backup name, or "latest"
+        backup: String,
+    },
+    /// Restore steering files from a backup (the current files are backed up first)
+    Restore {
+        /// Backup name, or "latest"
+        backup: String,
+        /// Restore only this file (e.g. tech.md)
+        #[arg(long)]
+        file: Option<String>,
+    },
+    /// Check steering files against the criteria in config.toml (exits non-zero on issues)
+    Lint,
+    /// Manage the steering types in config.toml
+    Type {
+        #[command(subcommand)]
+        command: SteeringTypeCommands,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SteeringTypeCommands {
+    /// List configured steering types
+    List,
+    /// Add a steering type and create its markdown file
+    Add {
+        /// Kebab-case type name (also the file name, <name>.md)
+        name: String,
+        /// What the steering file is for
+        #[arg(long)]
+        purpose: String,
+        /// Criterion in "Name: Description" format (repeatable)
+        #[arg(long = "criterion", value_name = "CRITERION", required = true)]
+        criteria: Vec<String>,
+        /// Whether the file is injected into the system prompt
+        #[arg(long, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+        include_in_prompt: bool,
+    },
+    /// Change a steering type (renaming also renames its file)
+    Edit {
+        /// Current type name
+        name: String,
+        /// New type name
+        #[arg(long, value_name = "NAME")]
+        rename: Option<String>,
+        /// New purpose
+        #[arg(long)]
+        purpose: Option<String>,
+        /// Replace all criteria ("Name: Description", repeatable)
+        #[arg(long = "criterion", value_name = "CRITERION")]
+        criteria: Vec<String>,
+        /// Whether the file is injected into the system prompt
+        #[arg(long, value_name = "BOOL")]
+        include_in_prompt: Option<bool>,
+    },
+    /// Remove a steering type and its file (the steering files are backed up first)
+    Remove {
+        /// Type name
+        name: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SteeringBackupCommands {
+    /// List existing backups
+    List,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnConflict {
+    /// Abort without touching either directory
+    Fail,
+    /// Replace the existing directory (a replaced active spec is archived, not deleted)
+    Overwrite,
+    /// Keep both, moving the spec under a numbered name
+    KeepBoth,
+}
+
+impl From<OnConflict> for CollisionPolicy {
+    fn from(value: OnConflict) -> Self {
+        match value {
+            OnConflict::Fail => CollisionPolicy::Fail,
+            OnConflict::Overwrite => CollisionPolicy::Overwrite,
+            OnConflict::KeepBoth => CollisionPolicy::KeepBoth,
+        }
+    }
 }
 
 #[derive(ValueEnum, Clone, Debug)]
@@ -64,7 +307,7 @@ impl Commands {
     }
 
     pub fn is_complete(&self) -> bool {
-        matches!(self, Commands::Complete)
+        matches!(self, Commands::Complete { .. })
     }
 
     pub fn is_code(&self) -> bool {
@@ -107,7 +350,336 @@ mod tests {
         let code_cmd = Commands::Code {
             no_danger: false,
             continue_conversation: false,
+            spec: None,
+            sbi: None,
+            new: None,
+            no_spec: false,
+            overrides: ConfigArgs::default(),
         };
         assert!(code_cmd.is_code());
     }
+
+    #[test]
+    fn test_code_spec_flags_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "code", "--spec", "pbi", "--sbi", "sbi-2-foo"])
+            .unwrap();
+        match cli.command {
+            Commands::Code { spec, sbi, .. } => {
+                assert_eq!(spec.as_deref(), Some("pbi"));
+                assert_eq!(sbi.as_deref(), Some("sbi-2-foo"));
+            }
+            _ => panic!("Expected Code command"),
+        }
+    }
+
+    #[test]
+    fn test_spec_subcommands_parse() {
+        let cli =
+            Cli::try_parse_from(["hail-mary", "spec", "new", "feature", "--pbi", "epic"]).unwrap();
+        match cli.command {
+            Commands::Spec {
+                command: SpecCommands::New { name, pbi },
+                ..
+            } => {
+                assert_eq!(name, "feature");
+                assert_eq!(pbi.as_deref(), Some("epic"));
+            }
+            _ => panic!("Expected spec new command"),
+        }
+
+        let cli = Cli::try_parse_from(["hail-mary", "spec", "delete", "old", "-y"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Spec {
+                command: SpecCommands::Delete { yes: true, .. },
+                ..
+            }
+        ));
+
+        let cli = Cli::try_parse_from([
+            "hail-mary",
+            "spec",
+            "unarchive",
+            "old",
+            "--on-conflict",
+            "keep-both",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Spec {
+                command: SpecCommands::Unarchive { on_conflict, .. },
+                ..
+            } => assert_eq!(on_conflict, OnConflict::KeepBoth),
+            _ => panic!("Expected spec unarchive command"),
+        }
+
+        assert!(Cli::try_parse_from(["hail-mary", "spec", "archive"]).is_err());
+        assert!(Cli::try_parse_from(["hail-mary", "spec", "rename", "only-one"]).is_err());
+    }
+
+    #[test]
+    fn test_init_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "init", "--dry-run"]).unwrap();
+        assert!(matches!(cli.command, Commands::Init { dry_run: true }));
+
+        let cli = Cli::try_parse_from(["hail-mary", "init"]).unwrap();
+        assert!(matches!(cli.command, Commands::Init { dry_run: false }));
+    }
+
+    #[test]
+    fn test_status_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "status", "feature", "--json"]).unwrap();
+        match cli.command {
+            Commands::Status { spec, json } => {
+                assert_eq!(spec.as_deref(), Some("feature"));
+                assert!(json);
+            }
+            _ => panic!("Expected status command"),
+        }
+
+        let cli = Cli::try_parse_from(["hail-mary", "status"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Status {
+                spec: None,
+                json: false
+            }
+        ));
+    }
+
+    #[test]
+    fn test_code_spec_flags_conflict() {
+        assert!(Cli::try_parse_from(["hail-mary", "code", "--sbi", "sbi-1"]).is_err());
+        assert!(Cli::try_parse_from(["hail-mary", "code", "--spec", "a", "--new", "b"]).is_err());
+        assert!(Cli::try_parse_from(["hail-mary", "code", "--new", "a", "--no-spec"]).is_err());
+    }
+
+    #[test]
+    fn test_steering_backup_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "steering", "backup"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Steering {
+                command: SteeringCommands::Backup { command: None },
+                ..
+            }
+        ));
+
+        let cli = Cli::try_parse_from(["hail-mary", "steering", "backup", "list"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Steering {
+                command: SteeringCommands::Backup {
+                    command: Some(SteeringBackupCommands::List)
+                },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_steering_restore_parse() {
+        let cli = Cli::try_parse_from([
+            "hail-mary",
+            "steering",
+            "restore",
+            "latest",
+            "--file",
+            "tech.md",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Steering {
+                command: SteeringCommands::Restore { backup, file },
+                ..
+            } => {
+                assert_eq!(backup, "latest");
+                assert_eq!(file.as_deref(), Some("tech.md"));
+            }
+            _ => panic!("Expected steering restore command"),
+        }
+
+        assert!(Cli::try_parse_from(["hail-mary", "steering", "diff"]).is_err());
+    }
+
+    #[test]
+    fn test_steering_type_parse() {
+        let cli = Cli::try_parse_from([
+            "hail-mary",
+            "steering",
+            "type",
+            "add",
+            "security",
+            "--purpose",
+            "Security policies",
+            "--criterion",
+            "Threat Model: Assets",
+            "--criterion",
+            "Secrets: Handling",
+            "--include-in-prompt",
+            "false",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Steering {
+                command:
+                    SteeringCommands::Type {
+                        command:
+                            SteeringTypeCommands::Add {
+                                name,
+                                criteria,
+                                include_in_prompt,
+                                ..
+                            },
+                    },
+                ..
+            } => {
+                assert_eq!(name, "security");
+                assert_eq!(criteria.len(), 2);
+                assert!(!include_in_prompt);
+            }
+            _ => panic!("Expected steering type add command"),
+        }
+
+        // At least one criterion is required
+        assert!(
+            Cli::try_parse_from([
+                "hail-mary",
+                "steering",
+                "type",
+                "add",
+                "security",
+                "--purpose",
+                "Security"
+            ])
+            .is_err()
+        );
+
+        let cli = Cli::try_parse_from([
+            "hail-mary",
+            "steering",
+            "type",
+            "edit",
+            "tech",
+            "--rename",
+            "stack",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Steering {
+                command:
+                    SteeringCommands::Type {
+                        command:
+                            SteeringTypeCommands::Edit {
+                                rename,
+                                criteria,
+                                include_in_prompt,
+                                ..
+                            },
+                    },
+                ..
+            } => {
+                assert_eq!(rename.as_deref(), Some("stack"));
+                assert!(criteria.is_empty());
+                assert_eq!(include_in_prompt, None);
+            }
+            _ => panic!("Expected steering type edit command"),
+        }
+    }
+
+    #[test]
+    fn test_steering_lint_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "steering", "lint"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Steering {
+                command: SteeringCommands::Lint,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_config_overrides_parse_anywhere_within_command() {
+        let cli = Cli::try_parse_from([
+            "hail-mary",
+            "code",
+            "--config",
+            "spec.lang=en",
+            "--config",
+            "steering.backup.max=3",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Code { overrides, .. } => {
+                assert_eq!(
+                    overrides.config,
+                    vec!["spec.lang=en", "steering.backup.max=3"]
+                )
+            }
+            _ => panic!("Expected Code command"),
+        }
+
+        let cli = Cli::try_parse_from([
+            "hail-mary",
+            "spec",
+            "--config",
+            "spec.lang=en",
+            "new",
+            "feature",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Spec { overrides, .. } => assert_eq!(overrides.config, vec!["spec.lang=en"]),
+            _ => panic!("Expected Spec command"),
+        }
+    }
+
+    #[test]
+    fn test_config_overrides_rejected_by_commands_without_config() {
+        for args in [
+            vec!["hail-mary", "--config", "spec.lang=en", "code"],
+            vec!["hail-mary", "init", "--config", "spec.lang=en"],
+            vec!["hail-mary", "status", "--config", "spec.lang=en"],
+            vec!["hail-mary", "complete", "--config", "spec.lang=en"],
+            vec!["hail-mary", "commands", "list", "--config", "spec.lang=en"],
+        ] {
+            assert!(Cli::try_parse_from(&args).is_err(), "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn test_config_show_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "config", "show", "--effective"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Config {
+                command: ConfigCommands::Show { effective: true },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_config_validate_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "config", "validate"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Config {
+                command: ConfigCommands::Validate,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_commands_list_parse() {
+        let cli = Cli::try_parse_from(["hail-mary", "commands", "list"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::SlashCommands {
+                command: SlashCommandCommands::List
+            }
+        ));
+    }
 }
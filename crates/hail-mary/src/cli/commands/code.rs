@@ -1,25 +1,42 @@
 use anyhow::Result;
 
-use crate::application::use_cases::{initialize_project, launch_claude_with_spec};
-use crate::cli::formatters::format_error;
+use crate::application::repositories::OverriddenConfigRepository;
+use crate::application::use_cases::{
+    LaunchOptions, SpecSelection, initialize_project, launch_claude_with_spec,
+};
+use crate::cli::formatters::{format_error, format_info, format_success, format_warning};
+use crate::domain::value_objects::config_override::ConfigOverrides;
+use crate::domain::value_objects::launch_command::SessionFlags;
 use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::process::agent_launcher::AgentProcessLauncher;
 use crate::infrastructure::repositories::{
     config::ConfigRepository, spec::SpecRepository, steering::SteeringRepository,
 };
+use crate::infrastructure::tui::terminal_prompt::TerminalPrompt;
 
 pub struct CodeCommand {
     no_danger: bool,
     continue_conversation: bool,
+    selection: SpecSelection,
+    config_overrides: ConfigOverrides,
 }
 
 impl CodeCommand {
-    pub fn new(no_danger: bool, continue_conversation: bool) -> Self {
+    pub fn new(no_danger: bool, continue_conversation: bool, selection: SpecSelection) -> Self {
         Self {
             no_danger,
             continue_conversation,
+            selection,
+            config_overrides: ConfigOverrides::default(),
         }
     }
 
+    /// Apply `HAIL_MARY_*` / `--config` overrides when loading config
+    pub fn with_config_overrides(mut self, config_overrides: ConfigOverrides) -> Self {
+        self.config_overrides = config_overrides;
+        self
+    }
+
     pub fn execute(&self) -> Result<()> {
         // Try to discover project root, or use current directory
         let path_manager = match PathManager::discover() {
@@ -33,25 +50,63 @@ impl CodeCommand {
 
         // Create repositories
         let spec_repo = SpecRepository::new(path_manager.clone());
-        let config_repo = ConfigRepository::new(path_manager.clone());
+        let file_config_repo = ConfigRepository::new(path_manager.clone());
+        let config_repo =
+            OverriddenConfigRepository::new(&file_config_repo, self.config_overrides.clone());
         let steering_repo = SteeringRepository::new(path_manager.clone());
 
         // Initialize project if needed (this is idempotent)
-        initialize_project(&config_repo, &spec_repo, &steering_repo)?;
+        let report = initialize_project(&config_repo, &spec_repo, &steering_repo)?;
+        for scaffold in report.created_steering_files() {
+            let file = path_manager
+                .kiro_dir(false)
+                .join("steering")
+                .join(&scaffold.file);
+            println!(
+                "{}",
+                format_success(&format!("Created steering file {}", file.display()))
+            );
+        }
+        let present: Vec<String> = report
+            .present_steering_files()
+            .map(|scaffold| scaffold.file.display().to_string())
+            .collect();
+        if !present.is_empty() {
+            println!(
+                "{}",
+                format_info(&format!(
+                    "Steering files already present: {}",
+                    present.join(", ")
+                ))
+            );
+        }
+        for upgrade in &report.upgrades {
+            println!("{}", format_success(&format!("Upgraded skill {}", upgrade)));
+        }
+        for warning in &report.warnings {
+            println!("{}", format_warning(&warning.to_string()));
+        }
 
         // Execute single use case
         match launch_claude_with_spec(
             &spec_repo,
             &config_repo,
             &steering_repo,
-            self.no_danger,
-            self.continue_conversation,
-            path_manager.project_root(),
+            &TerminalPrompt::new(),
+            &AgentProcessLauncher::new(),
+            self.selection.clone(),
+            LaunchOptions {
+                flags: SessionFlags {
+                    no_danger: self.no_danger,
+                    continue_conversation: self.continue_conversation,
+                },
+                project_root: path_manager.project_root(),
+            },
         ) {
             Ok(()) => Ok(()),
             Err(crate::application::errors::ApplicationError::ProcessLaunchError(msg)) => {
                 println!("{}", format_error(&msg));
-                Err(anyhow::anyhow!("Failed to launch Claude Code"))
+                Err(anyhow::anyhow!("Failed to launch agent"))
             }
             Err(crate::application::errors::ApplicationError::SpecNotFound(spec)) => {
                 println!(
@@ -80,7 +135,7 @@ impl CodeCommand {
 
 impl Default for CodeCommand {
     fn default() -> Self {
-        Self::new(false, false)
+        Self::new(false, false, SpecSelection::Interactive)
     }
 }
 
@@ -90,7 +145,7 @@ mod tests {
 
     #[test]
     fn test_code_command_new() {
-        let command = CodeCommand::new(false, false);
+        let command = CodeCommand::new(false, false, SpecSelection::Interactive);
         // Just ensure it can be created without panicking
         assert!(!command.no_danger);
         assert!(!command.continue_conversation);
@@ -98,7 +153,7 @@ mod tests {
 
     #[test]
     fn test_code_command_new_with_no_danger() {
-        let command = CodeCommand::new(true, false);
+        let command = CodeCommand::new(true, false, SpecSelection::Interactive);
         assert!(command.no_danger);
         assert!(!command.continue_conversation);
     }
@@ -109,6 +164,13 @@ mod tests {
         // Just ensure default works
         assert!(!command.no_danger);
         assert!(!command.continue_conversation);
+        assert_eq!(command.selection, SpecSelection::Interactive);
+    }
+
+    #[test]
+    fn test_code_command_new_with_selection() {
+        let command = CodeCommand::new(false, false, SpecSelection::New("feature".to_string()));
+        assert_eq!(command.selection, SpecSelection::New("feature".to_string()));
     }
 
     // Note: execute() method testing is complex due to TUI and process launching
@@ -1,23 +1,26 @@
 use anyhow::Result;
 
-use crate::application::repositories::SpecRepositoryInterface;
-use crate::application::use_cases::complete_specs;
+use crate::application::repositories::{CollisionPolicy, SpecRepositoryInterface};
+use crate::application::use_cases::{complete_specs, unarchive_specs};
+use crate::cli::commands::spec::print_unarchive_report;
 use crate::cli::formatters::{format_error, format_success};
 use crate::infrastructure::filesystem::path_manager::PathManager;
 use crate::infrastructure::repositories::spec::SpecRepository;
-use crate::infrastructure::tui::completion_ui::select_specs_for_completion;
+use crate::infrastructure::tui::completion_ui::{CompletionAction, select_specs_for_completion};
 
-pub struct CompleteCommand;
+pub struct CompleteCommand {
+    policy: CollisionPolicy,
+}
 
 impl Default for CompleteCommand {
     fn default() -> Self {
-        Self::new()
+        Self::new(CollisionPolicy::default())
     }
 }
 
 impl CompleteCommand {
-    pub fn new() -> Self {
-        Self
+    pub fn new(policy: CollisionPolicy) -> Self {
+        Self { policy }
     }
 
     pub fn execute(&self) -> Result<()> {
@@ -27,7 +30,7 @@ impl CompleteCommand {
             Err(_) => {
                 println!(
                     "{}",
-                    format_error("Not in a project directory. Run 'hail-mary code' to initialize.")
+                    format_error("Not in a project directory. Run 'hail-mary init' to initialize.")
                 );
                 return Err(anyhow::anyhow!("Project not found"));
             }
@@ -36,16 +39,19 @@ impl CompleteCommand {
         // Create spec repository
         let spec_repo = SpecRepository::new(path_manager);
 
-        // Get list of specifications
-        let specs = match spec_repo.list_spec_directories() {
-            Ok(specs) => specs,
+        // Get list of active and archived specifications
+        let specs_result = spec_repo
+            .list_spec_directories()
+            .and_then(|specs| Ok((specs, spec_repo.list_archived_specs()?)));
+        let (specs, archived) = match specs_result {
+            Ok(result) => result,
             Err(e) => {
                 println!("{}", format_error(&e.to_string()));
                 return Err(anyhow::anyhow!(e));
             }
         };
 
-        if specs.is_empty() {
+        if specs.is_empty() && archived.is_empty() {
             println!(
                 "{}",
                 format_error("No specifications found in .kiro/specs directory.")
@@ -54,28 +60,37 @@ impl CompleteCommand {
         }
 
         // Show TUI for spec selection
-        let selected_specs = match select_specs_for_completion(specs)? {
-            Some(specs) => specs,
+        let action = match select_specs_for_completion(specs, archived)? {
+            Some(action) => action,
             None => {
                 // User cancelled
                 return Ok(());
             }
         };
 
-        if selected_specs.is_empty() {
-            return Ok(());
-        }
-
-        // Execute complete specs
-        match complete_specs(&spec_repo, &selected_specs) {
-            Ok(()) => {
-                println!(
-                    "{}",
-                    format_success(&format!(
+        // Execute the chosen use case
+        let result = match &action {
+            CompletionAction::Archive(names) if !names.is_empty() => {
+                complete_specs(&spec_repo, names, self.policy).map(|moved| {
+                    format!(
                         "{} specification(s) moved to archive successfully.",
-                        selected_specs.len()
-                    ))
-                );
+                        moved.len()
+                    )
+                })
+            }
+            CompletionAction::Unarchive(names) if !names.is_empty() => {
+                let report = unarchive_specs(&spec_repo, names, self.policy);
+                return print_unarchive_report(&report).map_err(|e| {
+                    println!("{}", format_error(&e.to_string()));
+                    anyhow::anyhow!(e)
+                });
+            }
+            _ => return Ok(()),
+        };
+
+        match result {
+            Ok(message) => {
+                println!("{}", format_success(&message));
             }
             Err(e) => {
                 println!("{}", format_error(&e.to_string()));
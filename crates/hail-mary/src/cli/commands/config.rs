@@ -0,0 +1,240 @@
+use anyhow::Result;
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{ConfigRepositoryInterface, OverriddenConfigRepository};
+use crate::cli::args::ConfigCommands;
+use crate::cli::formatters::{format_error, format_info, format_success};
+use crate::domain::value_objects::config_diagnostic::ConfigFileDiagnostics;
+use crate::domain::value_objects::config_override::ConfigOverrides;
+use crate::domain::value_objects::config_source::{ConfigSource, EffectiveSetting};
+use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::repositories::config::ConfigRepository;
+use crate::infrastructure::repositories::config_validation::CONFIG_SCHEMA;
+use std::path::PathBuf;
+
+pub struct ConfigCommand {
+    command: ConfigCommands,
+    config_overrides: ConfigOverrides,
+}
+
+impl ConfigCommand {
+    pub fn new(command: ConfigCommands) -> Self {
+        Self {
+            command,
+            config_overrides: ConfigOverrides::default(),
+        }
+    }
+
+    /// Apply `HAIL_MARY_*` / `--config` overrides when loading config
+    pub fn with_config_overrides(mut self, config_overrides: ConfigOverrides) -> Self {
+        self.config_overrides = config_overrides;
+        self
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        // The schema does not depend on a project
+        if let ConfigCommands::Schema = self.command {
+            print!("{}", CONFIG_SCHEMA);
+            return Ok(());
+        }
+
+        // Discover project root
+        let path_manager = match PathManager::discover() {
+            Ok(pm) => pm,
+            Err(_) => {
+                println!(
+                    "{}",
+                    format_error("Not in a project directory. Run 'hail-mary init' to initialize.")
+                );
+                return Err(anyhow::anyhow!("Project not found"));
+            }
+        };
+
+        let files = ConfigFiles {
+            user: PathManager::user_config_path(),
+            project: path_manager.config_path(false),
+        };
+        let file_config_repo = ConfigRepository::new(path_manager);
+        let config_repo =
+            OverriddenConfigRepository::new(&file_config_repo, self.config_overrides.clone());
+
+        match self.run(&config_repo, &files) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                println!("{}", format_error(&e.to_string()));
+                Err(anyhow::anyhow!(e))
+            }
+        }
+    }
+
+    fn run(
+        &self,
+        config_repo: &dyn ConfigRepositoryInterface,
+        files: &ConfigFiles,
+    ) -> Result<(), ApplicationError> {
+        match &self.command {
+            ConfigCommands::Validate => {
+                let results = config_repo.validate_config()?;
+                let problems: usize = results.iter().map(|file| file.diagnostics.len()).sum();
+                if problems > 0 {
+                    print_diagnostics(&results);
+                    return Err(ApplicationError::ConfigValidationFailed(problems));
+                }
+                if results.is_empty() {
+                    println!(
+                        "{}",
+                        format_info(&format!(
+                            "No config files; {} does not exist",
+                            files.project.display()
+                        ))
+                    );
+                }
+                for file in &results {
+                    println!(
+                        "{}",
+                        format_success(&format!("{} is valid", file.path.display()))
+                    );
+                }
+            }
+            ConfigCommands::Schema => print!("{}", CONFIG_SCHEMA),
+            ConfigCommands::Show { effective } => {
+                let settings: Vec<EffectiveSetting> = config_repo
+                    .load_effective_config()?
+                    .into_iter()
+                    .filter(|setting| *effective || setting.source != ConfigSource::Default)
+                    .collect();
+                print_settings(files, &settings);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The config files layered by `ConfigRepository`, for display
+struct ConfigFiles {
+    user: Option<PathBuf>,
+    project: PathBuf,
+}
+
+/// `path:line:column: message`, the format editors and terminals link to
+fn print_diagnostics(results: &[ConfigFileDiagnostics]) {
+    for file in results {
+        for diagnostic in &file.diagnostics {
+            println!("{}:{}", file.path.display(), diagnostic);
+        }
+    }
+}
+
+fn print_settings(files: &ConfigFiles, settings: &[EffectiveSetting]) {
+    if let Some(user) = &files.user {
+        println!("{}", format_info(&format!("user: {}", user.display())));
+    }
+    println!(
+        "{}",
+        format_info(&format!("project: {}", files.project.display()))
+    );
+
+    if settings.is_empty() {
+        println!("{}", format_info("No settings in the config files"));
+        return;
+    }
+
+    println!();
+    for setting in settings {
+        println!("{}", format_setting(setting));
+    }
+}
+
+fn format_setting(setting: &EffectiveSetting) -> String {
+    format!("{} = {}  # {}", setting.key, setting.value, setting.source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockConfigRepository, TestDirectory};
+    use crate::domain::value_objects::config_diagnostic::ConfigDiagnostic;
+    use std::fs;
+
+    fn files() -> ConfigFiles {
+        ConfigFiles {
+            user: None,
+            project: PathBuf::from(".kiro/config.toml"),
+        }
+    }
+
+    #[test]
+    fn test_validate_fails_with_problem_count() {
+        let config_repo = MockConfigRepository::new();
+        config_repo.set_diagnostics(vec![
+            ConfigDiagnostic {
+                line: 3,
+                column: 1,
+                message: "unknown key 'nmae'".to_string(),
+            },
+            ConfigDiagnostic {
+                line: 7,
+                column: 9,
+                message: "spec.lang must be a string, found integer".to_string(),
+            },
+        ]);
+
+        let command = ConfigCommand::new(ConfigCommands::Validate);
+        let result = command.run(&config_repo, &files());
+
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ConfigValidationFailed(2))
+        ));
+    }
+
+    #[test]
+    fn test_validate_passes_on_clean_config() {
+        let config_repo = MockConfigRepository::new();
+
+        let command = ConfigCommand::new(ConfigCommands::Validate);
+        assert!(command.run(&config_repo, &files()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_reads_project_config() {
+        let test_dir = TestDirectory::new();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        fs::create_dir_all(path_manager.kiro_dir(true)).unwrap();
+
+        fs::write(path_manager.config_path(true), "[spec]\nlang = \"en\"\n").unwrap();
+        assert!(
+            ConfigCommand::new(ConfigCommands::Validate)
+                .execute()
+                .is_ok()
+        );
+
+        fs::write(path_manager.config_path(true), "[spec]\nlang = 1\n").unwrap();
+        assert!(
+            ConfigCommand::new(ConfigCommands::Validate)
+                .execute()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_show_formats_provenance() {
+        let setting = EffectiveSetting {
+            key: "steering.types.tech.include_in_prompt".to_string(),
+            value: "false".to_string(),
+            source: ConfigSource::User,
+        };
+        assert_eq!(
+            format_setting(&setting),
+            "steering.types.tech.include_in_prompt = false  # user"
+        );
+
+        let config_repo = MockConfigRepository::new();
+        config_repo.set_effective_config(vec![setting]);
+        for effective in [false, true] {
+            let command = ConfigCommand::new(ConfigCommands::Show { effective });
+            assert!(command.run(&config_repo, &files()).is_ok());
+        }
+    }
+}
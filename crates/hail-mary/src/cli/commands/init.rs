@@ -0,0 +1,224 @@
+use anyhow::Result;
+
+use crate::application::use_cases::{InitializationReport, initialize_project};
+use crate::cli::formatters::{format_error, format_info, format_success, format_warning};
+use crate::domain::value_objects::file_change::FileChange;
+use crate::infrastructure::filesystem::file_writer::FileWriter;
+use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::repositories::{
+    config::ConfigRepository, spec::SpecRepository, steering::SteeringRepository,
+};
+use std::path::Path;
+
+pub struct InitCommand {
+    dry_run: bool,
+}
+
+impl InitCommand {
+    pub fn new(dry_run: bool) -> Self {
+        Self { dry_run }
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        // Initialize the enclosing project, or the current directory
+        let path_manager = match PathManager::discover() {
+            Ok(pm) => pm,
+            Err(_) => PathManager::new(std::env::current_dir()?),
+        };
+
+        let spec_repo = SpecRepository::new(path_manager.clone());
+        let (config_repo, steering_repo) = self.repositories(&path_manager);
+
+        let report = match initialize_project(&config_repo, &spec_repo, &steering_repo) {
+            Ok(report) => report,
+            Err(e) => {
+                println!("{}", format_error(&e.to_string()));
+                return Err(anyhow::anyhow!(e));
+            }
+        };
+
+        let steering_dir = path_manager.kiro_dir(false).join("steering");
+        let changes = file_changes(&report, &steering_dir);
+
+        if changes.is_empty() {
+            println!(
+                "{}",
+                format_info("Project is already initialized; nothing to change")
+            );
+            print_warnings(&report);
+            return Ok(());
+        }
+
+        if self.dry_run {
+            println!(
+                "{}",
+                format_info(&format!(
+                    "Dry run: initialization would make {} change(s)",
+                    changes.len()
+                ))
+            );
+        } else {
+            println!(
+                "{}",
+                format_success(&format!(
+                    "Initialized {} ({} change(s))",
+                    path_manager.project_root().display(),
+                    changes.len()
+                ))
+            );
+        }
+        for change in &changes {
+            println!("  {}", change);
+        }
+        for upgrade in &report.upgrades {
+            let verb = if self.dry_run {
+                "Would upgrade"
+            } else {
+                "Upgraded"
+            };
+            println!("{}", format_info(&format!("{} skill {}", verb, upgrade)));
+        }
+
+        let present: Vec<String> = report
+            .present_steering_files()
+            .map(|scaffold| scaffold.file.display().to_string())
+            .collect();
+        if !present.is_empty() {
+            println!(
+                "{}",
+                format_info(&format!(
+                    "Steering files already present: {}",
+                    present.join(", ")
+                ))
+            );
+        }
+        print_warnings(&report);
+
+        Ok(())
+    }
+
+    /// Repositories that write the project, or in a dry run share one writer
+    /// that only reports, so a directory planned by one isn't reported again
+    /// by the other
+    fn repositories(&self, path_manager: &PathManager) -> (ConfigRepository, SteeringRepository) {
+        let config_repo = ConfigRepository::new(path_manager.clone());
+        let steering_repo = SteeringRepository::new(path_manager.clone());
+        if !self.dry_run {
+            return (config_repo, steering_repo);
+        }
+
+        let writer = FileWriter::dry_run(path_manager.project_root().to_path_buf());
+        (
+            config_repo.with_writer(writer.clone()),
+            steering_repo.with_writer(writer),
+        )
+    }
+}
+
+fn print_warnings(report: &InitializationReport) {
+    for warning in &report.warnings {
+        println!("{}", format_warning(&warning.to_string()));
+    }
+}
+
+/// Every change in the report, with created steering files under `steering_dir`
+fn file_changes(report: &InitializationReport, steering_dir: &Path) -> Vec<FileChange> {
+    let mut changes = report.changes.clone();
+    changes.extend(
+        report
+            .created_steering_files()
+            .map(|scaffold| FileChange::create(steering_dir.join(&scaffold.file))),
+    );
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repositories::SteeringFileScaffold;
+    use crate::application::test_helpers::TestDirectory;
+    use std::fs;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_file_changes_appends_created_steering_files() {
+        let report = InitializationReport {
+            changes: vec![FileChange::create(".kiro/config.toml")],
+            steering_files: vec![
+                SteeringFileScaffold {
+                    file: PathBuf::from("product.md"),
+                    created: false,
+                },
+                SteeringFileScaffold {
+                    file: PathBuf::from("rust-dev.md"),
+                    created: true,
+                },
+            ],
+            warnings: vec![],
+            upgrades: vec![],
+        };
+
+        assert_eq!(
+            file_changes(&report, Path::new(".kiro/steering")),
+            vec![
+                FileChange::create(".kiro/config.toml"),
+                FileChange::create(".kiro/steering/rust-dev.md"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_init_dry_run_writes_nothing() {
+        let test_dir = TestDirectory::new();
+
+        let result = InitCommand::new(true).execute();
+
+        assert!(result.is_ok());
+        assert!(!test_dir.path().join(".kiro").exists());
+        assert!(!test_dir.path().join(".claude").exists());
+    }
+
+    #[test]
+    fn test_init_dry_run_reports_the_changes_of_a_real_run() {
+        let test_dir = TestDirectory::new();
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let spec_repo = SpecRepository::new(path_manager.clone());
+        let steering_dir = path_manager.kiro_dir(false).join("steering");
+        let changes = |dry_run: bool| {
+            let (config_repo, steering_repo) =
+                InitCommand::new(dry_run).repositories(&path_manager);
+            let report = initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+            file_changes(&report, &steering_dir)
+        };
+
+        let planned = changes(true);
+        assert!(!test_dir.path().join(".kiro").exists());
+        let made = changes(false);
+
+        assert!(!made.is_empty());
+        assert_eq!(planned, made);
+    }
+
+    #[test]
+    fn test_init_creates_project_and_is_idempotent() {
+        let test_dir = TestDirectory::new();
+
+        assert!(InitCommand::new(false).execute().is_ok());
+        let kiro_dir = test_dir.path().join(".kiro");
+        assert!(kiro_dir.join("config.toml").exists());
+        assert!(kiro_dir.join("steering").join("product.md").exists());
+        assert!(
+            test_dir
+                .path()
+                .join(".claude/commands/hm/steering.md")
+                .exists()
+        );
+
+        let config = fs::read_to_string(kiro_dir.join("config.toml")).unwrap();
+        assert!(InitCommand::new(false).execute().is_ok());
+        assert_eq!(
+            fs::read_to_string(kiro_dir.join("config.toml")).unwrap(),
+            config
+        );
+    }
+}
@@ -1,9 +1,21 @@
 pub mod code;
 pub mod complete;
 pub mod completion;
+pub mod config;
+pub mod init;
+pub mod slash_commands;
+pub mod spec;
+pub mod status;
+pub mod steering;
 pub mod steering_backup;
 
 // Re-export command structures
 pub use code::CodeCommand;
 pub use complete::CompleteCommand;
+pub use config::ConfigCommand;
+pub use init::InitCommand;
+pub use slash_commands::SlashCommandsCommand;
+pub use spec::SpecCommand;
+pub use status::StatusCommand;
+pub use steering::SteeringCommand;
 pub use steering_backup::SteeringBackupCommand;
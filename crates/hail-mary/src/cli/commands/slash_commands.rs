@@ -0,0 +1,150 @@
+use anyhow::Result;
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::steering_repository::SteeringRepositoryInterface;
+use crate::cli::args::SlashCommandCommands;
+use crate::cli::formatters::{format_error, format_info};
+use crate::domain::value_objects::slash_command::{SlashCommand, SlashCommandSource};
+use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::repositories::steering::SteeringRepository;
+use std::path::Path;
+
+pub struct SlashCommandsCommand {
+    command: SlashCommandCommands,
+}
+
+impl SlashCommandsCommand {
+    pub fn new(command: SlashCommandCommands) -> Self {
+        Self { command }
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        // Discover project root
+        let path_manager = match PathManager::discover() {
+            Ok(pm) => pm,
+            Err(_) => {
+                println!(
+                    "{}",
+                    format_error("Not in a project directory. Run 'hail-mary init' to initialize.")
+                );
+                return Err(anyhow::anyhow!("Project not found"));
+            }
+        };
+
+        let commands_dir = path_manager.commands_dir(false);
+        let steering_repo = SteeringRepository::new(path_manager);
+
+        match self.run(&steering_repo, &commands_dir) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                println!("{}", format_error(&e.to_string()));
+                Err(anyhow::anyhow!(e))
+            }
+        }
+    }
+
+    fn run(
+        &self,
+        steering_repo: &dyn SteeringRepositoryInterface,
+        commands_dir: &Path,
+    ) -> Result<(), ApplicationError> {
+        match self.command {
+            SlashCommandCommands::List => {
+                let commands = steering_repo.list_slash_commands()?;
+                println!(
+                    "{}",
+                    format_info(&format!(
+                        "Slash commands ({} overlays the embedded ones; run 'hail-mary init' to deploy changes)",
+                        commands_dir.display()
+                    ))
+                );
+
+                let width = commands
+                    .iter()
+                    .map(|command| command.invocation().len())
+                    .max()
+                    .unwrap_or(0);
+                for command in &commands {
+                    println!("{}", format_command(command, width, commands_dir));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_command(command: &SlashCommand, width: usize, commands_dir: &Path) -> String {
+    let line = format!(
+        "  {:width$}  {:8}",
+        command.invocation(),
+        command.source.to_string(),
+        width = width
+    );
+    match command.source {
+        SlashCommandSource::Embedded => line.trim_end().to_string(),
+        SlashCommandSource::Overlay => {
+            let file = commands_dir.join(&command.file_name);
+            if command.replaces_embedded {
+                format!("{}  {} (replaces embedded)", line, file.display())
+            } else {
+                format!("{}  {}", line, file.display())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::{MockSteeringRepository, TestDirectory};
+    use std::fs;
+
+    #[test]
+    fn test_format_command_shows_source() {
+        let commands = SlashCommand::overlay(
+            &[("steering.md", "embedded"), ("steering-remember.md", "")],
+            vec![
+                ("steering.md".to_string(), "ours".to_string()),
+                ("release.md".to_string(), "".to_string()),
+            ],
+        );
+        let lines: Vec<String> = commands
+            .iter()
+            .map(|command| format_command(command, 21, Path::new(".kiro/commands")))
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "  /hm:release            overlay   .kiro/commands/release.md",
+                "  /hm:steering-remember  embedded",
+                "  /hm:steering           overlay   .kiro/commands/steering.md (replaces embedded)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_list_surfaces_repository_errors() {
+        let steering_repo = MockSteeringRepository::new();
+        steering_repo.set_operation_to_fail("list_slash_commands");
+
+        let command = SlashCommandsCommand::new(SlashCommandCommands::List);
+        assert!(
+            command
+                .run(&steering_repo, Path::new(".kiro/commands"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_list_in_project() {
+        let test_dir = TestDirectory::new();
+        let commands_dir = test_dir.path().join(".kiro/commands");
+        fs::create_dir_all(&commands_dir).unwrap();
+        fs::write(commands_dir.join("release.md"), "cut a release").unwrap();
+
+        let command = SlashCommandsCommand::new(SlashCommandCommands::List);
+        assert!(command.execute().is_ok());
+    }
+}
@@ -0,0 +1,316 @@
+use anyhow::Result;
+use std::io::{self, Write};
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{
+    ConfigRepositoryInterface, OverriddenConfigRepository, SpecRepositoryInterface,
+};
+use crate::application::use_cases::{
+    SpecListing, SpecOverview, UnarchiveReport, complete_specs, create_new_sbi, create_new_spec,
+    list_specs, rename_spec, resolve_archived_spec_name, resolve_spec_name, show_spec,
+    unarchive_specs,
+};
+use crate::cli::args::SpecCommands;
+use crate::cli::formatters::{
+    format_error, format_header, format_info, format_list, format_success,
+};
+use crate::domain::value_objects::config_override::ConfigOverrides;
+use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::repositories::{config::ConfigRepository, spec::SpecRepository};
+
+pub struct SpecCommand {
+    command: SpecCommands,
+    config_overrides: ConfigOverrides,
+}
+
+impl SpecCommand {
+    pub fn new(command: SpecCommands) -> Self {
+        Self {
+            command,
+            config_overrides: ConfigOverrides::default(),
+        }
+    }
+
+    /// Apply `HAIL_MARY_*` / `--config` overrides when loading config
+    pub fn with_config_overrides(mut self, config_overrides: ConfigOverrides) -> Self {
+        self.config_overrides = config_overrides;
+        self
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        // Discover project root
+        let path_manager = match PathManager::discover() {
+            Ok(pm) => pm,
+            Err(_) => {
+                println!(
+                    "{}",
+                    format_error("Not in a project directory. Run 'hail-mary init' to initialize.")
+                );
+                return Err(anyhow::anyhow!("Project not found"));
+            }
+        };
+
+        let spec_repo = SpecRepository::new(path_manager.clone());
+        let file_config_repo = ConfigRepository::new(path_manager);
+        let config_repo =
+            OverriddenConfigRepository::new(&file_config_repo, self.config_overrides.clone());
+
+        match self.run(&spec_repo, &config_repo) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                println!("{}", format_error(&e.to_string()));
+                Err(anyhow::anyhow!(e))
+            }
+        }
+    }
+
+    fn run(
+        &self,
+        spec_repo: &dyn SpecRepositoryInterface,
+        config_repo: &dyn ConfigRepositoryInterface,
+    ) -> Result<(), ApplicationError> {
+        match &self.command {
+            SpecCommands::New { name, pbi } => {
+                let lang = config_repo.load_spec_config()?.lang;
+                let created = match pbi {
+                    Some(pbi) => {
+                        let pbi_name = resolve_spec_name(spec_repo, pbi)?;
+                        let sbi_name = create_new_sbi(spec_repo, &pbi_name, name, &lang)?;
+                        format!("{}/{}", pbi_name, sbi_name)
+                    }
+                    None => create_new_spec(spec_repo, name, &lang)?,
+                };
+                let path = spec_repo.get_spec_path(&created)?;
+                println!(
+                    "{}",
+                    format_success(&format!("Created specification: {}", path.display()))
+                );
+            }
+            SpecCommands::List => {
+                print_listing(&list_specs(spec_repo)?);
+            }
+            SpecCommands::Show { name, sbi } => {
+                print_overview(&show_spec(spec_repo, name, sbi.as_deref())?);
+            }
+            SpecCommands::Archive { names, on_conflict } => {
+                let resolved = names
+                    .iter()
+                    .map(|name| resolve_spec_name(spec_repo, name))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let archived = complete_specs(spec_repo, &resolved, (*on_conflict).into())?;
+                println!(
+                    "{}",
+                    format_success(&format!(
+                        "{} specification(s) moved to archive successfully.",
+                        archived.len()
+                    ))
+                );
+                println!("{}", format_list(&archived));
+            }
+            SpecCommands::Unarchive { names, on_conflict } => {
+                let resolved = names
+                    .iter()
+                    .map(|name| resolve_archived_spec_name(spec_repo, name))
+                    .collect::<Result<Vec<_>, _>>()?;
+                print_unarchive_report(&unarchive_specs(
+                    spec_repo,
+                    &resolved,
+                    (*on_conflict).into(),
+                ))?;
+            }
+            SpecCommands::Rename { name, new_name } => {
+                let (old, new) = rename_spec(spec_repo, name, new_name)?;
+                println!(
+                    "{}",
+                    format_success(&format!("Renamed specification: {} → {}", old, new))
+                );
+            }
+            SpecCommands::Delete { name, yes } => {
+                let resolved = resolve_spec_name(spec_repo, name)?;
+                if !*yes && !confirm(&format!("Delete specification '{}'?", resolved))? {
+                    println!("{}", format_info("Deletion cancelled."));
+                    return Ok(());
+                }
+                spec_repo.delete_spec(&resolved)?;
+                println!(
+                    "{}",
+                    format_success(&format!("Deleted specification: {}", resolved))
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Print what was restored and why the rest wasn't; fails if anything wasn't restored
+pub(crate) fn print_unarchive_report(report: &UnarchiveReport) -> Result<(), ApplicationError> {
+    if !report.restored.is_empty() {
+        let names: Vec<String> = report
+            .restored
+            .iter()
+            .map(|spec| spec.name.clone())
+            .collect();
+        println!(
+            "{}",
+            format_success(&format!(
+                "{} specification(s) restored from archive successfully.",
+                names.len()
+            ))
+        );
+        println!("{}", format_list(&names));
+    }
+    for spec in &report.restored {
+        if let Some(replaced) = &spec.replaced {
+            println!(
+                "{}",
+                format_info(&format!(
+                    "Replaced active {}; the previous version is archived as {}",
+                    spec.name, replaced
+                ))
+            );
+        }
+    }
+    for (name, e) in &report.failed {
+        println!("{}", format_error(&format!("{}: {}", name, e)));
+    }
+
+    if report.failed.is_empty() {
+        Ok(())
+    } else {
+        Err(ApplicationError::UnarchiveFailed(report.failed.len()))
+    }
+}
+
+fn print_listing(listing: &SpecListing) {
+    println!("{}", format_header("Active specifications"));
+    if listing.active.is_empty() {
+        println!("{}", format_info("No active specifications."));
+    }
+    for entry in &listing.active {
+        println!("  • {}", entry.name);
+        for sbi in &entry.sbis {
+            println!("      ◦ {}", sbi);
+        }
+    }
+
+    println!();
+    println!("{}", format_header("Archived specifications"));
+    if listing.archived.is_empty() {
+        println!("{}", format_info("No archived specifications."));
+    } else {
+        println!("{}", format_list(&listing.archived));
+    }
+}
+
+fn print_overview(overview: &SpecOverview) {
+    println!("{}", format_header(&overview.name));
+    println!("Path: {}", overview.path.display());
+
+    match &overview.progress {
+        Some(progress) => println!(
+            "Tasks: {}/{} completed ({}%)",
+            progress.completed,
+            progress.total,
+            progress.percentage()
+        ),
+        None => println!("Tasks: no tasks.md"),
+    }
+
+    println!();
+    println!("Files:");
+    let files: Vec<String> = overview
+        .files
+        .iter()
+        .map(|file| format!("{} ({} bytes)", file.name, file.size))
+        .collect();
+    println!("{}", format_list(&files));
+
+    if !overview.sbis.is_empty() {
+        println!();
+        println!("SBIs:");
+        println!("{}", format_list(&overview.sbis));
+    }
+}
+
+fn confirm(question: &str) -> Result<bool, ApplicationError> {
+    print!("{} [y/N]: ", question);
+    io::stdout()
+        .flush()
+        .map_err(|e| ApplicationError::FileSystemError(format!("Failed to flush stdout: {}", e)))?;
+
+    let mut answer = String::new();
+    io::stdin()
+        .read_line(&mut answer)
+        .map_err(|e| ApplicationError::FileSystemError(format!("Failed to read input: {}", e)))?;
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::TestDirectory;
+    use crate::cli::args::OnConflict;
+    use std::fs;
+
+    fn setup_project(test_dir: &TestDirectory) -> PathManager {
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        fs::create_dir_all(path_manager.specs_dir(true)).unwrap();
+        fs::write(path_manager.config_path(true), "[spec]\nlang = \"en\"\n").unwrap();
+        path_manager
+    }
+
+    #[test]
+    fn test_spec_command_new_and_archive() {
+        let test_dir = TestDirectory::new();
+        let path_manager = setup_project(&test_dir);
+
+        let command = SpecCommand::new(SpecCommands::New {
+            name: "feature".to_string(),
+            pbi: None,
+        });
+        assert!(command.execute().is_ok());
+
+        let created = fs::read_dir(path_manager.specs_dir(true))
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .find(|name| name.ends_with("-feature"))
+            .expect("spec should be created");
+
+        let command = SpecCommand::new(SpecCommands::Archive {
+            names: vec!["feature".to_string()],
+            on_conflict: OnConflict::Fail,
+        });
+        assert!(command.execute().is_ok());
+        assert!(path_manager.archive_dir(true).join(&created).exists());
+        assert!(!path_manager.specs_dir(true).join(&created).exists());
+    }
+
+    #[test]
+    fn test_spec_command_delete_with_yes() {
+        let test_dir = TestDirectory::new();
+        let path_manager = setup_project(&test_dir);
+        let spec_dir = path_manager.specs_dir(true).join("2025-01-01-obsolete");
+        fs::create_dir_all(&spec_dir).unwrap();
+
+        let command = SpecCommand::new(SpecCommands::Delete {
+            name: "obsolete".to_string(),
+            yes: true,
+        });
+        assert!(command.execute().is_ok());
+        assert!(!spec_dir.exists());
+    }
+
+    #[test]
+    fn test_spec_command_show_unknown_spec_fails() {
+        let test_dir = TestDirectory::new();
+        setup_project(&test_dir);
+
+        let command = SpecCommand::new(SpecCommands::Show {
+            name: "missing".to_string(),
+            sbi: None,
+        });
+        assert!(command.execute().is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use anyhow::Result;
+
+use crate::application::use_cases::{SpecStatus, spec_status};
+use crate::cli::formatters::{format_error, format_info};
+use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::repositories::spec::SpecRepository;
+
+pub struct StatusCommand {
+    spec: Option<String>,
+    json: bool,
+}
+
+impl StatusCommand {
+    pub fn new(spec: Option<String>, json: bool) -> Self {
+        Self { spec, json }
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        // Discover project root
+        let path_manager = match PathManager::discover() {
+            Ok(pm) => pm,
+            Err(_) => {
+                let message = "Not in a project directory. Run 'hail-mary init' to initialize.";
+                self.print_error(message);
+                return Err(anyhow::anyhow!(message));
+            }
+        };
+
+        let spec_repo = SpecRepository::new(path_manager);
+
+        let statuses = match spec_status(&spec_repo, self.spec.as_deref()) {
+            Ok(statuses) => statuses,
+            Err(e) => {
+                self.print_error(&e.to_string());
+                return Err(anyhow::anyhow!(e));
+            }
+        };
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&statuses)?);
+            return Ok(());
+        }
+
+        if statuses.is_empty() {
+            println!("{}", format_info("No active specifications."));
+            return Ok(());
+        }
+
+        for status in &statuses {
+            println!("{}", format_status_line(status));
+        }
+
+        Ok(())
+    }
+
+    /// With `--json`, stdout carries nothing but JSON; the returned error is
+    /// still reported on stderr
+    fn print_error(&self, message: &str) {
+        if !self.json {
+            println!("{}", format_error(message));
+        }
+    }
+}
+
+fn format_status_line(status: &SpecStatus) -> String {
+    if !status.has_tasks {
+        return format!("  • {}  (no tasks.md)", status.name);
+    }
+
+    format!(
+        "  • {}  {:>3}% ({}/{})  Next: {}",
+        status.name,
+        status.percentage,
+        status.progress.completed,
+        status.progress.total,
+        status.next_action.as_deref().unwrap_or("-")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::tasks::TaskProgress;
+
+    #[test]
+    fn test_format_status_line() {
+        let status = SpecStatus {
+            name: "2025-01-01-feature".to_string(),
+            has_tasks: true,
+            progress: TaskProgress {
+                completed: 1,
+                total: 3,
+            },
+            percentage: 33,
+            next_action: Some("Define requirements".to_string()),
+            documents: Vec::new(),
+        };
+
+        assert_eq!(
+            format_status_line(&status),
+            "  • 2025-01-01-feature   33% (1/3)  Next: Define requirements"
+        );
+    }
+
+    #[test]
+    fn test_format_status_line_without_tasks() {
+        let status = SpecStatus {
+            name: "2025-01-01-feature".to_string(),
+            has_tasks: false,
+            progress: TaskProgress::default(),
+            percentage: 0,
+            next_action: None,
+            documents: Vec::new(),
+        };
+
+        assert_eq!(
+            format_status_line(&status),
+            "  • 2025-01-01-feature  (no tasks.md)"
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use anyhow::Result;
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{
+    ConfigRepositoryInterface, OverriddenConfigRepository, SteeringRepositoryInterface,
+};
+use crate::application::use_cases::{
+    RestoreReport, SteeringBackupEntry, SteeringFileChange, SteeringFileLint, SteeringTypeEdit,
+    SteeringTypeEntry, add_steering_type, backup_steering, diff_steering_backup,
+    edit_steering_type, lint_steering, list_steering_backups, list_steering_types,
+    remove_steering_type, restore_steering_backup,
+};
+use crate::cli::args::{SteeringBackupCommands, SteeringCommands, SteeringTypeCommands};
+use crate::cli::formatters::{
+    format_error, format_header, format_info, format_list, format_success,
+};
+use crate::domain::value_objects::config_override::ConfigOverrides;
+use crate::domain::value_objects::steering_backup::BackupReason;
+use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::repositories::{config::ConfigRepository, steering::SteeringRepository};
+
+pub struct SteeringCommand {
+    command: SteeringCommands,
+    config_overrides: ConfigOverrides,
+}
+
+impl SteeringCommand {
+    pub fn new(command: SteeringCommands) -> Self {
+        Self {
+            command,
+            config_overrides: ConfigOverrides::default(),
+        }
+    }
+
+    /// Apply `HAIL_MARY_*` / `--config` overrides when loading config
+    pub fn with_config_overrides(mut self, config_overrides: ConfigOverrides) -> Self {
+        self.config_overrides = config_overrides;
+        self
+    }
+
+    pub fn execute(&self) -> Result<()> {
+        // Discover project root
+        let path_manager = match PathManager::discover() {
+            Ok(pm) => pm,
+            Err(_) => {
+                println!(
+                    "{}",
+                    format_error("Not in a project directory. Run 'hail-mary init' to initialize.")
+                );
+                return Err(anyhow::anyhow!("Project not found"));
+            }
+        };
+
+        let file_config_repo = ConfigRepository::new(path_manager.clone());
+        let overridden_config_repo =
+            OverriddenConfigRepository::new(&file_config_repo, self.config_overrides.clone());
+        let steering_repo = SteeringRepository::new(path_manager);
+
+        // Type edits write back what they load, so overrides must not leak into config.toml
+        let config_repo: &dyn ConfigRepositoryInterface = match self.command {
+            SteeringCommands::Type { .. } => &file_config_repo,
+            _ => &overridden_config_repo,
+        };
+
+        match self.run(config_repo, &steering_repo) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                println!("{}", format_error(&e.to_string()));
+                Err(anyhow::anyhow!(e))
+            }
+        }
+    }
+
+    fn run(
+        &self,
+        config_repo: &dyn ConfigRepositoryInterface,
+        steering_repo: &dyn SteeringRepositoryInterface,
+    ) -> Result<(), ApplicationError> {
+        match &self.command {
+            SteeringCommands::Backup {
+                command: Some(SteeringBackupCommands::List),
+            } => {
+                print_backups(&list_steering_backups(steering_repo)?);
+            }
+            SteeringCommands::Backup { command: None } => {
+                let message = backup_steering(config_repo, steering_repo)?;
+                println!("{}", format_success(&message));
+            }
+            SteeringCommands::Diff { backup } => {
+                let (backup, diffs) = diff_steering_backup(steering_repo, backup)?;
+                if diffs.is_empty() {
+                    println!(
+                        "{}",
+                        format_info(&format!(
+                            "No differences between backup '{}' and the current steering files",
+                            backup
+                        ))
+                    );
+                }
+                for diff in &diffs {
+                    let label = match diff.change {
+                        SteeringFileChange::Modified => "modified",
+                        SteeringFileChange::Added => "only in current",
+                        SteeringFileChange::Removed => "only in backup",
+                    };
+                    println!("{} ({})", diff.file.display(), label);
+                    print!("{}", diff.diff);
+                }
+            }
+            SteeringCommands::Lint => {
+                let results = lint_steering(config_repo, steering_repo)?;
+                let issues: usize = results.iter().map(|result| result.issues.len()).sum();
+                print_lint(&results);
+                if issues > 0 {
+                    return Err(ApplicationError::SteeringLintFailed(issues));
+                }
+                println!(
+                    "{}",
+                    format_success("All steering files match their criteria")
+                );
+            }
+            SteeringCommands::Type { command } => {
+                run_type_command(config_repo, steering_repo, command)?;
+            }
+            SteeringCommands::Restore { backup, file } => {
+                print_restore(&restore_steering_backup(
+                    config_repo,
+                    steering_repo,
+                    backup,
+                    file.as_deref(),
+                )?);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn run_type_command(
+    config_repo: &dyn ConfigRepositoryInterface,
+    steering_repo: &dyn SteeringRepositoryInterface,
+    command: &SteeringTypeCommands,
+) -> Result<(), ApplicationError> {
+    match command {
+        SteeringTypeCommands::List => {
+            print_types(&list_steering_types(config_repo, steering_repo)?);
+        }
+        SteeringTypeCommands::Add {
+            name,
+            purpose,
+            criteria,
+            include_in_prompt,
+        } => {
+            let added = add_steering_type(
+                config_repo,
+                steering_repo,
+                name,
+                purpose,
+                criteria.clone(),
+                *include_in_prompt,
+            )?;
+            println!(
+                "{}",
+                format_success(&format!(
+                    "Added steering type '{}' with {} criteria ({}.md)",
+                    added.name,
+                    added.criteria.len(),
+                    added.name
+                ))
+            );
+        }
+        SteeringTypeCommands::Edit {
+            name,
+            rename,
+            purpose,
+            criteria,
+            include_in_prompt,
+        } => {
+            let edit = SteeringTypeEdit {
+                name: rename.clone(),
+                purpose: purpose.clone(),
+                criteria: (!criteria.is_empty()).then(|| criteria.clone()),
+                include_in_prompt: *include_in_prompt,
+            };
+            let edited = edit_steering_type(config_repo, steering_repo, name, edit)?;
+            let message = if edited.name == *name {
+                format!("Updated steering type '{}'", name)
+            } else {
+                format!(
+                    "Renamed steering type '{}' to '{}' ({}.md)",
+                    name, edited.name, edited.name
+                )
+            };
+            println!("{}", format_success(&message));
+        }
+        SteeringTypeCommands::Remove { name } => {
+            let removal = remove_steering_type(config_repo, steering_repo, name)?;
+            if let Some(backup) = &removal.backup {
+                println!(
+                    "{}",
+                    format_info(&format!("Saved steering files as backup '{}'", backup))
+                );
+            }
+            let file = if removal.file_removed {
+                format!(" and deleted {}.md", name)
+            } else {
+                String::new()
+            };
+            println!(
+                "{}",
+                format_success(&format!("Removed steering type '{}'{}", name, file))
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn print_types(entries: &[SteeringTypeEntry]) {
+    println!("{}", format_header("Steering types"));
+    if entries.is_empty() {
+        println!("{}", format_info("No steering types configured."));
+        return;
+    }
+
+    for entry in entries {
+        let steering_type = &entry.steering_type;
+        let mut notes = Vec::new();
+        if !steering_type.include_in_prompt {
+            notes.push("not in prompt");
+        }
+        if !entry.file_exists {
+            notes.push("file missing");
+        }
+        let notes = if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", notes.join(", "))
+        };
+
+        println!("{}{}: {}", steering_type.name, notes, steering_type.purpose);
+        let criteria: Vec<String> = steering_type
+            .criteria
+            .iter()
+            .map(|criterion| criterion.name.clone())
+            .collect();
+        println!("{}", format_list(&criteria));
+    }
+}
+
+fn print_backups(backups: &[SteeringBackupEntry]) {
+    println!("{}", format_header("Steering backups"));
+    if backups.is_empty() {
+        println!("{}", format_info("No steering backups found."));
+        return;
+    }
+
+    let lines: Vec<String> = backups
+        .iter()
+        .enumerate()
+        .map(|(i, backup)| {
+            let latest = if i + 1 == backups.len() {
+                " (latest)"
+            } else {
+                ""
+            };
+            let files: Vec<String> = backup
+                .files
+                .iter()
+                .map(|file| file.display().to_string())
+                .collect();
+            let reason = match backup.reason {
+                Some(BackupReason::BeforeRestore) => " [before restore]",
+                Some(BackupReason::BeforeTypeRemoval) => " [before type removal]",
+                _ => "",
+            };
+            format!("{}{}{}: {}", backup.name, latest, reason, files.join(", "))
+        })
+        .collect();
+    println!("{}", format_list(&lines));
+}
+
+fn print_lint(results: &[SteeringFileLint]) {
+    for result in results {
+        if result.issues.is_empty() {
+            println!("✓ {}", result.file.display());
+            continue;
+        }
+
+        println!("✗ {}", result.file.display());
+        let issues: Vec<String> = result
+            .issues
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect();
+        println!("{}", format_list(&issues));
+    }
+}
+
+fn print_restore(report: &RestoreReport) {
+    if let Some(safety_backup) = &report.safety_backup {
+        println!(
+            "{}",
+            format_info(&format!(
+                "Saved current steering files as backup '{}'",
+                safety_backup
+            ))
+        );
+    }
+
+    println!(
+        "{}",
+        format_success(&format!(
+            "Restored {} file(s) from backup '{}'",
+            report.restored.len(),
+            report.backup
+        ))
+    );
+    let files: Vec<String> = report
+        .restored
+        .iter()
+        .map(|file| file.display().to_string())
+        .collect();
+    println!("{}", format_list(&files));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::TestDirectory;
+    use std::fs;
+
+    fn setup_project(test_dir: &TestDirectory) -> PathManager {
+        let path_manager = PathManager::new(test_dir.path().to_path_buf());
+        let steering_dir = path_manager.kiro_dir(true).join("steering");
+        fs::create_dir_all(steering_dir.join("backup/2025-01-01-10-00")).unwrap();
+        fs::write(
+            steering_dir.join("backup/2025-01-01-10-00/tech.md"),
+            "# Tech v1\n",
+        )
+        .unwrap();
+        fs::write(steering_dir.join("tech.md"), "# Tech v2\n").unwrap();
+        fs::write(
+            path_manager.config_path(true),
+            "[steering.backup]\nmax = 10\n",
+        )
+        .unwrap();
+        path_manager
+    }
+
+    #[test]
+    fn test_steering_command_list_and_diff() {
+        let test_dir = TestDirectory::new();
+        setup_project(&test_dir);
+
+        let command = SteeringCommand::new(SteeringCommands::Backup {
+            command: Some(SteeringBackupCommands::List),
+        });
+        assert!(command.execute().is_ok());
+
+        let command = SteeringCommand::new(SteeringCommands::Diff {
+            backup: "latest".to_string(),
+        });
+        assert!(command.execute().is_ok());
+    }
+
+    #[test]
+    fn test_steering_command_restore() {
+        let test_dir = TestDirectory::new();
+        let path_manager = setup_project(&test_dir);
+        let steering_dir = path_manager.kiro_dir(true).join("steering");
+
+        let command = SteeringCommand::new(SteeringCommands::Restore {
+            backup: "2025-01-01-10-00".to_string(),
+            file: Some("tech".to_string()),
+        });
+        assert!(command.execute().is_ok());
+
+        assert_eq!(
+            fs::read_to_string(steering_dir.join("tech.md")).unwrap(),
+            "# Tech v1\n"
+        );
+        let steering_repo = SteeringRepository::new(path_manager);
+        assert_eq!(steering_repo.list_steering_backups().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_steering_command_lint() {
+        let test_dir = TestDirectory::new();
+        let path_manager = setup_project(&test_dir);
+        let steering_dir = path_manager.kiro_dir(true).join("steering");
+        fs::write(
+            path_manager.config_path(true),
+            "[[steering.types]]\nname = \"tech\"\npurpose = \"Stack\"\ncriteria = [\"Architecture: Design\"]\n",
+        )
+        .unwrap();
+
+        let command = SteeringCommand::new(SteeringCommands::Lint);
+        assert!(command.execute().is_err());
+
+        fs::write(
+            steering_dir.join("tech.md"),
+            "# Tech\n\n## Architecture\n\nLayers\n",
+        )
+        .unwrap();
+        assert!(command.execute().is_ok());
+    }
+
+    #[test]
+    fn test_steering_command_type_add_and_list() {
+        let test_dir = TestDirectory::new();
+        let path_manager = setup_project(&test_dir);
+
+        let command = SteeringCommand::new(SteeringCommands::Type {
+            command: SteeringTypeCommands::Add {
+                name: "security".to_string(),
+                purpose: "Security policies".to_string(),
+                criteria: vec!["Threat Model: Assets".to_string()],
+                include_in_prompt: true,
+            },
+        });
+        assert!(command.execute().is_ok());
+        assert!(
+            path_manager
+                .kiro_dir(true)
+                .join("steering/security.md")
+                .exists()
+        );
+
+        // Adding the same type twice fails
+        assert!(command.execute().is_err());
+
+        let command = SteeringCommand::new(SteeringCommands::Type {
+            command: SteeringTypeCommands::List,
+        });
+        assert!(command.execute().is_ok());
+    }
+
+    #[test]
+    fn test_steering_command_unknown_backup_fails() {
+        let test_dir = TestDirectory::new();
+        setup_project(&test_dir);
+
+        let command = SteeringCommand::new(SteeringCommands::Diff {
+            backup: "2024-12-31-10-00".to_string(),
+        });
+        assert!(command.execute().is_err());
+    }
+}
@@ -1,10 +1,14 @@
+use crate::application::repositories::OverriddenConfigRepository;
 use crate::application::use_cases::backup_steering;
 use crate::cli::formatters::{format_error, format_success};
+use crate::domain::value_objects::config_override::ConfigOverrides;
 use crate::infrastructure::filesystem::path_manager::PathManager;
 use crate::infrastructure::repositories::{config::ConfigRepository, steering::SteeringRepository};
 use anyhow::Result;
 
-pub struct SteeringBackupCommand;
+pub struct SteeringBackupCommand {
+    config_overrides: ConfigOverrides,
+}
 
 impl Default for SteeringBackupCommand {
     fn default() -> Self {
@@ -14,7 +18,15 @@ impl Default for SteeringBackupCommand {
 
 impl SteeringBackupCommand {
     pub fn new() -> Self {
-        Self
+        Self {
+            config_overrides: ConfigOverrides::default(),
+        }
+    }
+
+    /// Apply `HAIL_MARY_*` / `--config` overrides when loading config
+    pub fn with_config_overrides(mut self, config_overrides: ConfigOverrides) -> Self {
+        self.config_overrides = config_overrides;
+        self
     }
 
     pub fn execute(&self) -> Result<()> {
@@ -23,7 +35,9 @@ impl SteeringBackupCommand {
         let path_manager = PathManager::new(current_dir);
 
         // Create repositories
-        let config_repo = ConfigRepository::new(path_manager.clone());
+        let file_config_repo = ConfigRepository::new(path_manager.clone());
+        let config_repo =
+            OverriddenConfigRepository::new(&file_config_repo, self.config_overrides.clone());
         let steering_repo = SteeringRepository::new(path_manager);
 
         // Execute backup use case
@@ -49,13 +63,13 @@ mod tests {
     #[test]
     fn test_steering_backup_command_new() {
         let command = SteeringBackupCommand::new();
-        assert!(matches!(command, SteeringBackupCommand));
+        assert!(command.config_overrides.is_empty());
     }
 
     #[test]
     fn test_steering_backup_command_default() {
-        let command = SteeringBackupCommand;
-        assert!(matches!(command, SteeringBackupCommand));
+        let command = SteeringBackupCommand::default();
+        assert!(command.config_overrides.is_empty());
     }
 
     #[test]
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Agent CLI that `hail-mary code` launches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgentBackend {
+    #[default]
+    Claude,
+    Opencode,
+    Codex,
+    Gemini,
+}
+
+impl AgentBackend {
+    pub const ALL: [AgentBackend; 4] = [
+        AgentBackend::Claude,
+        AgentBackend::Opencode,
+        AgentBackend::Codex,
+        AgentBackend::Gemini,
+    ];
+
+    /// Config value and default executable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            AgentBackend::Claude => "claude",
+            AgentBackend::Opencode => "opencode",
+            AgentBackend::Codex => "codex",
+            AgentBackend::Gemini => "gemini",
+        }
+    }
+}
+
+impl fmt::Display for AgentBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The `[agent]` section of `.kiro/config.toml`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentConfig {
+    #[serde(default)]
+    pub backend: AgentBackend,
+    /// Executable to run instead of the backend's default binary name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Extra arguments passed after the backend's own flags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+}
+
+impl AgentConfig {
+    pub fn program(&self) -> &str {
+        self.command.as_deref().unwrap_or(self.backend.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agent_config_defaults_to_claude() {
+        let config: AgentConfig = toml::from_str("").unwrap();
+        assert_eq!(config, AgentConfig::default());
+        assert_eq!(config.program(), "claude");
+    }
+
+    #[test]
+    fn test_agent_config_parses_backend_and_command() {
+        let config: AgentConfig = toml::from_str(
+            r#"
+backend = "opencode"
+command = "/opt/bin/opencode"
+args = ["--model", "anthropic/claude-sonnet-4"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.backend, AgentBackend::Opencode);
+        assert_eq!(config.program(), "/opt/bin/opencode");
+        assert_eq!(config.args.len(), 2);
+    }
+
+    #[test]
+    fn test_agent_config_rejects_unknown_backend() {
+        let result = toml::from_str::<AgentConfig>(r#"backend = "cursor""#);
+        assert!(result.is_err());
+    }
+}
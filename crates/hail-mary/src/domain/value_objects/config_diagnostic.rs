@@ -0,0 +1,70 @@
+use std::fmt;
+use std::path::PathBuf;
+
+/// A problem found in a config file, located by 1-based line and column
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigDiagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ConfigDiagnostic {
+    /// Locate `offset` (a byte index into `source`) and attach `message`
+    pub fn at_offset(source: &str, offset: usize, message: impl Into<String>) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.into(),
+        }
+    }
+}
+
+/// The problems found in one config file (none when it is valid)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigFileDiagnostics {
+    pub path: PathBuf,
+    pub diagnostics: Vec<ConfigDiagnostic>,
+}
+
+impl fmt::Display for ConfigDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_offset_counts_lines_and_characters() {
+        let source = "[spec]\nlang = \"日本\"\n";
+
+        let start = ConfigDiagnostic::at_offset(source, 0, "a");
+        assert_eq!((start.line, start.column), (1, 1));
+
+        let value = ConfigDiagnostic::at_offset(source, source.find('"').unwrap(), "b");
+        assert_eq!((value.line, value.column), (2, 8));
+
+        let end = ConfigDiagnostic::at_offset(source, source.len() + 10, "c");
+        assert_eq!((end.line, end.column), (3, 1));
+    }
+
+    #[test]
+    fn test_display() {
+        let diagnostic = ConfigDiagnostic {
+            line: 4,
+            column: 12,
+            message: "unknown key 'nmae'".to_string(),
+        };
+        assert_eq!(diagnostic.to_string(), "4:12: unknown key 'nmae'");
+    }
+}
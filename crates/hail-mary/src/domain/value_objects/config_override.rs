@@ -0,0 +1,547 @@
+use crate::domain::value_objects::agent::{AgentBackend, AgentConfig};
+use crate::domain::value_objects::config_source::ConfigSource;
+use crate::domain::value_objects::steering::{
+    Criterion, STEERING_OPERATIONS, SpecConfig, SteeringBackupConfig, SteeringConfig, SteeringType,
+};
+use std::ffi::OsString;
+
+/// Prefix of environment variables that override config values
+pub const ENV_PREFIX: &str = "HAIL_MARY_";
+
+/// Top-level sections an environment variable must name to count as an override
+const SECTIONS: &[&str] = &["spec", "steering", "agent"];
+
+/// A typed value for one overridable key
+#[derive(Debug, Clone, PartialEq)]
+enum Setting {
+    SpecLang(String),
+    BackupMax(usize),
+    AgentBackend(AgentBackend),
+    AgentCommand(String),
+    AgentArgs(Vec<String>),
+    TypePurpose(String, String),
+    TypeCriteria(String, Vec<Criterion>),
+    TypeAllowedOperations(String, Vec<String>),
+    TypeIncludeInPrompt(String, bool),
+}
+
+/// One config value set from outside the config files for a single invocation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigOverride {
+    /// Dotted key, as shown by `config show` (`spec.lang`, `steering.types.tech.purpose`)
+    pub key: String,
+    /// The value as given
+    pub value: String,
+    pub source: ConfigSource,
+    setting: Setting,
+}
+
+impl ConfigOverride {
+    /// Parses and type-checks `key` = `value`
+    pub fn new(key: &str, value: &str, source: ConfigSource) -> Result<Self, String> {
+        let setting = parse_setting(key, value).map_err(|e| format!("{}: {}", key, e))?;
+        Ok(Self {
+            key: key.to_string(),
+            value: value.to_string(),
+            source,
+            setting,
+        })
+    }
+
+    /// `--config spec.lang=en`
+    pub fn parse_assignment(assignment: &str) -> Result<Self, String> {
+        let (key, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("'{}' must be written as KEY=VALUE", assignment))?;
+        Self::new(key.trim(), value.trim(), ConfigSource::CommandLine)
+    }
+
+    /// `HAIL_MARY_SPEC__LANG=en` (`__` separates keys); `None` when the variable does
+    /// not name a config section, so unrelated `HAIL_MARY_*` variables are left alone.
+    ///
+    /// Steering type names are kebab-case, which variable names cannot spell, so `_`
+    /// in the type name stands for `-`: `HAIL_MARY_STEERING__TYPES__RUST_DEV__PURPOSE`
+    /// sets `steering.types.rust-dev.purpose`.
+    pub fn from_env_var(name: &str, value: &str) -> Option<Result<Self, String>> {
+        let mut segments: Vec<String> = name
+            .strip_prefix(ENV_PREFIX)?
+            .split("__")
+            .map(str::to_lowercase)
+            .collect();
+        if !SECTIONS.contains(&segments[0].as_str()) {
+            return None;
+        }
+        if segments.len() > 2 && segments[0] == "steering" && segments[1] == "types" {
+            segments[2] = segments[2].replace('_', "-");
+        }
+        let key = segments.join(".");
+
+        Some(
+            Self::new(&key, value, ConfigSource::Environment)
+                .map_err(|e| format!("{}: {}", name, e)),
+        )
+    }
+
+    /// The value rendered as TOML
+    pub fn toml_value(&self) -> String {
+        match &self.setting {
+            Setting::SpecLang(s) | Setting::AgentCommand(s) | Setting::TypePurpose(_, s) => {
+                toml_string(s)
+            }
+            Setting::AgentBackend(backend) => toml_string(backend.name()),
+            Setting::BackupMax(max) => max.to_string(),
+            Setting::TypeIncludeInPrompt(_, include) => include.to_string(),
+            Setting::AgentArgs(values) | Setting::TypeAllowedOperations(_, values) => {
+                toml_array(values.iter().map(String::as_str))
+            }
+            Setting::TypeCriteria(_, criteria) => {
+                let criteria: Vec<String> = criteria.iter().map(ToString::to_string).collect();
+                toml_array(criteria.iter().map(String::as_str))
+            }
+        }
+    }
+}
+
+/// Overrides in increasing precedence: environment variables, then `--config`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigOverrides(Vec<ConfigOverride>);
+
+impl ConfigOverrides {
+    pub fn new(
+        env_vars: impl IntoIterator<Item = (String, String)>,
+        assignments: &[String],
+    ) -> Result<Self, String> {
+        let mut env_vars: Vec<_> = env_vars.into_iter().collect();
+        env_vars.sort();
+
+        let mut overrides = Vec::new();
+        for (name, value) in env_vars {
+            if let Some(parsed) = ConfigOverride::from_env_var(&name, &value) {
+                overrides.push(parsed?);
+            }
+        }
+        for assignment in assignments {
+            overrides.push(ConfigOverride::parse_assignment(assignment)?);
+        }
+
+        Ok(Self(overrides))
+    }
+
+    /// Overrides from the process environment (`std::env::vars_os`) and `--config`.
+    /// Variables that are not valid UTF-8 cannot be overrides and are skipped.
+    pub fn from_env(
+        env_vars: impl IntoIterator<Item = (OsString, OsString)>,
+        assignments: &[String],
+    ) -> Result<Self, String> {
+        let env_vars = env_vars.into_iter().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        });
+        Self::new(env_vars, assignments)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ConfigOverride> {
+        self.0.iter()
+    }
+
+    pub fn apply_to_spec(&self, spec: &mut SpecConfig) {
+        for config_override in &self.0 {
+            if let Setting::SpecLang(lang) = &config_override.setting {
+                spec.lang = lang.clone();
+            }
+        }
+    }
+
+    pub fn apply_to_backup(&self, backup: &mut SteeringBackupConfig) {
+        for config_override in &self.0 {
+            if let Setting::BackupMax(max) = &config_override.setting {
+                backup.max = *max;
+            }
+        }
+    }
+
+    pub fn apply_to_agent(&self, agent: &mut AgentConfig) {
+        for config_override in &self.0 {
+            match &config_override.setting {
+                Setting::AgentBackend(backend) => agent.backend = *backend,
+                Setting::AgentCommand(command) => agent.command = Some(command.clone()),
+                Setting::AgentArgs(args) => agent.args = args.clone(),
+                _ => {}
+            }
+        }
+    }
+
+    /// Fails when an override names a steering type that is not configured
+    pub fn apply_to_steering(&self, steering: &mut SteeringConfig) -> Result<(), String> {
+        self.apply_to_backup(&mut steering.backup);
+
+        for config_override in &self.0 {
+            let name = match &config_override.setting {
+                Setting::TypePurpose(name, _)
+                | Setting::TypeCriteria(name, _)
+                | Setting::TypeAllowedOperations(name, _)
+                | Setting::TypeIncludeInPrompt(name, _) => name,
+                _ => continue,
+            };
+            let steering_type = steering
+                .types
+                .iter_mut()
+                .find(|t| &t.name == name)
+                .ok_or_else(|| {
+                    format!(
+                        "{}: no steering type '{}' is configured",
+                        config_override.key, name
+                    )
+                })?;
+
+            match &config_override.setting {
+                Setting::TypePurpose(_, purpose) => steering_type.purpose = purpose.clone(),
+                Setting::TypeCriteria(_, criteria) => steering_type.criteria = criteria.clone(),
+                Setting::TypeAllowedOperations(_, operations) => {
+                    steering_type.allowed_operations = operations.clone()
+                }
+                Setting::TypeIncludeInPrompt(_, include) => {
+                    steering_type.include_in_prompt = *include
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_setting(key: &str, value: &str) -> Result<Setting, String> {
+    let segments: Vec<&str> = key.split('.').collect();
+    match segments.as_slice() {
+        ["spec", "lang"] => {
+            let lang = unquote(value);
+            SpecConfig::validate_lang(&lang)?;
+            Ok(Setting::SpecLang(lang))
+        }
+        ["steering", "backup", "max"] => unquote(value)
+            .parse()
+            .map(Setting::BackupMax)
+            .map_err(|_| format!("'{}' is not a non-negative integer", value)),
+        ["agent", "backend"] => {
+            let backend = unquote(value);
+            AgentBackend::ALL
+                .iter()
+                .find(|b| b.name() == backend)
+                .map(|b| Setting::AgentBackend(*b))
+                .ok_or_else(|| format!("unknown agent backend '{}'", backend))
+        }
+        ["agent", "command"] => Ok(Setting::AgentCommand(unquote(value))),
+        ["agent", "args"] => parse_list(value).map(Setting::AgentArgs),
+        ["steering", "types", name, field] => {
+            SteeringType::validate_name(name)?;
+            let name = name.to_string();
+            match *field {
+                "purpose" => Ok(Setting::TypePurpose(name, unquote(value))),
+                "criteria" => parse_list(value)?
+                    .iter()
+                    .map(|c| {
+                        c.split_once(": ")
+                            .filter(|(name, _)| !name.trim().is_empty())
+                            .and_then(|_| Criterion::parse_from_string(c))
+                            .ok_or_else(|| {
+                                format!(
+                                    "criterion '{}' must be written as \"Name: Description\"",
+                                    c
+                                )
+                            })
+                    })
+                    .collect::<Result<_, _>>()
+                    .map(|criteria| Setting::TypeCriteria(name, criteria)),
+                "allowed_operations" => {
+                    let operations = parse_list(value)?;
+                    match operations
+                        .iter()
+                        .find(|op| !STEERING_OPERATIONS.contains(&op.as_str()))
+                    {
+                        Some(unknown) => Err(format!(
+                            "unknown operation '{}' (expected one of: {})",
+                            unknown,
+                            STEERING_OPERATIONS.join(", ")
+                        )),
+                        None => Ok(Setting::TypeAllowedOperations(name, operations)),
+                    }
+                }
+                "include_in_prompt" => unquote(value)
+                    .parse()
+                    .map(|include| Setting::TypeIncludeInPrompt(name, include))
+                    .map_err(|_| format!("'{}' is not true or false", value)),
+                _ => Err("unknown key".to_string()),
+            }
+        }
+        _ => Err("unknown key".to_string()),
+    }
+}
+
+/// Accepts both `en` and the TOML spelling `"en"`
+fn unquote(value: &str) -> String {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|rest| rest.strip_suffix(quote))
+        {
+            return inner.to_string();
+        }
+    }
+    value.to_string()
+}
+
+/// `a, b` or the TOML spelling `["a", "b"]`, whose items may contain commas
+fn parse_list(value: &str) -> Result<Vec<String>, String> {
+    let value = value.trim();
+    if !value.starts_with('[') {
+        return Ok(value
+            .split(',')
+            .map(unquote)
+            .filter(|item| !item.is_empty())
+            .collect());
+    }
+
+    let not_a_list = || format!("'{}' is not a TOML array of strings", value);
+    let table: toml::Table = toml::from_str(&format!("v = {}", value)).map_err(|_| not_a_list())?;
+    match table.get("v") {
+        Some(toml::Value::Array(items)) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string).ok_or_else(not_a_list))
+            .collect(),
+        _ => Err(not_a_list()),
+    }
+}
+
+fn toml_string(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn toml_array<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let values: Vec<String> = values.map(toml_string).collect();
+    format!("[{}]", values.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_env_var_names_map_to_keys() {
+        let config_override = ConfigOverride::from_env_var("HAIL_MARY_SPEC__LANG", "en")
+            .unwrap()
+            .unwrap();
+        assert_eq!(config_override.key, "spec.lang");
+        assert_eq!(config_override.source, ConfigSource::Environment);
+
+        let config_override = ConfigOverride::from_env_var(
+            "HAIL_MARY_STEERING__TYPES__TECH__INCLUDE_IN_PROMPT",
+            "false",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(config_override.key, "steering.types.tech.include_in_prompt");
+
+        // `_` in a steering type name stands for `-`; field names keep theirs
+        let config_override = ConfigOverride::from_env_var(
+            "HAIL_MARY_STEERING__TYPES__RUST_DEV__INCLUDE_IN_PROMPT",
+            "true",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            config_override.key,
+            "steering.types.rust-dev.include_in_prompt"
+        );
+
+        // Not config: other prefixes and unrelated HAIL_MARY_ variables
+        assert!(ConfigOverride::from_env_var("SPEC__LANG", "en").is_none());
+        assert!(ConfigOverride::from_env_var("HAIL_MARY_DEBUG", "1").is_none());
+
+        let error = ConfigOverride::from_env_var("HAIL_MARY_SPEC__LNG", "en")
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(error, "HAIL_MARY_SPEC__LNG: spec.lng: unknown key");
+    }
+
+    #[test]
+    fn test_assignments_are_type_checked() {
+        let config_override = ConfigOverride::parse_assignment("steering.backup.max = 3").unwrap();
+        assert_eq!(config_override.toml_value(), "3");
+        assert_eq!(config_override.source, ConfigSource::CommandLine);
+
+        let errors = [
+            ("spec.lang", "must be written as KEY=VALUE"),
+            (
+                "spec.lang=English",
+                "spec.lang: 'English' is not a language tag",
+            ),
+            (
+                "steering.backup.max=-1",
+                "'-1' is not a non-negative integer",
+            ),
+            ("agent.backend=copilot", "unknown agent backend 'copilot'"),
+            (
+                "steering.types.tech.criteria=Architecture",
+                "must be written as",
+            ),
+            (
+                "steering.types.tech.allowed_operations=rewrite",
+                "unknown operation",
+            ),
+            (
+                "steering.types.tech.include_in_prompt=no",
+                "is not true or false",
+            ),
+        ];
+        for (assignment, message) in errors {
+            let error = ConfigOverride::parse_assignment(assignment).unwrap_err();
+            assert!(error.contains(message), "{}: {}", assignment, error);
+        }
+    }
+
+    #[test]
+    fn test_values_accept_plain_and_toml_spelling() {
+        for value in ["pt-BR", "\"pt-BR\"", "'pt-BR'"] {
+            let config_override =
+                ConfigOverride::new("spec.lang", value, ConfigSource::CommandLine).unwrap();
+            assert_eq!(config_override.toml_value(), "\"pt-BR\"");
+        }
+
+        for value in ["refresh, discover", "[\"refresh\", \"discover\"]"] {
+            let config_override = ConfigOverride::new(
+                "steering.types.tech.allowed_operations",
+                value,
+                ConfigSource::CommandLine,
+            )
+            .unwrap();
+            assert_eq!(config_override.toml_value(), "[\"refresh\", \"discover\"]");
+        }
+    }
+
+    #[test]
+    fn test_toml_lists_keep_commas_inside_items() {
+        let value = r#"["Frontend: Frameworks, libraries", 'Backend: "Rust", Axum']"#;
+        let config_override = ConfigOverride::new(
+            "steering.types.tech.criteria",
+            value,
+            ConfigSource::CommandLine,
+        )
+        .unwrap();
+
+        let Setting::TypeCriteria(_, criteria) = &config_override.setting else {
+            panic!("expected criteria");
+        };
+        let criteria: Vec<String> = criteria.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            criteria,
+            vec![
+                "Frontend: Frameworks, libraries".to_string(),
+                "Backend: \"Rust\", Axum".to_string()
+            ]
+        );
+        // The rendered value parses back to the same setting
+        let rendered = ConfigOverride::new(
+            "steering.types.tech.criteria",
+            &config_override.toml_value(),
+            ConfigSource::CommandLine,
+        )
+        .unwrap();
+        assert_eq!(rendered.toml_value(), config_override.toml_value());
+
+        let error =
+            ConfigOverride::new("agent.args", "[\"a\", 1]", ConfigSource::CommandLine).unwrap_err();
+        assert!(
+            error.contains("is not a TOML array of strings"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_command_line_wins_over_environment() {
+        let overrides = ConfigOverrides::new(
+            env(&[("HAIL_MARY_SPEC__LANG", "en"), ("HOME", "/home/me")]),
+            &["spec.lang=fr".to_string()],
+        )
+        .unwrap();
+
+        let mut spec = SpecConfig::default();
+        overrides.apply_to_spec(&mut spec);
+        assert_eq!(spec.lang, "fr");
+        assert_eq!(overrides.iter().count(), 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_from_env_skips_non_utf8_variables() {
+        use std::os::unix::ffi::OsStringExt;
+
+        let overrides = ConfigOverrides::from_env(
+            vec![
+                (
+                    OsString::from("BADVAR"),
+                    OsString::from_vec(vec![0xff, 0xfe]),
+                ),
+                (OsString::from("HAIL_MARY_SPEC__LANG"), OsString::from("en")),
+            ],
+            &[],
+        )
+        .unwrap();
+
+        let keys: Vec<&str> = overrides.iter().map(|o| o.key.as_str()).collect();
+        assert_eq!(keys, vec!["spec.lang"]);
+    }
+
+    #[test]
+    fn test_steering_type_names_must_be_kebab_case() {
+        let error =
+            ConfigOverride::parse_assignment("steering.types.rust_dev.purpose=x").unwrap_err();
+        assert!(error.contains("must be kebab-case"), "{}", error);
+    }
+
+    #[test]
+    fn test_apply_to_steering_and_agent() {
+        let overrides = ConfigOverrides::new(
+            env(&[("HAIL_MARY_STEERING__BACKUP__MAX", "2")]),
+            &[
+                "steering.types.tech.include_in_prompt=false".to_string(),
+                "agent.backend=codex".to_string(),
+                "agent.args=--model, o3".to_string(),
+            ],
+        )
+        .unwrap();
+
+        let mut steering = SteeringConfig::default_for_new_project();
+        overrides.apply_to_steering(&mut steering).unwrap();
+        assert_eq!(steering.backup.max, 2);
+        let tech: &SteeringType = steering.types.iter().find(|t| t.name == "tech").unwrap();
+        assert!(!tech.include_in_prompt);
+
+        let mut agent = AgentConfig::default();
+        overrides.apply_to_agent(&mut agent);
+        assert_eq!(agent.backend, AgentBackend::Codex);
+        assert_eq!(agent.args, vec!["--model", "o3"]);
+
+        let unknown = ConfigOverrides::new(
+            Vec::new(),
+            &["steering.types.security.purpose=Policies".to_string()],
+        )
+        .unwrap();
+        let error = unknown.apply_to_steering(&mut steering).unwrap_err();
+        assert_eq!(
+            error,
+            "steering.types.security.purpose: no steering type 'security' is configured"
+        );
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt;
+
+/// Where a configuration value comes from, lowest precedence first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConfigSource {
+    /// Built-in default; the key is set nowhere
+    Default,
+    /// User-level config shared by all projects
+    User,
+    /// The project's `.kiro/config.toml`
+    Project,
+    /// A `HAIL_MARY_*` environment variable
+    Environment,
+    /// `--config key=value`
+    CommandLine,
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigSource::Default => f.write_str("default"),
+            ConfigSource::User => f.write_str("user"),
+            ConfigSource::Project => f.write_str("project"),
+            ConfigSource::Environment => f.write_str("env"),
+            ConfigSource::CommandLine => f.write_str("command line"),
+        }
+    }
+}
+
+/// One setting of the merged configuration and the layer it was taken from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectiveSetting {
+    /// Dotted key; steering types are addressed by name (`steering.types.tech.purpose`)
+    pub key: String,
+    /// The value as TOML
+    pub value: String,
+    pub source: ConfigSource,
+}
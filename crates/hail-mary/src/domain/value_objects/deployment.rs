@@ -0,0 +1,517 @@
+use crate::domain::value_objects::file_change::FileChange;
+use crate::domain::value_objects::steering_backup::content_hash;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A file hail-mary deployed into the project, with the hash of what it wrote
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployedFile {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    pub sha256: String,
+}
+
+/// Files hail-mary deployed (`.kiro/deployed-files.json`).
+///
+/// Only files listed here are ever overwritten or removed by a later deployment,
+/// and only while their content still matches the recorded hash.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentManifest {
+    pub files: Vec<DeployedFile>,
+    /// Versions of the skills whose files are deployed
+    #[serde(default)]
+    pub skills: Vec<DeployedSkill>,
+}
+
+/// The version of a skill as last deployed in full
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployedSkill {
+    pub name: String,
+    pub version: String,
+}
+
+/// A skill hail-mary wants in the project; its files are planned as [`DesiredFile`]s
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesiredSkill {
+    pub name: String,
+    pub version: String,
+    /// Skill directory relative to the project root
+    pub dir: PathBuf,
+}
+
+/// A deployed skill moving to the version hail-mary ships
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillUpgrade {
+    pub name: String,
+    pub from: String,
+    pub to: String,
+}
+
+impl fmt::Display for SkillUpgrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} -> {}", self.name, self.from, self.to)
+    }
+}
+
+/// A file hail-mary wants in the project
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DesiredFile {
+    /// Path relative to the project root
+    pub path: PathBuf,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeployAction {
+    Write { path: PathBuf, content: String },
+    Remove { path: PathBuf },
+}
+
+/// Deployed files that were left alone to protect local work
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeploymentWarning {
+    /// A deployed file was edited locally; the new version was not written
+    LocallyModified(PathBuf),
+    /// A file hail-mary would deploy already exists but was not installed by it
+    NotManaged(PathBuf),
+    /// A file hail-mary no longer ships was edited locally, so it was not removed
+    LeftInPlace(PathBuf),
+    /// A directory deployed by older versions, no longer managed or removed
+    Legacy(PathBuf),
+    /// A skill stays at its deployed version because some of its files were edited locally
+    SkillNotUpgraded {
+        name: String,
+        deployed: String,
+        available: String,
+    },
+}
+
+impl fmt::Display for DeploymentWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeploymentWarning::LocallyModified(path) => write!(
+                f,
+                "{} was modified locally; not updated (delete it to get the current version)",
+                path.display()
+            ),
+            DeploymentWarning::NotManaged(path) => write!(
+                f,
+                "{} was not installed by hail-mary; left as is",
+                path.display()
+            ),
+            DeploymentWarning::LeftInPlace(path) => write!(
+                f,
+                "{} is no longer shipped but was modified locally; left in place",
+                path.display()
+            ),
+            DeploymentWarning::Legacy(path) => write!(
+                f,
+                "{} is left over from an older hail-mary and no longer updated; delete it if unused",
+                path.display()
+            ),
+            DeploymentWarning::SkillNotUpgraded {
+                name,
+                deployed,
+                available,
+            } => write!(
+                f,
+                "skill {} stays at {} because its files were modified locally; {} is available",
+                name, deployed, available
+            ),
+        }
+    }
+}
+
+/// What a deployment changed (or, in a dry run, would change)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeploymentReport {
+    pub changes: Vec<FileChange>,
+    pub warnings: Vec<DeploymentWarning>,
+    pub upgrades: Vec<SkillUpgrade>,
+}
+
+/// What a deployment has to do, and the manifest to record afterwards
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeploymentPlan {
+    pub actions: Vec<DeployAction>,
+    pub warnings: Vec<DeploymentWarning>,
+    pub upgrades: Vec<SkillUpgrade>,
+    pub manifest: DeploymentManifest,
+}
+
+impl DeploymentPlan {
+    /// Record the version of each skill in the manifest, once its files are planned.
+    ///
+    /// A skill with files left alone to protect local edits keeps the version it
+    /// was deployed at, so the pending upgrade is reported until it is resolved.
+    /// A skill hail-mary never deployed whose directory already holds foreign
+    /// files belongs to the user, so none of its files are written or claimed.
+    pub fn stamp_skills(&mut self, deployed: &[DeployedSkill], skills: &[DesiredSkill]) {
+        for skill in skills {
+            let previous = deployed
+                .iter()
+                .find(|deployed| deployed.name == skill.name)
+                .map(|deployed| deployed.version.clone());
+            let foreign = self.warnings.iter().any(|warning| {
+                matches!(warning, DeploymentWarning::NotManaged(path) if path.starts_with(&skill.dir))
+            });
+            if foreign && previous.is_none() {
+                self.actions.retain(|action| match action {
+                    DeployAction::Write { path, .. } => !path.starts_with(&skill.dir),
+                    DeployAction::Remove { .. } => true,
+                });
+                self.manifest
+                    .files
+                    .retain(|file| !file.path.starts_with(&skill.dir));
+                continue;
+            }
+            let blocked = self.warnings.iter().any(|warning| match warning {
+                DeploymentWarning::LocallyModified(path) | DeploymentWarning::NotManaged(path) => {
+                    path.starts_with(&skill.dir)
+                }
+                _ => false,
+            });
+
+            let version = match (blocked, previous) {
+                // Not deployed by hail-mary yet; nothing to record
+                (true, None) => continue,
+                (true, Some(previous)) => {
+                    if previous != skill.version {
+                        self.warnings.push(DeploymentWarning::SkillNotUpgraded {
+                            name: skill.name.clone(),
+                            deployed: previous.clone(),
+                            available: skill.version.clone(),
+                        });
+                    }
+                    previous
+                }
+                (false, Some(previous)) => {
+                    if previous != skill.version {
+                        self.upgrades.push(SkillUpgrade {
+                            name: skill.name.clone(),
+                            from: previous,
+                            to: skill.version.clone(),
+                        });
+                    }
+                    skill.version.clone()
+                }
+                (false, None) => skill.version.clone(),
+            };
+
+            self.manifest.skills.push(DeployedSkill {
+                name: skill.name.clone(),
+                version,
+            });
+        }
+    }
+}
+
+impl DeploymentManifest {
+    fn recorded_hash(&self, path: &Path) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|file| file.path == path)
+            .map(|file| file.sha256.as_str())
+    }
+
+    /// Plan the deployment of `desired` over the files this manifest recorded.
+    ///
+    /// `current_hash` returns the hash of a file as it is on disk, or None when
+    /// it does not exist.
+    pub fn plan(
+        &self,
+        desired: &[DesiredFile],
+        current_hash: impl Fn(&Path) -> Option<String>,
+    ) -> DeploymentPlan {
+        let mut plan = DeploymentPlan::default();
+
+        for file in desired {
+            let hash = content_hash(file.content.as_bytes());
+            let write = DeployAction::Write {
+                path: file.path.clone(),
+                content: file.content.clone(),
+            };
+
+            let recorded = match current_hash(&file.path) {
+                None => {
+                    plan.actions.push(write);
+                    hash
+                }
+                Some(current) if current == hash => hash,
+                Some(current) => match self.recorded_hash(&file.path) {
+                    Some(recorded) if recorded == current => {
+                        plan.actions.push(write);
+                        hash
+                    }
+                    Some(recorded) => {
+                        plan.warnings
+                            .push(DeploymentWarning::LocallyModified(file.path.clone()));
+                        // Keep the old hash so the file stays flagged until resolved
+                        recorded.to_string()
+                    }
+                    None => {
+                        plan.warnings
+                            .push(DeploymentWarning::NotManaged(file.path.clone()));
+                        continue;
+                    }
+                },
+            };
+
+            plan.manifest.files.push(DeployedFile {
+                path: file.path.clone(),
+                sha256: recorded,
+            });
+        }
+
+        for stale in &self.files {
+            if desired.iter().any(|file| file.path == stale.path) {
+                continue;
+            }
+            match current_hash(&stale.path) {
+                None => {}
+                Some(current) if current == stale.sha256 => {
+                    plan.actions.push(DeployAction::Remove {
+                        path: stale.path.clone(),
+                    })
+                }
+                Some(_) => plan
+                    .warnings
+                    .push(DeploymentWarning::LeftInPlace(stale.path.clone())),
+            }
+        }
+
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn desired(path: &str, content: &str) -> DesiredFile {
+        DesiredFile {
+            path: PathBuf::from(path),
+            content: content.to_string(),
+        }
+    }
+
+    fn deployed(path: &str, content: &str) -> DeployedFile {
+        DeployedFile {
+            path: PathBuf::from(path),
+            sha256: content_hash(content.as_bytes()),
+        }
+    }
+
+    fn disk(files: &[(&str, &str)]) -> impl Fn(&Path) -> Option<String> {
+        let files: HashMap<PathBuf, String> = files
+            .iter()
+            .map(|(path, content)| (PathBuf::from(path), content_hash(content.as_bytes())))
+            .collect();
+        move |path| files.get(path).cloned()
+    }
+
+    fn write(path: &str, content: &str) -> DeployAction {
+        DeployAction::Write {
+            path: PathBuf::from(path),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_plan_writes_missing_and_updates_unmodified_files() {
+        let manifest = DeploymentManifest {
+            files: vec![deployed("hm/a.md", "a v1"), deployed("hm/b.md", "b v1")],
+            skills: vec![],
+        };
+
+        let plan = manifest.plan(
+            &[
+                desired("hm/a.md", "a v2"),
+                desired("hm/b.md", "b v1"),
+                desired("hm/c.md", "c"),
+            ],
+            disk(&[("hm/a.md", "a v1"), ("hm/b.md", "b v1")]),
+        );
+
+        assert_eq!(
+            plan.actions,
+            vec![write("hm/a.md", "a v2"), write("hm/c.md", "c")]
+        );
+        assert!(plan.warnings.is_empty());
+        assert_eq!(
+            plan.manifest.files,
+            vec![
+                deployed("hm/a.md", "a v2"),
+                deployed("hm/b.md", "b v1"),
+                deployed("hm/c.md", "c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_never_overwrites_local_or_foreign_files() {
+        let manifest = DeploymentManifest {
+            files: vec![deployed("hm/a.md", "a v1")],
+            skills: vec![],
+        };
+
+        let plan = manifest.plan(
+            &[desired("hm/a.md", "a v2"), desired("hm/b.md", "b")],
+            disk(&[("hm/a.md", "a edited"), ("hm/b.md", "mine")]),
+        );
+
+        assert!(plan.actions.is_empty());
+        assert_eq!(
+            plan.warnings,
+            vec![
+                DeploymentWarning::LocallyModified(PathBuf::from("hm/a.md")),
+                DeploymentWarning::NotManaged(PathBuf::from("hm/b.md")),
+            ]
+        );
+        // The edited file keeps its old hash; the foreign one is not adopted
+        assert_eq!(plan.manifest.files, vec![deployed("hm/a.md", "a v1")]);
+    }
+
+    #[test]
+    fn test_plan_removes_only_unmodified_stale_files() {
+        let manifest = DeploymentManifest {
+            files: vec![
+                deployed("hm/old.md", "old"),
+                deployed("hm/edited.md", "edited v1"),
+                deployed("hm/gone.md", "gone"),
+            ],
+            skills: vec![],
+        };
+
+        let plan = manifest.plan(
+            &[],
+            disk(&[("hm/old.md", "old"), ("hm/edited.md", "edited v2")]),
+        );
+
+        assert_eq!(
+            plan.actions,
+            vec![DeployAction::Remove {
+                path: PathBuf::from("hm/old.md")
+            }]
+        );
+        assert_eq!(
+            plan.warnings,
+            vec![DeploymentWarning::LeftInPlace(PathBuf::from(
+                "hm/edited.md"
+            ))]
+        );
+        assert!(plan.manifest.files.is_empty());
+    }
+
+    fn skill(name: &str, version: &str) -> DesiredSkill {
+        DesiredSkill {
+            name: name.to_string(),
+            version: version.to_string(),
+            dir: PathBuf::from("skills").join(name),
+        }
+    }
+
+    fn stamped(name: &str, version: &str) -> DeployedSkill {
+        DeployedSkill {
+            name: name.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_stamp_skills_records_versions_and_upgrades() {
+        let mut plan = DeploymentPlan::default();
+
+        plan.stamp_skills(
+            &[stamped("prd", "1.0.0")],
+            &[skill("prd", "1.1.0"), skill("review", "0.1.0")],
+        );
+
+        assert_eq!(
+            plan.upgrades,
+            vec![SkillUpgrade {
+                name: "prd".to_string(),
+                from: "1.0.0".to_string(),
+                to: "1.1.0".to_string(),
+            }]
+        );
+        assert!(plan.warnings.is_empty());
+        assert_eq!(
+            plan.manifest.skills,
+            vec![stamped("prd", "1.1.0"), stamped("review", "0.1.0")]
+        );
+    }
+
+    #[test]
+    fn test_stamp_skills_keeps_version_of_locally_edited_skill() {
+        let mut plan = DeploymentPlan {
+            warnings: vec![
+                DeploymentWarning::LocallyModified(PathBuf::from("skills/prd/SKILL.md")),
+                DeploymentWarning::NotManaged(PathBuf::from("skills/review/SKILL.md")),
+            ],
+            ..DeploymentPlan::default()
+        };
+
+        plan.stamp_skills(
+            &[stamped("prd", "1.0.0")],
+            &[skill("prd", "1.1.0"), skill("review", "0.1.0")],
+        );
+
+        assert!(plan.upgrades.is_empty());
+        assert_eq!(
+            plan.warnings[2],
+            DeploymentWarning::SkillNotUpgraded {
+                name: "prd".to_string(),
+                deployed: "1.0.0".to_string(),
+                available: "1.1.0".to_string(),
+            }
+        );
+        // A skill hail-mary never deployed is not claimed
+        assert_eq!(plan.manifest.skills, vec![stamped("prd", "1.0.0")]);
+    }
+
+    #[test]
+    fn test_plan_leaves_foreign_skill_directory_alone() {
+        let manifest = DeploymentManifest::default();
+        let skills = [skill("prd", "1.0.0"), skill("review", "0.1.0")];
+
+        let mut plan = manifest.plan(
+            &[
+                desired("skills/prd/SKILL.md", "prd"),
+                desired("skills/prd/references/template.md", "template"),
+                desired("skills/prd/references/shared.md", "shared"),
+                desired("skills/review/SKILL.md", "review"),
+            ],
+            disk(&[
+                ("skills/prd/SKILL.md", "my own prd skill"),
+                ("skills/prd/references/shared.md", "shared"),
+            ]),
+        );
+        plan.stamp_skills(&manifest.skills, &skills);
+
+        // Nothing is written into, or recorded from, the user's own skill
+        assert_eq!(
+            plan.actions,
+            vec![write("skills/review/SKILL.md", "review")]
+        );
+        assert_eq!(
+            plan.warnings,
+            vec![DeploymentWarning::NotManaged(PathBuf::from(
+                "skills/prd/SKILL.md"
+            ))]
+        );
+        assert_eq!(
+            plan.manifest.files,
+            vec![deployed("skills/review/SKILL.md", "review")]
+        );
+        assert_eq!(plan.manifest.skills, vec![stamped("review", "0.1.0")]);
+    }
+
+    #[test]
+    fn test_manifest_without_skills_still_parses() {
+        let manifest: DeploymentManifest = serde_json::from_str(r#"{"files": []}"#).unwrap();
+        assert!(manifest.skills.is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileChangeKind {
+    Create,
+    Modify,
+    Delete,
+}
+
+impl fmt::Display for FileChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FileChangeKind::Create => write!(f, "create"),
+            FileChangeKind::Modify => write!(f, "modify"),
+            FileChangeKind::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+/// A change made (or, in a dry run, planned) to a project file.
+///
+/// Paths are relative to the project root; directories end with `/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileChange {
+    pub path: PathBuf,
+    pub kind: FileChangeKind,
+}
+
+impl FileChange {
+    pub fn create(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            kind: FileChangeKind::Create,
+        }
+    }
+
+    pub fn modify(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            kind: FileChangeKind::Modify,
+        }
+    }
+
+    pub fn delete(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            kind: FileChangeKind::Delete,
+        }
+    }
+}
+
+impl fmt::Display for FileChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind, self.path.display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            FileChange::create(".kiro/config.toml").to_string(),
+            "create .kiro/config.toml"
+        );
+        assert_eq!(
+            FileChange::modify(".gitignore").to_string(),
+            "modify .gitignore"
+        );
+        assert_eq!(
+            FileChange::delete(".claude/agents/").to_string(),
+            "delete .claude/agents/"
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use serde_json::json;
+
+use crate::domain::value_objects::agent::{AgentBackend, AgentConfig};
+
+/// Environment variables set for every Claude Code session
+const CLAUDE_ENV: [(&str, &str); 5] = [
+    ("DISABLE_INTERLEAVED_THINKING", "1"),
+    ("DISABLE_MICROCOMPACT", "1"),
+    ("FORCE_AUTO_BACKGROUND_TASKS", "1"),
+    ("ENABLE_BACKGROUND_TASKS", "1"),
+    ("CLAUDE_BASH_MAINTAIN_PROJECT_WORKING_DIR", "1"),
+];
+
+/// Session flags chosen on the `code` command line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionFlags {
+    /// Keep permission prompts (omit the backend's skip-permissions flag)
+    pub no_danger: bool,
+    /// Continue the previous conversation
+    pub continue_conversation: bool,
+}
+
+/// A fully resolved command line for an agent CLI: program, argv and environment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LaunchCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    pub env: Vec<(String, String)>,
+}
+
+impl LaunchCommand {
+    /// Build the `claude` invocation for a session
+    pub fn claude(
+        system_prompt: Option<&str>,
+        plans_directory: Option<&str>,
+        flags: SessionFlags,
+    ) -> Self {
+        Self::for_agent(
+            &AgentConfig::default(),
+            system_prompt,
+            plans_directory,
+            flags,
+        )
+    }
+
+    /// Build the invocation for the configured agent backend.
+    ///
+    /// Only Claude Code can append to its system prompt and take a plans directory;
+    /// the other backends receive the prompt as the opening message of the session.
+    pub fn for_agent(
+        agent: &AgentConfig,
+        system_prompt: Option<&str>,
+        plans_directory: Option<&str>,
+        flags: SessionFlags,
+    ) -> Self {
+        let mut args: Vec<String> = Vec::new();
+        let mut env: Vec<(String, String)> = Vec::new();
+        // Positional arguments that must follow every flag
+        let mut positional: Vec<String> = Vec::new();
+
+        match agent.backend {
+            AgentBackend::Claude => {
+                let settings = match plans_directory {
+                    Some(dir) => json!({ "plansDirectory": dir }),
+                    None => json!({}),
+                };
+
+                if let Some(prompt) = system_prompt {
+                    push(&mut args, ["--append-system-prompt", prompt]);
+                }
+                push(
+                    &mut args,
+                    [
+                        "--permission-mode",
+                        "plan",
+                        "--settings",
+                        &settings.to_string(),
+                    ],
+                );
+                if !flags.no_danger {
+                    push(&mut args, ["--dangerously-skip-permissions"]);
+                }
+                if flags.continue_conversation {
+                    push(&mut args, ["--continue"]);
+                }
+
+                env.extend(
+                    CLAUDE_ENV
+                        .iter()
+                        .map(|(key, value)| (key.to_string(), value.to_string())),
+                );
+            }
+            AgentBackend::Opencode => {
+                // opencode allows every tool by default; ask instead when --no-danger is set
+                if flags.no_danger {
+                    let permission = json!({
+                        "permission": { "edit": "ask", "bash": "ask", "webfetch": "ask" }
+                    });
+                    env.push((
+                        "OPENCODE_CONFIG_CONTENT".to_string(),
+                        permission.to_string(),
+                    ));
+                }
+                if flags.continue_conversation {
+                    push(&mut args, ["--continue"]);
+                }
+                if let Some(prompt) = system_prompt {
+                    push(&mut args, ["--prompt", prompt]);
+                }
+            }
+            AgentBackend::Codex => {
+                if flags.continue_conversation {
+                    push(&mut args, ["resume", "--last"]);
+                }
+                if !flags.no_danger {
+                    push(&mut args, ["--dangerously-bypass-approvals-and-sandbox"]);
+                }
+                if let Some(prompt) = system_prompt {
+                    positional.push(prompt.to_string());
+                }
+            }
+            AgentBackend::Gemini => {
+                if !flags.no_danger {
+                    push(&mut args, ["--yolo"]);
+                }
+                if flags.continue_conversation {
+                    push(&mut args, ["--resume", "latest"]);
+                }
+                if let Some(prompt) = system_prompt {
+                    push(&mut args, ["--prompt-interactive", prompt]);
+                }
+            }
+        }
+
+        args.extend(agent.args.iter().cloned());
+        args.extend(positional);
+
+        Self {
+            program: agent.program().to_string(),
+            args,
+            env,
+        }
+    }
+
+    /// Value of a `--flag value` argument
+    pub fn arg_value(&self, flag: &str) -> Option<&str> {
+        self.args
+            .iter()
+            .position(|arg| arg == flag)
+            .and_then(|i| self.args.get(i + 1))
+            .map(String::as_str)
+    }
+
+    /// Parsed `--settings` JSON, if present
+    pub fn settings(&self) -> Option<serde_json::Value> {
+        self.arg_value("--settings")
+            .and_then(|settings| serde_json::from_str(settings).ok())
+    }
+}
+
+fn push<const N: usize>(args: &mut Vec<String>, values: [&str; N]) {
+    args.extend(values.iter().map(|value| value.to_string()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(backend: AgentBackend) -> AgentConfig {
+        AgentConfig {
+            backend,
+            ..AgentConfig::default()
+        }
+    }
+
+    const CONTINUE: SessionFlags = SessionFlags {
+        no_danger: false,
+        continue_conversation: true,
+    };
+
+    #[test]
+    fn test_claude_command_defaults() {
+        let command = LaunchCommand::claude(None, None, SessionFlags::default());
+
+        assert_eq!(command.program, "claude");
+        assert_eq!(
+            command.args,
+            vec![
+                "--permission-mode",
+                "plan",
+                "--settings",
+                "{}",
+                "--dangerously-skip-permissions",
+            ]
+        );
+        assert_eq!(command.env.len(), 5);
+        assert_eq!(command.settings(), Some(json!({})));
+    }
+
+    #[test]
+    fn test_claude_command_with_prompt_and_flags() {
+        let command = LaunchCommand::claude(
+            Some("prompt"),
+            Some(".kiro/specs/feature/plans"),
+            SessionFlags {
+                no_danger: true,
+                continue_conversation: true,
+            },
+        );
+
+        assert_eq!(command.arg_value("--append-system-prompt"), Some("prompt"));
+        assert_eq!(
+            command.settings(),
+            Some(json!({ "plansDirectory": ".kiro/specs/feature/plans" }))
+        );
+        assert!(
+            !command
+                .args
+                .contains(&"--dangerously-skip-permissions".to_string())
+        );
+        assert_eq!(command.args.last().map(String::as_str), Some("--continue"));
+    }
+
+    #[test]
+    fn test_settings_json_is_escaped() {
+        let command = LaunchCommand::claude(None, Some(r#"odd "dir""#), SessionFlags::default());
+        assert_eq!(
+            command.settings(),
+            Some(json!({ "plansDirectory": r#"odd "dir""# }))
+        );
+    }
+
+    #[test]
+    fn test_opencode_command() {
+        let command = LaunchCommand::for_agent(
+            &agent(AgentBackend::Opencode),
+            Some("prompt"),
+            Some("plans"),
+            CONTINUE,
+        );
+
+        assert_eq!(command.program, "opencode");
+        assert_eq!(command.args, vec!["--continue", "--prompt", "prompt"]);
+        assert!(command.env.is_empty());
+
+        let cautious = LaunchCommand::for_agent(
+            &agent(AgentBackend::Opencode),
+            None,
+            None,
+            SessionFlags {
+                no_danger: true,
+                continue_conversation: false,
+            },
+        );
+        assert!(cautious.args.is_empty());
+        assert_eq!(cautious.env[0].0, "OPENCODE_CONFIG_CONTENT");
+        assert!(cautious.env[0].1.contains(r#""bash":"ask""#));
+    }
+
+    #[test]
+    fn test_codex_command_puts_prompt_last() {
+        let config = AgentConfig {
+            backend: AgentBackend::Codex,
+            command: None,
+            args: vec!["--model".to_string(), "gpt-5".to_string()],
+        };
+
+        let command = LaunchCommand::for_agent(&config, Some("prompt"), None, CONTINUE);
+
+        assert_eq!(command.program, "codex");
+        assert_eq!(
+            command.args,
+            vec![
+                "resume",
+                "--last",
+                "--dangerously-bypass-approvals-and-sandbox",
+                "--model",
+                "gpt-5",
+                "prompt",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gemini_command() {
+        let command =
+            LaunchCommand::for_agent(&agent(AgentBackend::Gemini), Some("prompt"), None, CONTINUE);
+
+        assert_eq!(command.program, "gemini");
+        assert_eq!(
+            command.args,
+            vec![
+                "--yolo",
+                "--resume",
+                "latest",
+                "--prompt-interactive",
+                "prompt"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_command_override_and_extra_args() {
+        let config = AgentConfig {
+            backend: AgentBackend::Claude,
+            command: Some("/usr/local/bin/claude-beta".to_string()),
+            args: vec!["--verbose".to_string()],
+        };
+
+        let command = LaunchCommand::for_agent(&config, None, None, SessionFlags::default());
+
+        assert_eq!(command.program, "/usr/local/bin/claude-beta");
+        assert_eq!(command.args.last().map(String::as_str), Some("--verbose"));
+    }
+}
@@ -1,8 +1,31 @@
+pub mod agent;
+pub mod config_diagnostic;
+pub mod config_override;
+pub mod config_source;
+pub mod deployment;
+pub mod file_change;
+pub mod launch_command;
+pub mod slash_command;
 pub mod spec;
+pub mod spec_template;
 pub mod steering;
+pub mod steering_backup;
+pub mod steering_lint;
 pub mod system_prompt;
+pub mod tasks;
 
 // Re-export main types for convenience
-pub use spec::SpecValidator;
+pub use agent::{AgentBackend, AgentConfig};
+pub use config_diagnostic::{ConfigDiagnostic, ConfigFileDiagnostics};
+pub use config_override::{ConfigOverride, ConfigOverrides};
+pub use config_source::{ConfigSource, EffectiveSetting};
+pub use deployment::{DeploymentManifest, DeploymentReport, DeploymentWarning, SkillUpgrade};
+pub use file_change::{FileChange, FileChangeKind};
+pub use launch_command::{LaunchCommand, SessionFlags};
+pub use slash_command::{SlashCommand, SlashCommandSource};
+pub use spec::{FuzzyMatch, SpecMatcher, SpecValidator};
+pub use spec_template::{SpecTemplateSet, SpecTemplateVars};
 pub use steering::{Criterion, Steering, SteeringConfig, SteeringType, Steerings};
+pub use steering_backup::{BackupFileEntry, BackupManifest, BackupReason};
 pub use system_prompt::SystemPrompt;
+pub use tasks::{DocumentState, TaskLink, TaskProgress, TasksDocument, TimelineItem};
@@ -0,0 +1,127 @@
+use std::fmt;
+
+/// Where a deployed slash command comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlashCommandSource {
+    /// Compiled into hail-mary
+    Embedded,
+    /// A file in the project's `.kiro/commands/`
+    Overlay,
+}
+
+impl fmt::Display for SlashCommandSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SlashCommandSource::Embedded => write!(f, "embedded"),
+            SlashCommandSource::Overlay => write!(f, "overlay"),
+        }
+    }
+}
+
+/// A slash command file deployed into `.claude/commands/hm/`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlashCommand {
+    /// File name, e.g. `steering.md`
+    pub file_name: String,
+    pub content: String,
+    pub source: SlashCommandSource,
+    /// An overlay file that takes the place of the embedded command of the same name
+    pub replaces_embedded: bool,
+}
+
+impl SlashCommand {
+    /// Merge overlay files into the embedded commands: a file with the same
+    /// name replaces the embedded version, any other file is added
+    pub fn overlay(embedded: &[(&str, &str)], overlay: Vec<(String, String)>) -> Vec<SlashCommand> {
+        let mut commands: Vec<SlashCommand> = embedded
+            .iter()
+            .map(|(file_name, content)| SlashCommand {
+                file_name: file_name.to_string(),
+                content: content.to_string(),
+                source: SlashCommandSource::Embedded,
+                replaces_embedded: false,
+            })
+            .collect();
+
+        for (file_name, content) in overlay {
+            match commands
+                .iter_mut()
+                .find(|command| command.file_name == file_name)
+            {
+                Some(command) => {
+                    command.content = content;
+                    command.source = SlashCommandSource::Overlay;
+                    command.replaces_embedded = true;
+                }
+                None => commands.push(SlashCommand {
+                    file_name,
+                    content,
+                    source: SlashCommandSource::Overlay,
+                    replaces_embedded: false,
+                }),
+            }
+        }
+
+        commands.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+        commands
+    }
+
+    /// How the command is invoked in Claude Code, e.g. `/hm:steering`
+    pub fn invocation(&self) -> String {
+        let name = self
+            .file_name
+            .strip_suffix(".md")
+            .unwrap_or(&self.file_name);
+        format!("/hm:{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_replaces_same_name_and_adds_extra_files() {
+        let commands = SlashCommand::overlay(
+            &[
+                ("steering.md", "embedded"),
+                ("steering-remember.md", "remember"),
+            ],
+            vec![
+                ("steering.md".to_string(), "ours".to_string()),
+                ("release.md".to_string(), "release".to_string()),
+            ],
+        );
+
+        let summary: Vec<(&str, &str, SlashCommandSource, bool)> = commands
+            .iter()
+            .map(|command| {
+                (
+                    command.file_name.as_str(),
+                    command.content.as_str(),
+                    command.source,
+                    command.replaces_embedded,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("release.md", "release", SlashCommandSource::Overlay, false),
+                (
+                    "steering-remember.md",
+                    "remember",
+                    SlashCommandSource::Embedded,
+                    false
+                ),
+                ("steering.md", "ours", SlashCommandSource::Overlay, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invocation() {
+        let commands = SlashCommand::overlay(&[("steering-remember.md", "")], vec![]);
+        assert_eq!(commands[0].invocation(), "/hm:steering-remember");
+    }
+}
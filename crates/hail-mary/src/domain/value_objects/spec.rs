@@ -1,3 +1,9 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// The `YYYY-MM-DD-` prefix of spec directory names
+static DATE_PREFIX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}-").unwrap());
+
 /// Spec-related validation and utilities
 pub struct SpecValidator;
 
@@ -22,6 +28,98 @@ impl SpecValidator {
     }
 }
 
+/// Result of a fuzzy match: higher scores are better matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i64,
+    /// Char indices of the matched characters in the candidate
+    pub positions: Vec<usize>,
+}
+
+/// Matches user-supplied queries against spec directory names
+pub struct SpecMatcher;
+
+impl SpecMatcher {
+    /// Strip the `YYYY-MM-DD-` date prefix from a spec directory name
+    pub fn strip_date_prefix(name: &str) -> &str {
+        match DATE_PREFIX.find(name) {
+            Some(m) => &name[m.end()..],
+            None => name,
+        }
+    }
+
+    /// Find candidates matching `query`, trying progressively looser strategies:
+    /// exact name, prefix, substring and finally in-order characters (fuzzy).
+    /// Returns the matches of the first strategy that finds any.
+    pub fn find_matches(query: &str, candidates: &[String]) -> Vec<String> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let strategies: [fn(&str, &str) -> bool; 4] = [
+            |name, query| name == query,
+            |name, query| name.starts_with(query),
+            |name, query| name.contains(query),
+            Self::is_subsequence,
+        ];
+
+        for matches_query in strategies {
+            let matches: Vec<String> = candidates
+                .iter()
+                .filter(|candidate| {
+                    let full = candidate.to_lowercase();
+                    let short = Self::strip_date_prefix(&full);
+                    matches_query(&full, &query) || matches_query(short, &query)
+                })
+                .cloned()
+                .collect();
+
+            if !matches.is_empty() {
+                return matches;
+            }
+        }
+
+        Vec::new()
+    }
+
+    /// Case-insensitive in-order character match used for interactive filtering.
+    /// Consecutive characters and characters at word starts (after `-`, `_` or `/`)
+    /// score higher; gaps between matched characters score lower.
+    pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+        let query: Vec<char> = query.trim().to_lowercase().chars().collect();
+        let chars: Vec<char> = candidate.to_lowercase().chars().collect();
+
+        let mut positions = Vec::with_capacity(query.len());
+        let mut score = 0;
+        let mut next = 0;
+
+        for q in query {
+            let index = (next..chars.len()).find(|&i| chars[i] == q)?;
+
+            score += 16;
+            if index == 0 || matches!(chars[index - 1], '-' | '_' | '/') {
+                score += 8;
+            }
+            match positions.last() {
+                Some(&last) if index == last + 1 => score += 12,
+                Some(&last) => score -= (index - last - 1) as i64,
+                None => score -= index as i64 / 4,
+            }
+
+            positions.push(index);
+            next = index + 1;
+        }
+
+        Some(FuzzyMatch { score, positions })
+    }
+
+    fn is_subsequence(name: &str, query: &str) -> bool {
+        let mut chars = name.chars();
+        query.chars().all(|q| chars.any(|c| c == q))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +172,97 @@ mod tests {
             Err(DomainError::InvalidSpecName(_))
         ));
     }
+
+    fn candidates() -> Vec<String> {
+        vec![
+            "2025-10-14-brainstorming".to_string(),
+            "2025-10-11-embedded-subagent".to_string(),
+            "2025-10-11-embedded-skill".to_string(),
+            "2025-10-01-login".to_string(),
+            "2025-10-02-login-v2".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_strip_date_prefix() {
+        assert_eq!(
+            SpecMatcher::strip_date_prefix("2025-10-14-brainstorming"),
+            "brainstorming"
+        );
+        assert_eq!(SpecMatcher::strip_date_prefix("sbi-1-login"), "sbi-1-login");
+        assert_eq!(SpecMatcher::strip_date_prefix("2025-10-14"), "2025-10-14");
+    }
+
+    #[test]
+    fn test_find_matches_exact_name_wins_over_prefix() {
+        let matches = SpecMatcher::find_matches("login", &candidates());
+        assert_eq!(matches, vec!["2025-10-01-login"]);
+
+        let matches = SpecMatcher::find_matches("2025-10-02-login-v2", &candidates());
+        assert_eq!(matches, vec!["2025-10-02-login-v2"]);
+    }
+
+    #[test]
+    fn test_find_matches_prefix() {
+        let matches = SpecMatcher::find_matches("brain", &candidates());
+        assert_eq!(matches, vec!["2025-10-14-brainstorming"]);
+
+        let matches = SpecMatcher::find_matches("embedded", &candidates());
+        assert_eq!(
+            matches,
+            vec!["2025-10-11-embedded-subagent", "2025-10-11-embedded-skill"]
+        );
+    }
+
+    #[test]
+    fn test_find_matches_substring_and_fuzzy() {
+        let matches = SpecMatcher::find_matches("subagent", &candidates());
+        assert_eq!(matches, vec!["2025-10-11-embedded-subagent"]);
+
+        let matches = SpecMatcher::find_matches("bstrm", &candidates());
+        assert_eq!(matches, vec!["2025-10-14-brainstorming"]);
+    }
+
+    #[test]
+    fn test_find_matches_is_case_insensitive() {
+        let matches = SpecMatcher::find_matches("BRAIN", &candidates());
+        assert_eq!(matches, vec!["2025-10-14-brainstorming"]);
+    }
+
+    #[test]
+    fn test_find_matches_none() {
+        assert!(SpecMatcher::find_matches("zzz", &candidates()).is_empty());
+        assert!(SpecMatcher::find_matches("  ", &candidates()).is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_match_positions() {
+        let m = SpecMatcher::fuzzy_match("lgn", "2025-01-01-login").unwrap();
+        assert_eq!(m.positions, vec![11, 13, 15]);
+
+        assert!(SpecMatcher::fuzzy_match("xyz", "2025-01-01-login").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_match_empty_query_matches_everything() {
+        let m = SpecMatcher::fuzzy_match("", "anything").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.positions.is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_match_prefers_consecutive_and_word_starts() {
+        let consecutive = SpecMatcher::fuzzy_match("api", "sbi-1-api").unwrap();
+        let scattered = SpecMatcher::fuzzy_match("api", "sbi-1-add-parser-io").unwrap();
+        assert!(consecutive.score > scattered.score);
+
+        let word_start = SpecMatcher::fuzzy_match("p", "2025-01-01-pbi").unwrap();
+        let inner = SpecMatcher::fuzzy_match("p", "2025-01-01-spec").unwrap();
+        assert!(word_start.score > inner.score);
+    }
+
+    #[test]
+    fn test_fuzzy_match_is_case_insensitive() {
+        assert!(SpecMatcher::fuzzy_match("API", "sbi-1-api").is_some());
+    }
 }
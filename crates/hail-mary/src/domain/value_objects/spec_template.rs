@@ -0,0 +1,108 @@
+const TASKS_TEMPLATE: &str = r#"# Tasks
+
+**Language**: {lang}
+
+## State Tracking
+
+| Document | Status | Coverage | Next Action |
+|----------|--------|----------|-------------|
+| requirements.md | pending | - | Define requirements |
+| tasks.md#Timeline | pending | 0% | Plan implementation order |
+
+## Timeline
+
+- [x] Spec created → {name}
+- [ ] Requirements definition
+- [ ] Implementation planning
+"#;
+
+const MEMO_TEMPLATE: &str = r#"# Memo: {name}
+
+"#;
+
+/// Which documents a new spec directory is seeded with.
+///
+/// Projects can replace or extend the built-in documents by placing files in
+/// `.kiro/templates/<set>/`; every file there becomes a document of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecTemplateSet {
+    /// Top-level specs and PBIs (`.kiro/templates/spec/`)
+    Spec,
+    /// SBIs inside a PBI (`.kiro/templates/sbi/`)
+    Sbi,
+}
+
+impl SpecTemplateSet {
+    /// Directory name under `.kiro/templates/`
+    pub fn dir_name(&self) -> &'static str {
+        match self {
+            SpecTemplateSet::Spec => "spec",
+            SpecTemplateSet::Sbi => "sbi",
+        }
+    }
+
+    /// Documents created when the project provides no template of the same name
+    pub fn builtin(&self) -> &'static [(&'static str, &'static str)] {
+        &[("tasks.md", TASKS_TEMPLATE), ("memo.md", MEMO_TEMPLATE)]
+    }
+}
+
+/// Values substituted into templates as `{name}`, `{date}`, `{lang}` and `{parent}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecTemplateVars {
+    /// Spec or SBI name without date prefix
+    pub name: String,
+    /// Creation date, `YYYY-MM-DD`
+    pub date: String,
+    pub lang: String,
+    /// Parent PBI directory for SBIs, empty for top-level specs
+    pub parent: String,
+}
+
+impl SpecTemplateVars {
+    pub fn render(&self, template: &str) -> String {
+        template
+            .replace("{name}", &self.name)
+            .replace("{date}", &self.date)
+            .replace("{lang}", &self.lang)
+            .replace("{parent}", &self.parent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> SpecTemplateVars {
+        SpecTemplateVars {
+            name: "sbi-1-login".to_string(),
+            date: "2025-01-02".to_string(),
+            lang: "en".to_string(),
+            parent: "2025-01-01-auth".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_substitutes_all_variables() {
+        let rendered = vars().render("{name} of {parent} ({lang}, {date}) {unknown}");
+        assert_eq!(
+            rendered,
+            "sbi-1-login of 2025-01-01-auth (en, 2025-01-02) {unknown}"
+        );
+    }
+
+    #[test]
+    fn test_builtin_templates() {
+        let builtin = SpecTemplateSet::Spec.builtin();
+        let names: Vec<&str> = builtin.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["tasks.md", "memo.md"]);
+
+        let tasks = vars().render(builtin[0].1);
+        assert!(tasks.contains("**Language**: en"));
+        assert!(tasks.contains("- [x] Spec created → sbi-1-login"));
+        assert_eq!(vars().render(builtin[1].1), "# Memo: sbi-1-login\n\n");
+
+        assert_eq!(SpecTemplateSet::Spec.dir_name(), "spec");
+        assert_eq!(SpecTemplateSet::Sbi.dir_name(), "sbi");
+    }
+}
@@ -1,5 +1,15 @@
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::LazyLock;
+
+/// Steering type names: kebab-case
+static TYPE_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z0-9]+(-[a-z0-9]+)*$").unwrap());
+
+/// Language tags such as `ja`, `en` or `pt-BR`
+static LANGUAGE_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z]{2,3}(-[A-Za-z0-9]{2,8})*$").unwrap());
 
 /// Combines a SteeringType with its file content
 #[derive(Debug, Clone, PartialEq)]
@@ -59,12 +69,48 @@ pub struct SteeringType {
     pub criteria: Vec<Criterion>,
     #[serde(default = "default_allowed_operations")]
     pub allowed_operations: Vec<String>,
+    #[serde(default = "default_include_in_prompt")]
+    pub include_in_prompt: bool,
+}
+
+impl SteeringType {
+    /// Steering type names become file names (`<name>.md`), so they must be kebab-case
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        if TYPE_NAME.is_match(name) {
+            Ok(())
+        } else {
+            Err(format!(
+                "'{}' must be kebab-case (lowercase letters, digits and hyphens)",
+                name
+            ))
+        }
+    }
+
+    /// Initial content of a new steering file: the purpose as lead paragraph,
+    /// then one empty section per criterion
+    pub fn skeleton(&self) -> String {
+        let mut content = format!("# {}\n\n", self.name);
+        if !self.purpose.is_empty() {
+            content.push_str(&format!("{}\n\n", self.purpose));
+        }
+        for criterion in &self.criteria {
+            content.push_str(&format!("## {}\n\n", criterion.name));
+        }
+        content
+    }
 }
 
+/// Automatic update operations a steering type can permit in `allowed_operations`
+pub const STEERING_OPERATIONS: &[&str] = &["refresh", "discover"];
+
 fn default_allowed_operations() -> Vec<String> {
     vec![] // Default to manual-only for safety
 }
 
+pub(crate) fn default_include_in_prompt() -> bool {
+    true
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SteeringBackupConfig {
     #[serde(default = "default_backup_max")]
@@ -87,6 +133,20 @@ pub struct SpecConfig {
     pub lang: String,
 }
 
+impl SpecConfig {
+    /// `lang` is a language tag such as `ja`, `en` or `pt-BR`
+    pub fn validate_lang(lang: &str) -> Result<(), String> {
+        if LANGUAGE_TAG.is_match(lang) {
+            Ok(())
+        } else {
+            Err(format!(
+                "'{}' is not a language tag (e.g. \"ja\", \"en\", \"pt-BR\")",
+                lang
+            ))
+        }
+    }
+}
+
 impl Default for SpecConfig {
     fn default() -> Self {
         Self {
@@ -161,6 +221,7 @@ impl SteeringConfig {
                         },
                     ],
                     allowed_operations: vec!["refresh".to_string(), "discover".to_string()],
+                    include_in_prompt: true,
                 },
                 SteeringType {
                     name: "tech".to_string(),
@@ -198,6 +259,7 @@ impl SteeringConfig {
                         },
                     ],
                     allowed_operations: vec!["refresh".to_string(), "discover".to_string()],
+                    include_in_prompt: true,
                 },
                 SteeringType {
                     name: "structure".to_string(),
@@ -229,6 +291,7 @@ impl SteeringConfig {
                         },
                     ],
                     allowed_operations: vec!["refresh".to_string(), "discover".to_string()],
+                    include_in_prompt: true,
                 },
             ],
         }
@@ -255,14 +318,53 @@ impl SteeringConfig {
             purpose: purpose.to_string(),
             criteria,
             allowed_operations: vec![], // New types default to manual-only
+            include_in_prompt: true,
         })
     }
+
+    /// Return a copy containing only the types that are injected into the system prompt
+    pub fn prompt_types(&self) -> SteeringConfig {
+        Self {
+            types: self
+                .types
+                .iter()
+                .filter(|t| t.include_in_prompt)
+                .cloned()
+                .collect(),
+            backup: self.backup.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_steering_type_skeleton() {
+        let steering_type = SteeringType {
+            name: "rust-dev".to_string(),
+            purpose: "Rust conventions".to_string(),
+            criteria: vec![
+                Criterion {
+                    name: "Error Handling".to_string(),
+                    description: "thiserror vs anyhow".to_string(),
+                },
+                Criterion {
+                    name: "Testing".to_string(),
+                    description: "Where tests live".to_string(),
+                },
+            ],
+            allowed_operations: vec![],
+            include_in_prompt: true,
+        };
+
+        assert_eq!(
+            steering_type.skeleton(),
+            "# rust-dev\n\nRust conventions\n\n## Error Handling\n\n## Testing\n\n"
+        );
+    }
+
     #[test]
     fn test_criterion_parse_from_string_valid() {
         let criterion = Criterion::parse_from_string(
@@ -354,6 +456,24 @@ mod tests {
         assert_eq!(structure.allowed_operations, vec!["refresh", "discover"]);
     }
 
+    #[test]
+    fn test_validate_steering_type_name() {
+        assert!(SteeringType::validate_name("prompt-engineering").is_ok());
+        assert!(SteeringType::validate_name("").is_err());
+        assert!(SteeringType::validate_name("../tech").is_err());
+        assert!(SteeringType::validate_name("Tech").is_err());
+    }
+
+    #[test]
+    fn test_validate_spec_lang() {
+        for lang in ["ja", "en", "pt-BR", "zh-Hant"] {
+            assert!(SpecConfig::validate_lang(lang).is_ok(), "{}", lang);
+        }
+        for lang in ["", "JA", "japanese", "en_US", "e"] {
+            assert!(SpecConfig::validate_lang(lang).is_err(), "{}", lang);
+        }
+    }
+
     #[test]
     fn test_from_criterion_strings_valid() {
         let criterion_strings = vec![
@@ -402,6 +522,7 @@ mod tests {
                 description: "test description".to_string(),
             }],
             allowed_operations: vec!["refresh".to_string()],
+            include_in_prompt: true,
         };
 
         let cloned = steering_type.clone();
@@ -427,6 +548,36 @@ mod tests {
         assert!(debug_str.contains("test"));
     }
 
+    #[test]
+    fn test_prompt_types_excludes_disabled_types() {
+        let mut config = SteeringConfig::default_for_new_project();
+        config.types[1].include_in_prompt = false;
+
+        let prompt_config = config.prompt_types();
+
+        let names: Vec<&str> = prompt_config
+            .types
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["product", "structure"]);
+        assert_eq!(prompt_config.backup, config.backup);
+    }
+
+    #[test]
+    fn test_steering_type_include_in_prompt_defaults_to_true() {
+        let steering_type: SteeringType = toml::from_str(
+            r#"
+name = "custom"
+purpose = "Custom purpose"
+criteria = []
+"#,
+        )
+        .unwrap();
+
+        assert!(steering_type.include_in_prompt);
+    }
+
     #[test]
     fn test_steering_config_clone_and_debug() {
         let config = SteeringConfig {
@@ -436,6 +587,7 @@ mod tests {
                 purpose: "test purpose".to_string(),
                 criteria: vec![],
                 allowed_operations: vec![],
+                include_in_prompt: true,
             }],
         };
 
@@ -496,6 +648,7 @@ mod tests {
                     },
                 ],
                 allowed_operations: vec![],
+                include_in_prompt: true,
             },
             content: "This is the product content".to_string(),
         };
@@ -525,6 +678,7 @@ mod tests {
                     description: "System design".to_string(),
                 }],
                 allowed_operations: vec![],
+                include_in_prompt: true,
             },
             content: "Tech stack details".to_string(),
         };
@@ -550,6 +704,7 @@ mod tests {
                     description: "Brief description".to_string(),
                 }],
                 allowed_operations: vec![],
+                include_in_prompt: true,
             },
             content: "Product content".to_string(),
         };
@@ -563,6 +718,7 @@ mod tests {
                     description: "System design".to_string(),
                 }],
                 allowed_operations: vec![],
+                include_in_prompt: true,
             },
             content: "Tech content".to_string(),
         };
@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::PathBuf;
+
+/// Why a steering backup was created
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BackupReason {
+    /// `hail-mary steering backup`
+    #[default]
+    Manual,
+    /// Automatic backup of the current state before `steering restore`
+    BeforeRestore,
+    /// Automatic backup before `steering type remove` deletes a file
+    BeforeTypeRemoval,
+}
+
+impl fmt::Display for BackupReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackupReason::Manual => f.write_str("manual"),
+            BackupReason::BeforeRestore => f.write_str("before-restore"),
+            BackupReason::BeforeTypeRemoval => f.write_str("before-type-removal"),
+        }
+    }
+}
+
+/// A file recorded in a backup manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupFileEntry {
+    pub path: PathBuf,
+    pub size: u64,
+    pub sha256: String,
+}
+
+impl BackupFileEntry {
+    pub fn from_content(path: PathBuf, content: &[u8]) -> Self {
+        Self {
+            path,
+            size: content.len() as u64,
+            sha256: content_hash(content),
+        }
+    }
+}
+
+/// `manifest.json` stored with each steering backup
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub name: String,
+    /// RFC 3339 creation time
+    pub created_at: String,
+    pub reason: BackupReason,
+    pub files: Vec<BackupFileEntry>,
+}
+
+/// True if both file sets hold exactly the same paths and contents, in any order
+pub fn same_content(a: &[BackupFileEntry], b: &[BackupFileEntry]) -> bool {
+    fingerprint(a) == fingerprint(b)
+}
+
+/// Hex-encoded SHA-256 of `content`
+pub fn content_hash(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+fn fingerprint(files: &[BackupFileEntry]) -> Vec<(&PathBuf, &str)> {
+    let mut entries: Vec<_> = files
+        .iter()
+        .map(|file| (&file.path, file.sha256.as_str()))
+        .collect();
+    entries.sort();
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(files: Vec<BackupFileEntry>) -> BackupManifest {
+        BackupManifest {
+            name: "2025-01-01-10-00-00".to_string(),
+            created_at: "2025-01-01T10:00:00+09:00".to_string(),
+            reason: BackupReason::Manual,
+            files,
+        }
+    }
+
+    #[test]
+    fn test_content_hash() {
+        assert_eq!(
+            content_hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_same_content_ignores_order() {
+        let product = BackupFileEntry::from_content(PathBuf::from("product.md"), b"# Product");
+        let tech = BackupFileEntry::from_content(PathBuf::from("tech.md"), b"# Tech");
+        let backup = [product.clone(), tech.clone()];
+
+        assert!(same_content(&backup, &[tech.clone(), product.clone()]));
+        assert!(!same_content(&backup, std::slice::from_ref(&product)));
+
+        let edited = BackupFileEntry::from_content(PathBuf::from("tech.md"), b"# Tech v2");
+        assert!(!same_content(&backup, &[product, edited]));
+    }
+
+    #[test]
+    fn test_manifest_json_format() {
+        let backup = manifest(vec![BackupFileEntry::from_content(
+            PathBuf::from("tech.md"),
+            b"abc",
+        )]);
+
+        let json = serde_json::to_value(&backup).unwrap();
+        assert_eq!(json["reason"], "manual");
+        assert_eq!(json["files"][0]["path"], "tech.md");
+        assert_eq!(json["files"][0]["size"], 3);
+
+        let restored: BackupManifest = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, backup);
+        assert_eq!(BackupReason::BeforeRestore.to_string(), "before-restore");
+    }
+}
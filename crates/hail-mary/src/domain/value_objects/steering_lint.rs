@@ -0,0 +1,198 @@
+use crate::domain::value_objects::steering::SteeringType;
+use std::fmt;
+
+/// A problem found in a steering file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SteeringLintIssue {
+    /// A configured criterion has no `##` section
+    MissingSection(String),
+    /// A `##` section that matches no configured criterion
+    UnknownSection(String),
+    /// A `##` section without any content
+    EmptySection(String),
+    /// A configured steering type has no file
+    MissingFile,
+    /// A steering file without a `[[steering.types]]` entry
+    StaleFile,
+}
+
+impl fmt::Display for SteeringLintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SteeringLintIssue::MissingSection(name) => {
+                write!(f, "missing section '## {}'", name)
+            }
+            SteeringLintIssue::UnknownSection(name) => {
+                write!(f, "section '## {}' matches no configured criterion", name)
+            }
+            SteeringLintIssue::EmptySection(name) => write!(f, "section '## {}' is empty", name),
+            SteeringLintIssue::MissingFile => f.write_str("file is missing"),
+            SteeringLintIssue::StaleFile => {
+                f.write_str("no [[steering.types]] entry in config.toml")
+            }
+        }
+    }
+}
+
+/// A `##` section of a steering markdown file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SteeringSection {
+    pub heading: String,
+    /// Whether the section has text other than subheadings
+    pub has_content: bool,
+}
+
+/// Splits markdown into its `##` sections, ignoring headings inside fenced code blocks
+pub fn parse_sections(content: &str) -> Vec<SteeringSection> {
+    let mut sections: Vec<SteeringSection> = Vec::new();
+    let mut in_code_block = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            if let Some(section) = sections.last_mut() {
+                section.has_content = true;
+            }
+            continue;
+        }
+
+        if !in_code_block && let Some(heading) = trimmed.strip_prefix("## ") {
+            sections.push(SteeringSection {
+                heading: heading.trim().to_string(),
+                has_content: false,
+            });
+            continue;
+        }
+
+        let is_heading = !in_code_block && trimmed.starts_with('#');
+        if !trimmed.is_empty()
+            && !is_heading
+            && let Some(section) = sections.last_mut()
+        {
+            section.has_content = true;
+        }
+    }
+
+    sections
+}
+
+/// Checks a steering file against the criteria of its type
+pub fn lint_steering_content(
+    steering_type: &SteeringType,
+    content: &str,
+) -> Vec<SteeringLintIssue> {
+    let sections = parse_sections(content);
+    let mut issues = Vec::new();
+
+    for criterion in &steering_type.criteria {
+        if !sections
+            .iter()
+            .any(|section| same_heading(&section.heading, &criterion.name))
+        {
+            issues.push(SteeringLintIssue::MissingSection(criterion.name.clone()));
+        }
+    }
+
+    for section in &sections {
+        let known = steering_type
+            .criteria
+            .iter()
+            .any(|criterion| same_heading(&section.heading, &criterion.name));
+        if !known {
+            issues.push(SteeringLintIssue::UnknownSection(section.heading.clone()));
+        } else if !section.has_content {
+            issues.push(SteeringLintIssue::EmptySection(section.heading.clone()));
+        }
+    }
+
+    issues
+}
+
+/// Case- and whitespace-insensitive heading comparison
+fn same_heading(heading: &str, criterion: &str) -> bool {
+    let normalize = |s: &str| {
+        s.split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+    };
+    normalize(heading) == normalize(criterion)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::steering::Criterion;
+
+    fn tech_type() -> SteeringType {
+        SteeringType {
+            name: "tech".to_string(),
+            purpose: "Technical stack".to_string(),
+            criteria: ["Architecture", "Common Commands"]
+                .iter()
+                .map(|name| Criterion {
+                    name: name.to_string(),
+                    description: String::new(),
+                })
+                .collect(),
+            allowed_operations: vec![],
+            include_in_prompt: true,
+        }
+    }
+
+    #[test]
+    fn test_parse_sections_ignores_code_blocks() {
+        let sections = parse_sections(
+            "# Tech\n\n## Architecture\n\n### Layers\n\n## Common Commands\n\n```bash\n## not a heading\n```\n",
+        );
+
+        assert_eq!(
+            sections,
+            vec![
+                SteeringSection {
+                    heading: "Architecture".to_string(),
+                    has_content: false,
+                },
+                SteeringSection {
+                    heading: "Common Commands".to_string(),
+                    has_content: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_clean_file() {
+        let content =
+            "# Tech\n\n## architecture\n\nLayered.\n\n## Common  Commands\n\n- cargo test\n";
+        assert!(lint_steering_content(&tech_type(), content).is_empty());
+    }
+
+    #[test]
+    fn test_lint_reports_missing_unknown_and_empty_sections() {
+        let content = "# Tech\n\n## Architecture\n\n## Deployment\n\nK8s\n";
+
+        assert_eq!(
+            lint_steering_content(&tech_type(), content),
+            vec![
+                SteeringLintIssue::MissingSection("Common Commands".to_string()),
+                SteeringLintIssue::EmptySection("Architecture".to_string()),
+                SteeringLintIssue::UnknownSection("Deployment".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_issue_messages() {
+        assert_eq!(
+            SteeringLintIssue::MissingSection("Architecture".to_string()).to_string(),
+            "missing section '## Architecture'"
+        );
+        assert_eq!(
+            SteeringLintIssue::StaleFile.to_string(),
+            "no [[steering.types]] entry in config.toml"
+        );
+    }
+}
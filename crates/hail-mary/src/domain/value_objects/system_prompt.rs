@@ -1,3 +1,4 @@
+use crate::domain::value_objects::steering::Steerings;
 use std::path::Path;
 
 const BASE_TEMPLATE: &str = include_str!("system_prompt_template.md");
@@ -16,14 +17,20 @@ These files track the current feature's lifecycle:
 
 "#;
 
+const STEERING_TEMPLATE: &str = r#"# Steering
+
+Project knowledge maintained in `.kiro/steering/`. Treat it as the authoritative context for this repository.
+
+{steerings}"#;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SystemPrompt {
     content: String,
 }
 
 impl SystemPrompt {
-    /// Create a new SystemPrompt with spec context
-    pub fn new(spec_name: &str, spec_path: &Path) -> Self {
+    /// Create a new SystemPrompt with spec context and steering sections
+    pub fn new(spec_name: &str, spec_path: &Path, steerings: &Steerings) -> Self {
         let path_str = spec_path.display().to_string();
 
         let prd_path = format!("{}/prd.md", path_str);
@@ -41,18 +48,31 @@ impl SystemPrompt {
             .replace("{memo_path}", &memo_path)
             .replace("{plans_path}", &plans_path);
 
-        let content = format!("{}{}", spec_section, BASE_TEMPLATE);
+        let content = format!(
+            "{}{}{}",
+            spec_section,
+            Self::steering_section(steerings),
+            BASE_TEMPLATE
+        );
 
         Self { content }
     }
 
-    /// Create a new SystemPrompt without spec context (base template only)
-    pub fn base() -> Self {
+    /// Create a new SystemPrompt without spec context (steering sections and base template)
+    pub fn base(steerings: &Steerings) -> Self {
         Self {
-            content: BASE_TEMPLATE.to_string(),
+            content: format!("{}{}", Self::steering_section(steerings), BASE_TEMPLATE),
         }
     }
 
+    fn steering_section(steerings: &Steerings) -> String {
+        if steerings.0.is_empty() {
+            return String::new();
+        }
+
+        STEERING_TEMPLATE.replace("{steerings}", &steerings.to_string())
+    }
+
     pub fn as_str(&self) -> &str {
         &self.content
     }
@@ -61,14 +81,28 @@ impl SystemPrompt {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::value_objects::steering::{Steering, SteeringType};
     use std::path::PathBuf;
 
+    fn steering(name: &str, content: &str) -> Steering {
+        Steering {
+            steering_type: SteeringType {
+                name: name.to_string(),
+                purpose: format!("{} purpose", name),
+                criteria: vec![],
+                allowed_operations: vec![],
+                include_in_prompt: true,
+            },
+            content: content.to_string(),
+        }
+    }
+
     #[test]
     fn test_system_prompt_with_spec() {
         let spec_name = "test-feature";
         let spec_path = PathBuf::from(".kiro/specs/2025-01-01-test-feature");
 
-        let prompt = SystemPrompt::new(spec_name, &spec_path);
+        let prompt = SystemPrompt::new(spec_name, &spec_path, &Steerings(vec![]));
         let content = prompt.as_str();
 
         assert!(content.contains("# Spec Files"));
@@ -87,12 +121,48 @@ mod tests {
 
     #[test]
     fn test_system_prompt_base_only() {
-        let prompt = SystemPrompt::base();
+        let prompt = SystemPrompt::base(&Steerings(vec![]));
         let content = prompt.as_str();
 
         assert!(!content.contains("# Spec Files"));
         assert!(!content.contains("<tasks-file>"));
+        assert!(!content.contains("# Steering"));
         assert!(content.contains("# Role"));
         assert!(content.contains("# Core Behaviors"));
     }
+
+    #[test]
+    fn test_system_prompt_with_steerings() {
+        let spec_path = PathBuf::from(".kiro/specs/2025-01-01-test-feature");
+        let steerings = Steerings(vec![
+            steering("product", "Product content"),
+            steering("tech", "Tech content"),
+        ]);
+
+        let prompt = SystemPrompt::new("test-feature", &spec_path, &steerings);
+        let content = prompt.as_str();
+
+        assert!(content.contains("# Steering"));
+        assert!(content.contains("<steering-product>\nProduct content\n</steering-product>"));
+        assert!(content.contains("<steering-tech>\nTech content\n</steering-tech>"));
+
+        // Steering sits between the spec section and the base template
+        let spec_pos = content.find("# Spec Files").unwrap();
+        let steering_pos = content.find("# Steering").unwrap();
+        let role_pos = content.find("# Role").unwrap();
+        assert!(spec_pos < steering_pos);
+        assert!(steering_pos < role_pos);
+    }
+
+    #[test]
+    fn test_system_prompt_base_with_steerings() {
+        let steerings = Steerings(vec![steering("structure", "Structure content")]);
+
+        let prompt = SystemPrompt::base(&steerings);
+        let content = prompt.as_str();
+
+        assert!(!content.contains("# Spec Files"));
+        assert!(content.contains("<steering-structure>"));
+        assert!(content.contains("# Role"));
+    }
 }
@@ -0,0 +1,352 @@
+use regex::Regex;
+use serde::Serialize;
+use std::sync::LazyLock;
+
+/// A timeline link target: `file.ext` or `file.ext#anchor`
+static LINK: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^([^\s#]+\.[A-Za-z0-9]+)(?:#(\S+))?$").unwrap());
+
+/// Checkbox completion counts parsed from a tasks.md file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct TaskProgress {
+    pub completed: usize,
+    pub total: usize,
+}
+
+impl TaskProgress {
+    /// Count `- [x]` and `- [ ]` items (at any indentation) in the Timeline of a tasks.md
+    /// (or the whole document when it has no Timeline section)
+    pub fn from_markdown(content: &str) -> Self {
+        TasksDocument::parse(content).progress()
+    }
+
+    /// Completion percentage (0 when there are no items)
+    pub fn percentage(&self) -> u8 {
+        if self.total == 0 {
+            return 0;
+        }
+        ((self.completed * 100) / self.total) as u8
+    }
+}
+
+/// A row of the `## State Tracking` table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DocumentState {
+    pub document: String,
+    pub status: String,
+    /// Free-form coverage cell (`-` becomes None)
+    pub coverage: Option<String>,
+    /// Next action cell (`-` becomes None)
+    pub next_action: Option<String>,
+}
+
+impl DocumentState {
+    pub fn is_complete(&self) -> bool {
+        self.status.eq_ignore_ascii_case("complete")
+    }
+}
+
+/// A `→ file#anchor` reference at the end of a timeline item
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TaskLink {
+    pub file: String,
+    pub anchor: Option<String>,
+}
+
+/// A checkbox item of the `## Timeline` checklist
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimelineItem {
+    /// Item text without the checkbox and the trailing file link
+    pub text: String,
+    pub done: bool,
+    /// Nesting level (0 for top-level items)
+    pub depth: usize,
+    pub link: Option<TaskLink>,
+}
+
+/// Parsed form of the tasks.md written by `create_template_files`
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+pub struct TasksDocument {
+    pub language: Option<String>,
+    pub states: Vec<DocumentState>,
+    pub timeline: Vec<TimelineItem>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Other,
+    StateTracking,
+    Timeline,
+}
+
+impl TasksDocument {
+    /// Parse tasks.md content. Unknown sections are ignored; checkbox items are
+    /// taken from `## Timeline`, or from the whole document if that section is missing.
+    pub fn parse(content: &str) -> Self {
+        let mut document = Self::default();
+        let mut section = Section::Other;
+        let mut has_timeline = false;
+        let mut loose_items = Vec::new();
+
+        for line in content.lines() {
+            let trimmed = line.trim();
+
+            if let Some(heading) = trimmed.strip_prefix("## ") {
+                section = match heading.trim() {
+                    "State Tracking" => Section::StateTracking,
+                    "Timeline" => {
+                        has_timeline = true;
+                        Section::Timeline
+                    }
+                    _ => Section::Other,
+                };
+                continue;
+            }
+
+            if let Some(language) = trimmed
+                .strip_prefix("**Language**:")
+                .map(str::trim)
+                .filter(|language| !language.is_empty())
+            {
+                document.language = Some(language.to_string());
+                continue;
+            }
+
+            match section {
+                Section::StateTracking => {
+                    if let Some(state) = parse_state_row(trimmed) {
+                        document.states.push(state);
+                    }
+                }
+                Section::Timeline => {
+                    if let Some(item) = parse_timeline_item(line) {
+                        document.timeline.push(item);
+                    }
+                }
+                Section::Other => {
+                    if let Some(item) = parse_timeline_item(line) {
+                        loose_items.push(item);
+                    }
+                }
+            }
+        }
+
+        if !has_timeline {
+            document.timeline = loose_items;
+        }
+
+        document
+    }
+
+    /// Checkbox completion over all timeline items, nested ones included
+    pub fn progress(&self) -> TaskProgress {
+        TaskProgress {
+            completed: self.timeline.iter().filter(|item| item.done).count(),
+            total: self.timeline.len(),
+        }
+    }
+
+    /// The next thing to do: the first unfinished document's Next Action from the
+    /// State Tracking table, falling back to the first unchecked timeline item
+    pub fn next_action(&self) -> Option<String> {
+        self.states
+            .iter()
+            .filter(|state| !state.is_complete())
+            .find_map(|state| state.next_action.clone())
+            .or_else(|| {
+                self.timeline
+                    .iter()
+                    .find(|item| !item.done)
+                    .map(|item| item.text.clone())
+            })
+    }
+}
+
+fn parse_state_row(line: &str) -> Option<DocumentState> {
+    let inner = line.strip_prefix('|')?.strip_suffix('|')?;
+    let cells: Vec<&str> = inner.split('|').map(str::trim).collect();
+
+    if cells.len() < 4 || cells[0] == "Document" || cells[0].chars().all(|c| c == '-') {
+        return None;
+    }
+
+    let optional = |cell: &str| (cell != "-" && !cell.is_empty()).then(|| cell.to_string());
+
+    Some(DocumentState {
+        document: cells[0].to_string(),
+        status: cells[1].to_string(),
+        coverage: optional(cells[2]),
+        next_action: optional(cells[3]),
+    })
+}
+
+fn parse_timeline_item(line: &str) -> Option<TimelineItem> {
+    let indent = line.len() - line.trim_start().len();
+    let item = line.trim_start();
+    let rest = item
+        .strip_prefix("- [")
+        .or_else(|| item.strip_prefix("* ["))?;
+
+    let (done, text) =
+        if let Some(text) = rest.strip_prefix("x]").or_else(|| rest.strip_prefix("X]")) {
+            (true, text)
+        } else {
+            (false, rest.strip_prefix(" ]")?)
+        };
+
+    let text = text.trim();
+    let (text, link) = match text.rsplit_once('→') {
+        Some((label, target)) => match parse_link(target.trim()) {
+            Some(link) => (label.trim(), Some(link)),
+            None => (text, None),
+        },
+        None => (text, None),
+    };
+
+    Some(TimelineItem {
+        text: text.to_string(),
+        done,
+        depth: indent / 2,
+        link,
+    })
+}
+
+/// Accepts `file.ext` or `file.ext#anchor`; anything else (e.g. a bare spec name) is not a link
+fn parse_link(target: &str) -> Option<TaskLink> {
+    let captures = LINK.captures(target)?;
+
+    Some(TaskLink {
+        file: captures[1].to_string(),
+        anchor: captures.get(2).map(|anchor| anchor.as_str().to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TASKS: &str = r#"# Tasks
+
+**Language**: ja
+
+## State Tracking
+
+| Document | Status | Coverage | Next Action |
+|----------|--------|----------|-------------|
+| requirements.md | complete | - | - |
+| investigation.md | in-progress | 1/2 (50%) | Investigate caching |
+| tasks.md#Timeline | pending | 0% | Plan implementation order |
+
+## Timeline
+
+- [x] Spec created → my-feature
+- [x] Requirements definition → requirements.md#overview
+- [ ] investigation: caching → investigation.md#caching
+  - [x] read docs
+  - [ ] benchmark
+"#;
+
+    #[test]
+    fn test_from_markdown_counts_checkboxes() {
+        let content = r#"# Tasks
+
+## Timeline
+
+- [x] Spec created → test
+- [ ] Requirements definition
+  - [X] nested done
+  * [ ] nested pending
+- not a task
+"#;
+
+        let progress = TaskProgress::from_markdown(content);
+        assert_eq!(progress.completed, 2);
+        assert_eq!(progress.total, 4);
+        assert_eq!(progress.percentage(), 50);
+    }
+
+    #[test]
+    fn test_from_markdown_empty() {
+        let progress = TaskProgress::from_markdown("# Tasks\n");
+        assert_eq!(progress, TaskProgress::default());
+        assert_eq!(progress.percentage(), 0);
+    }
+
+    #[test]
+    fn test_parse_language_and_state_table() {
+        let document = TasksDocument::parse(TASKS);
+
+        assert_eq!(document.language.as_deref(), Some("ja"));
+        assert_eq!(document.states.len(), 3);
+        assert_eq!(
+            document.states[1],
+            DocumentState {
+                document: "investigation.md".to_string(),
+                status: "in-progress".to_string(),
+                coverage: Some("1/2 (50%)".to_string()),
+                next_action: Some("Investigate caching".to_string()),
+            }
+        );
+        assert!(document.states[0].is_complete());
+        assert_eq!(document.states[0].next_action, None);
+    }
+
+    #[test]
+    fn test_parse_timeline_items_and_links() {
+        let document = TasksDocument::parse(TASKS);
+
+        assert_eq!(document.timeline.len(), 5);
+
+        // A bare spec name is not a file link
+        assert_eq!(document.timeline[0].text, "Spec created → my-feature");
+        assert_eq!(document.timeline[0].link, None);
+
+        assert_eq!(document.timeline[1].text, "Requirements definition");
+        assert_eq!(
+            document.timeline[1].link,
+            Some(TaskLink {
+                file: "requirements.md".to_string(),
+                anchor: Some("overview".to_string()),
+            })
+        );
+
+        assert_eq!(document.timeline[3].text, "read docs");
+        assert_eq!(document.timeline[3].depth, 1);
+        assert!(document.timeline[3].done);
+
+        assert_eq!(
+            document.progress(),
+            TaskProgress {
+                completed: 3,
+                total: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_action_prefers_state_table() {
+        let document = TasksDocument::parse(TASKS);
+        assert_eq!(
+            document.next_action().as_deref(),
+            Some("Investigate caching")
+        );
+    }
+
+    #[test]
+    fn test_next_action_falls_back_to_timeline() {
+        let document = TasksDocument::parse("## Timeline\n\n- [x] done\n- [ ] write docs\n");
+        assert_eq!(document.next_action().as_deref(), Some("write docs"));
+
+        let finished = TasksDocument::parse("## Timeline\n\n- [x] done\n");
+        assert_eq!(finished.next_action(), None);
+    }
+
+    #[test]
+    fn test_parse_ignores_checkboxes_outside_timeline() {
+        let content = "## Notes\n\n- [ ] not tracked\n\n## Timeline\n\n- [x] tracked\n";
+        let document = TasksDocument::parse(content);
+
+        assert_eq!(document.timeline.len(), 1);
+        assert_eq!(document.progress().percentage(), 100);
+    }
+}
@@ -1,4 +1,4 @@
-//! Embedded resources for slash commands
+//! Embedded resources for slash commands and skills
 //!
 //! This module contains markdown files embedded at compile time for deployment
 //! to projects during initialization.
@@ -23,6 +23,50 @@ impl EmbeddedSlashCommands {
     }
 }
 
+/// A Claude Code skill embedded for deployment into `.claude/skills/<name>/`
+#[derive(Debug, Clone, Copy)]
+pub struct EmbeddedSkill {
+    pub name: &'static str,
+    /// Version of the plugin the skill is published in, recorded on deployment
+    pub version: &'static str,
+    /// (path within the skill directory, content) pairs
+    pub files: &'static [(&'static str, &'static str)],
+}
+
+/// Embedded skills, shipped in this repository as Claude Code plugins under `plugins/`
+pub struct EmbeddedSkills;
+
+impl EmbeddedSkills {
+    /// PRD management: feature specs, user stories and implementation plans
+    const PRD: EmbeddedSkill = EmbeddedSkill {
+        name: "prd",
+        version: "1.0.0",
+        files: &[
+            (
+                "SKILL.md",
+                include_str!("../../../../plugins/prd/skills/prd/SKILL.md"),
+            ),
+            (
+                "references/feature-spec.md",
+                include_str!("../../../../plugins/prd/skills/prd/references/feature-spec.md"),
+            ),
+            (
+                "references/plan-story.md",
+                include_str!("../../../../plugins/prd/skills/prd/references/plan-story.md"),
+            ),
+            (
+                "references/user-stories.md",
+                include_str!("../../../../plugins/prd/skills/prd/references/user-stories.md"),
+            ),
+        ],
+    };
+
+    /// Returns all embedded skills
+    pub fn get_all() -> Vec<EmbeddedSkill> {
+        vec![Self::PRD]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,4 +90,43 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_embedded_skills_not_empty() {
+        for skill in EmbeddedSkills::get_all() {
+            assert!(
+                skill.files.iter().any(|(path, _)| *path == "SKILL.md"),
+                "Skill {} should have a SKILL.md",
+                skill.name
+            );
+            for (path, content) in skill.files {
+                assert!(
+                    !content.is_empty(),
+                    "File {} of skill {} should not be empty",
+                    path,
+                    skill.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_embedded_skill_versions_match_plugin_manifests() {
+        let plugin: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../../plugins/prd/.claude-plugin/plugin.json"
+        ))
+        .unwrap();
+        let marketplace: serde_json::Value =
+            serde_json::from_str(include_str!("../../../../.claude-plugin/marketplace.json"))
+                .unwrap();
+        let listed = marketplace["plugins"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|entry| entry["name"] == "prd")
+            .unwrap();
+
+        assert_eq!(plugin["version"], EmbeddedSkills::PRD.version);
+        assert_eq!(listed["version"], EmbeddedSkills::PRD.version);
+    }
 }
@@ -0,0 +1,649 @@
+//! Content-addressed store for steering backups.
+//!
+//! Layout under `.kiro/steering/backup/`:
+//!
+//! ```text
+//! objects/<sha256>        one blob per unique file content
+//! snapshots/<name>.json   BackupManifest listing the blobs of a backup
+//! <name>/                 legacy full-copy backups (read and deleted, never created)
+//! ```
+//!
+//! Blobs are shared between snapshots, so backing up unchanged files costs
+//! only a manifest. Blobs no longer referenced by any snapshot are removed
+//! by [`BackupStore::collect_garbage`] whenever backups are deleted.
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::steering_repository::BackupInfo;
+use crate::domain::value_objects::steering_backup::{
+    BackupFileEntry, BackupManifest, BackupReason, content_hash,
+};
+use chrono::{DateTime, Local};
+use std::collections::HashSet;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+const OBJECTS_DIR: &str = "objects";
+const SNAPSHOTS_DIR: &str = "snapshots";
+/// Infix of manifests being written, `<name>.json.tmp-<pid>`
+const SNAPSHOT_TEMP_MARKER: &str = ".json.tmp-";
+/// Manifest written into legacy directory backups
+const LEGACY_MANIFEST_FILE: &str = "manifest.json";
+
+pub struct BackupStore {
+    root: PathBuf,
+}
+
+impl BackupStore {
+    pub fn new(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    fn objects_dir(&self) -> PathBuf {
+        self.root.join(OBJECTS_DIR)
+    }
+
+    fn snapshots_dir(&self) -> PathBuf {
+        self.root.join(SNAPSHOTS_DIR)
+    }
+
+    fn snapshot_path(&self, name: &str) -> PathBuf {
+        self.snapshots_dir().join(format!("{}.json", name))
+    }
+
+    fn legacy_dir(&self, name: &str) -> Option<PathBuf> {
+        if name.is_empty() || name == OBJECTS_DIR || name == SNAPSHOTS_DIR {
+            return None;
+        }
+        let path = self.root.join(name);
+        path.is_dir().then_some(path)
+    }
+
+    /// Store `files` (relative path and content) as a new snapshot.
+    ///
+    /// A `-2`, `-3`, ... suffix is appended when `name` is already taken.
+    pub fn create(
+        &self,
+        name: &str,
+        files: &[(PathBuf, Vec<u8>)],
+        reason: BackupReason,
+    ) -> Result<BackupManifest, ApplicationError> {
+        fs::create_dir_all(self.objects_dir())
+            .and_then(|_| fs::create_dir_all(self.snapshots_dir()))
+            .map_err(|e| fs_error("Failed to create backup directory", e))?;
+
+        // Blobs first, so a snapshot never references a missing object
+        let mut entries = Vec::new();
+        for (path, content) in files {
+            let entry = BackupFileEntry::from_content(path.clone(), content);
+            self.write_object(&entry.sha256, content)?;
+            entries.push(entry);
+        }
+
+        // Never reuse a name, even one freed by rotation, so name order stays creation order
+        let mut attempt = self
+            .list()?
+            .iter()
+            .filter_map(|backup| collision_index(name, &backup.name))
+            .max()
+            .map_or(1, |index| index + 1);
+        loop {
+            let candidate = if attempt == 1 {
+                name.to_string()
+            } else {
+                format!("{}-{}", name, attempt)
+            };
+            attempt += 1;
+
+            if self.legacy_dir(&candidate).is_some() {
+                continue;
+            }
+
+            let manifest = BackupManifest {
+                name: candidate,
+                created_at: Local::now().to_rfc3339(),
+                reason,
+                files: entries.clone(),
+            };
+            let json = serde_json::to_string_pretty(&manifest)
+                .map_err(|e| fs_error("Failed to serialize backup manifest", e))?;
+
+            // Write the manifest aside, then link it into place: linking claims the
+            // name atomically and a snapshot is never observed half-written
+            let temp = self.snapshots_dir().join(format!(
+                "{}{}{}",
+                manifest.name,
+                SNAPSHOT_TEMP_MARKER,
+                std::process::id()
+            ));
+            fs::write(&temp, json).map_err(|e| fs_error("Failed to write backup manifest", e))?;
+            let linked = fs::hard_link(&temp, self.snapshot_path(&manifest.name));
+            fs::remove_file(&temp).map_err(|e| fs_error("Failed to write backup manifest", e))?;
+
+            match linked {
+                Ok(()) => return Ok(manifest),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(fs_error("Failed to create backup snapshot", e)),
+            }
+        }
+    }
+
+    fn write_object(&self, hash: &str, content: &[u8]) -> Result<(), ApplicationError> {
+        let path = self.objects_dir().join(hash);
+        if path.is_file() {
+            return Ok(());
+        }
+
+        // Write then rename so a blob is never observed half-written
+        let temp = self
+            .objects_dir()
+            .join(format!("{}.tmp-{}", hash, std::process::id()));
+        fs::write(&temp, content)
+            .and_then(|_| fs::rename(&temp, &path))
+            .map_err(|e| fs_error("Failed to store backup object", e))
+    }
+
+    /// All backups, snapshots and legacy directories alike, sorted by name (oldest first)
+    pub fn list(&self) -> Result<Vec<BackupInfo>, ApplicationError> {
+        let mut backups = Vec::new();
+
+        if self.snapshots_dir().is_dir() {
+            for entry in read_dir(&self.snapshots_dir())? {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                    continue;
+                }
+                let Some(name) = path.file_stem().map(|s| s.to_string_lossy().to_string()) else {
+                    continue;
+                };
+                let created_at = self
+                    .read_snapshot(&name)
+                    .ok()
+                    .and_then(|manifest| DateTime::parse_from_rfc3339(&manifest.created_at).ok())
+                    .map(SystemTime::from)
+                    .unwrap_or_else(|| modified(&entry));
+                backups.push(BackupInfo {
+                    name,
+                    created_at,
+                    path,
+                });
+            }
+        }
+
+        if self.root.is_dir() {
+            for entry in read_dir(&self.root)? {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if let Some(path) = self.legacy_dir(&name) {
+                    backups.push(BackupInfo {
+                        name,
+                        created_at: modified(&entry),
+                        path,
+                    });
+                }
+            }
+        }
+
+        // Names are timestamps (YYYY-MM-DD-HH-MM-SS, or YYYY-MM-DD-HH-MM for older
+        // backups) with an optional collision suffix, so name order is creation order
+        backups.sort_by_cached_key(|backup| name_order(&backup.name));
+        Ok(backups)
+    }
+
+    /// Delete a backup. Its objects stay until the next garbage collection.
+    pub fn delete(&self, backup: &BackupInfo) -> Result<(), ApplicationError> {
+        let result = if backup.path.is_dir() {
+            fs::remove_dir_all(&backup.path)
+        } else {
+            fs::remove_file(&backup.path)
+        };
+        result.map_err(|e| {
+            ApplicationError::FileSystemError(format!(
+                "Failed to delete backup {}: {}",
+                backup.name, e
+            ))
+        })
+    }
+
+    /// Remove objects no snapshot references, returning how many were removed.
+    /// Manifests left half-written by an interrupted backup are removed as well.
+    ///
+    /// If any snapshot cannot be read, nothing is removed and the error is returned:
+    /// its objects may still be needed, e.g. by a manifest from a newer hail-mary.
+    pub fn collect_garbage(&self) -> Result<usize, ApplicationError> {
+        if !self.objects_dir().is_dir() {
+            return Ok(0);
+        }
+
+        let mut referenced = HashSet::new();
+        for backup in self.list()? {
+            if backup.path.is_dir() {
+                continue;
+            }
+            let manifest = self.read_snapshot(&backup.name).map_err(|e| match e {
+                ApplicationError::FileSystemError(message) => ApplicationError::FileSystemError(
+                    format!("{}; no backup objects were removed", message),
+                ),
+                other => other,
+            })?;
+            referenced.extend(manifest.files.into_iter().map(|file| file.sha256));
+        }
+
+        let snapshots = if self.snapshots_dir().is_dir() {
+            read_dir(&self.snapshots_dir())?
+        } else {
+            Vec::new()
+        };
+        for entry in snapshots {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.contains(SNAPSHOT_TEMP_MARKER) {
+                fs::remove_file(entry.path())
+                    .map_err(|e| fs_error("Failed to remove incomplete backup manifest", e))?;
+            }
+        }
+
+        let mut removed = 0;
+        for entry in read_dir(&self.objects_dir())? {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !referenced.contains(&name) {
+                fs::remove_file(entry.path())
+                    .map_err(|e| fs_error("Failed to remove backup object", e))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Manifest of a backup; None for legacy backups made before manifests existed
+    pub fn manifest(&self, backup: &str) -> Result<Option<BackupManifest>, ApplicationError> {
+        if self.snapshot_path(backup).is_file() && !backup.is_empty() {
+            return self.read_snapshot(backup).map(Some);
+        }
+
+        let dir = self
+            .legacy_dir(backup)
+            .ok_or_else(|| ApplicationError::BackupNotFound(backup.to_string()))?;
+        let manifest_path = dir.join(LEGACY_MANIFEST_FILE);
+        if !manifest_path.is_file() {
+            return Ok(None);
+        }
+        read_manifest(&manifest_path, backup).map(Some)
+    }
+
+    /// Files stored in a backup (relative paths, sorted)
+    pub fn files(&self, backup: &str) -> Result<Vec<PathBuf>, ApplicationError> {
+        let mut files: Vec<PathBuf> = match self.legacy_dir(backup) {
+            Some(dir) => read_dir(&dir)?
+                .into_iter()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("md")
+                })
+                .filter_map(|path| path.file_name().map(PathBuf::from))
+                .collect(),
+            None => self
+                .manifest(backup)?
+                .map(|manifest| manifest.files.into_iter().map(|file| file.path).collect())
+                .unwrap_or_default(),
+        };
+
+        files.sort();
+        Ok(files)
+    }
+
+    /// Content of `file` in a backup, verified against its recorded hash
+    pub fn read(&self, backup: &str, file: &Path) -> Result<String, ApplicationError> {
+        let not_found = || {
+            ApplicationError::FileSystemError(format!(
+                "File {} not found in backup {}",
+                file.display(),
+                backup
+            ))
+        };
+
+        let content = if let Some(dir) = self.legacy_dir(backup) {
+            let path = dir.join(file);
+            if !path.is_file() {
+                return Err(not_found());
+            }
+            fs::read(&path)
+        } else {
+            let manifest = self.manifest(backup)?.ok_or_else(not_found)?;
+            let entry = manifest
+                .files
+                .iter()
+                .find(|entry| entry.path == file)
+                .ok_or_else(not_found)?;
+
+            let content = fs::read(self.objects_dir().join(&entry.sha256));
+            if let Ok(content) = &content
+                && content_hash(content) != entry.sha256
+            {
+                return Err(ApplicationError::FileSystemError(format!(
+                    "Backup object for {} in backup {} is corrupted",
+                    file.display(),
+                    backup
+                )));
+            }
+            content
+        };
+
+        content
+            .map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to read {} from backup {}: {}",
+                    file.display(),
+                    backup,
+                    e
+                ))
+            })
+            .and_then(|content| {
+                String::from_utf8(content).map_err(|_| {
+                    ApplicationError::FileSystemError(format!(
+                        "{} in backup {} is not valid UTF-8",
+                        file.display(),
+                        backup
+                    ))
+                })
+            })
+    }
+
+    fn read_snapshot(&self, backup: &str) -> Result<BackupManifest, ApplicationError> {
+        read_manifest(&self.snapshot_path(backup), backup)
+    }
+}
+
+/// 1 if `existing` is `name` itself, N if it is `name-N`
+fn collision_index(name: &str, existing: &str) -> Option<u64> {
+    if existing == name {
+        return Some(1);
+    }
+    existing.strip_prefix(name)?.strip_prefix('-')?.parse().ok()
+}
+
+/// Sort key comparing dash-separated numeric segments by value (`-10` after `-2`)
+fn name_order(name: &str) -> Vec<Result<u64, String>> {
+    name.split('-')
+        .map(|segment| segment.parse().map_err(|_| segment.to_string()))
+        .collect()
+}
+
+fn read_manifest(path: &Path, backup: &str) -> Result<BackupManifest, ApplicationError> {
+    let json = fs::read_to_string(path).map_err(|e| {
+        ApplicationError::FileSystemError(format!(
+            "Failed to read manifest of backup {}: {}",
+            backup, e
+        ))
+    })?;
+    serde_json::from_str(&json).map_err(|e| {
+        ApplicationError::FileSystemError(format!("Invalid manifest in backup {}: {}", backup, e))
+    })
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<fs::DirEntry>, ApplicationError> {
+    fs::read_dir(dir)
+        .and_then(|entries| entries.collect::<Result<Vec<_>, _>>())
+        .map_err(|e| fs_error("Failed to read backup directory", e))
+}
+
+fn modified(entry: &fs::DirEntry) -> SystemTime {
+    entry
+        .metadata()
+        .and_then(|metadata| metadata.modified())
+        .unwrap_or(SystemTime::UNIX_EPOCH)
+}
+
+fn fs_error(action: &str, e: impl std::fmt::Display) -> ApplicationError {
+    ApplicationError::FileSystemError(format!("{}: {}", action, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn file(path: &str, content: &str) -> (PathBuf, Vec<u8>) {
+        (PathBuf::from(path), content.as_bytes().to_vec())
+    }
+
+    fn object_count(store: &BackupStore) -> usize {
+        fs::read_dir(store.objects_dir()).unwrap().count()
+    }
+
+    #[test]
+    fn test_identical_content_is_stored_once() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+
+        store
+            .create(
+                "2025-01-01-10-00-00",
+                &[file("product.md", "# Same"), file("tech.md", "# Tech v1")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+        store
+            .create(
+                "2025-01-01-10-00-01",
+                &[file("product.md", "# Same"), file("tech.md", "# Tech v2")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+
+        // "# Same" is shared by both snapshots
+        assert_eq!(object_count(&store), 3);
+        assert_eq!(
+            store
+                .read("2025-01-01-10-00-00", Path::new("tech.md"))
+                .unwrap(),
+            "# Tech v1"
+        );
+        assert_eq!(
+            store.files("2025-01-01-10-00-01").unwrap(),
+            vec![PathBuf::from("product.md"), PathBuf::from("tech.md")]
+        );
+    }
+
+    #[test]
+    fn test_garbage_collection_keeps_shared_objects() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+        store
+            .create(
+                "2025-01-01-10-00-00",
+                &[file("product.md", "# Same"), file("tech.md", "# Tech v1")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+        store
+            .create(
+                "2025-01-01-10-00-01",
+                &[file("product.md", "# Same"), file("tech.md", "# Tech v2")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+
+        let oldest = store.list().unwrap().remove(0);
+        store.delete(&oldest).unwrap();
+
+        assert_eq!(store.collect_garbage().unwrap(), 1);
+        assert_eq!(object_count(&store), 2);
+        assert_eq!(
+            store
+                .read("2025-01-01-10-00-01", Path::new("product.md"))
+                .unwrap(),
+            "# Same"
+        );
+    }
+
+    #[test]
+    fn test_garbage_collection_keeps_unreadable_snapshots() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+        store
+            .create(
+                "2025-01-01-10-00-00",
+                &[file("tech.md", "# Tech v1")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+        // e.g. written by a newer hail-mary with a reason this build doesn't know
+        let manifest = store
+            .create(
+                "2025-01-01-10-00-01",
+                &[file("tech.md", "# Tech v2")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+        let newer = store.snapshot_path("2025-01-01-10-00-01");
+        let json = fs::read_to_string(&newer)
+            .unwrap()
+            .replace("\"manual\"", "\"before-upgrade\"");
+        fs::write(&newer, &json).unwrap();
+        // Left behind by a backup interrupted while writing its manifest
+        let temp = store
+            .snapshots_dir()
+            .join("2025-01-01-10-00-02.json.tmp-4242");
+        fs::write(&temp, "{\"name\": ").unwrap();
+
+        let oldest = store.list().unwrap().remove(0);
+        store.delete(&oldest).unwrap();
+
+        let error = store.collect_garbage().unwrap_err();
+        assert!(error.to_string().contains("2025-01-01-10-00-01"));
+        assert_eq!(fs::read_to_string(&newer).unwrap(), json);
+        assert!(
+            store
+                .objects_dir()
+                .join(&manifest.files[0].sha256)
+                .is_file()
+        );
+        assert_eq!(object_count(&store), 2);
+
+        // Once the manifest is readable again, collection proceeds
+        fs::write(&newer, json.replace("\"before-upgrade\"", "\"manual\"")).unwrap();
+        assert_eq!(store.collect_garbage().unwrap(), 1);
+        assert!(!temp.exists());
+        assert!(newer.is_file());
+    }
+
+    #[test]
+    fn test_create_leaves_only_complete_snapshots() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+
+        store
+            .create("2025-01-01-10-00-00", &[], BackupReason::Manual)
+            .unwrap();
+        store
+            .create("2025-01-01-10-00-00", &[], BackupReason::Manual)
+            .unwrap();
+
+        let mut snapshots: Vec<String> = fs::read_dir(store.snapshots_dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        snapshots.sort();
+        assert_eq!(
+            snapshots,
+            vec![
+                "2025-01-01-10-00-00-2.json".to_string(),
+                "2025-01-01-10-00-00.json".to_string()
+            ]
+        );
+        assert!(store.read_snapshot("2025-01-01-10-00-00-2").is_ok());
+    }
+
+    #[test]
+    fn test_legacy_directory_backups_are_listed_and_read() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+        let legacy = temp_dir.path().join("backup/2024-12-31-09-00");
+        fs::create_dir_all(&legacy).unwrap();
+        fs::write(legacy.join("tech.md"), "# Old tech").unwrap();
+        store
+            .create(
+                "2025-01-01-10-00-00",
+                &[file("tech.md", "# Tech")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+
+        let names: Vec<String> = store.list().unwrap().into_iter().map(|b| b.name).collect();
+        assert_eq!(names, vec!["2024-12-31-09-00", "2025-01-01-10-00-00"]);
+        assert_eq!(store.manifest("2024-12-31-09-00").unwrap(), None);
+        assert_eq!(
+            store
+                .read("2024-12-31-09-00", Path::new("tech.md"))
+                .unwrap(),
+            "# Old tech"
+        );
+
+        // A legacy name is taken too
+        let manifest = store
+            .create("2024-12-31-09-00", &[], BackupReason::Manual)
+            .unwrap();
+        assert_eq!(manifest.name, "2024-12-31-09-00-2");
+    }
+
+    #[test]
+    fn test_names_are_not_reused_after_deletion() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+        let create = || {
+            store
+                .create("2025-01-01-10-00-00", &[], BackupReason::Manual)
+                .unwrap()
+                .name
+        };
+
+        for _ in 0..10 {
+            create();
+        }
+        let oldest = store.list().unwrap().remove(0);
+        assert_eq!(oldest.name, "2025-01-01-10-00-00");
+        store.delete(&oldest).unwrap();
+
+        assert_eq!(create(), "2025-01-01-10-00-00-11");
+        let names: Vec<String> = store.list().unwrap().into_iter().map(|b| b.name).collect();
+        assert_eq!(names[0], "2025-01-01-10-00-00-2");
+        assert_eq!(names[8], "2025-01-01-10-00-00-10");
+        assert_eq!(names[9], "2025-01-01-10-00-00-11");
+    }
+
+    #[test]
+    fn test_corrupted_object_is_reported() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+        let manifest = store
+            .create(
+                "2025-01-01-10-00-00",
+                &[file("tech.md", "# Tech")],
+                BackupReason::Manual,
+            )
+            .unwrap();
+        fs::write(
+            store.objects_dir().join(&manifest.files[0].sha256),
+            "tampered",
+        )
+        .unwrap();
+
+        let error = store
+            .read("2025-01-01-10-00-00", Path::new("tech.md"))
+            .unwrap_err();
+        assert!(error.to_string().contains("corrupted"));
+    }
+
+    #[test]
+    fn test_unknown_backup_is_not_found() {
+        let temp_dir = tempdir().unwrap();
+        let store = BackupStore::new(temp_dir.path().join("backup"));
+
+        for name in ["missing", "objects", "snapshots", ""] {
+            assert!(matches!(
+                store.manifest(name),
+                Err(ApplicationError::BackupNotFound(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! File system writes that report what they change.
+//!
+//! Every operation returns the [`FileChange`]s it made, relative to the
+//! project root. Writes that would leave a file byte-for-byte identical are
+//! skipped and report nothing. In dry-run mode nothing is touched, but the
+//! same changes are reported. Clones of a dry-run writer share the directories
+//! planned so far, so repositories writing through them report each directory
+//! once, as a real run would.
+
+use crate::application::errors::ApplicationError;
+use crate::domain::value_objects::file_change::FileChange;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone)]
+pub struct FileWriter {
+    project_root: PathBuf,
+    dry_run: bool,
+    /// Directories a dry run would have created
+    planned_dirs: Arc<Mutex<BTreeSet<PathBuf>>>,
+}
+
+impl FileWriter {
+    pub fn new(project_root: PathBuf) -> Self {
+        Self {
+            project_root,
+            dry_run: false,
+            planned_dirs: Arc::default(),
+        }
+    }
+
+    /// A writer that only reports the changes it would make
+    pub fn dry_run(project_root: PathBuf) -> Self {
+        Self {
+            project_root,
+            dry_run: true,
+            planned_dirs: Arc::default(),
+        }
+    }
+
+    /// Create `dir` and any missing parents
+    pub fn create_dir_all(&self, dir: &Path) -> Result<Vec<FileChange>, ApplicationError> {
+        if dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        if self.dry_run {
+            let mut planned_dirs = self
+                .planned_dirs
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            // A planned subdirectory would have created `dir` along the way
+            if planned_dirs.iter().any(|planned| planned.starts_with(dir)) {
+                return Ok(Vec::new());
+            }
+            planned_dirs.insert(dir.to_path_buf());
+        } else {
+            fs::create_dir_all(dir).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to create {}: {}",
+                    self.relative(dir).display(),
+                    e
+                ))
+            })?;
+        }
+
+        Ok(vec![FileChange::create(self.relative_dir(dir))])
+    }
+
+    /// Write `contents` to `file` unless it already has exactly that content
+    pub fn write(&self, file: &Path, contents: &str) -> Result<Vec<FileChange>, ApplicationError> {
+        let change = match fs::read(file) {
+            Ok(existing) if existing == contents.as_bytes() => return Ok(Vec::new()),
+            Ok(_) => FileChange::modify(self.relative(file)),
+            Err(_) => FileChange::create(self.relative(file)),
+        };
+
+        if !self.dry_run {
+            fs::write(file, contents).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to write {}: {}",
+                    change.path.display(),
+                    e
+                ))
+            })?;
+        }
+
+        Ok(vec![change])
+    }
+
+    /// Remove a single file if present
+    pub fn remove_file(&self, file: &Path) -> Result<Vec<FileChange>, ApplicationError> {
+        if !file.is_file() {
+            return Ok(Vec::new());
+        }
+
+        if !self.dry_run {
+            fs::remove_file(file).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to remove {}: {}",
+                    self.relative(file).display(),
+                    e
+                ))
+            })?;
+        }
+
+        Ok(vec![FileChange::delete(self.relative(file))])
+    }
+
+    /// Remove `dir` with everything in it, reporting each file deleted
+    pub fn remove_dir_all(&self, dir: &Path) -> Result<Vec<FileChange>, ApplicationError> {
+        if !dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+        collect_files(dir, &mut files).map_err(|e| {
+            ApplicationError::FileSystemError(format!(
+                "Failed to read {}: {}",
+                self.relative(dir).display(),
+                e
+            ))
+        })?;
+        files.sort();
+
+        if !self.dry_run {
+            fs::remove_dir_all(dir).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to remove {}: {}",
+                    self.relative(dir).display(),
+                    e
+                ))
+            })?;
+        }
+
+        let mut changes: Vec<FileChange> = files
+            .iter()
+            .map(|file| FileChange::delete(self.relative(file)))
+            .collect();
+        changes.push(FileChange::delete(self.relative_dir(dir)));
+        Ok(changes)
+    }
+
+    fn relative(&self, path: &Path) -> PathBuf {
+        path.strip_prefix(&self.project_root)
+            .unwrap_or(path)
+            .to_path_buf()
+    }
+
+    fn relative_dir(&self, dir: &Path) -> PathBuf {
+        PathBuf::from(format!("{}/", self.relative(dir).display()))
+    }
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::file_change::FileChangeKind;
+    use tempfile::tempdir;
+
+    fn kinds(changes: &[FileChange]) -> Vec<(FileChangeKind, String)> {
+        changes
+            .iter()
+            .map(|change| (change.kind, change.path.display().to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_write_reports_create_modify_and_skips_unchanged() {
+        let temp_dir = tempdir().unwrap();
+        let writer = FileWriter::new(temp_dir.path().to_path_buf());
+        let file = temp_dir.path().join(".gitignore");
+
+        let created = writer.write(&file, "target\n").unwrap();
+        assert_eq!(
+            kinds(&created),
+            vec![(FileChangeKind::Create, ".gitignore".to_string())]
+        );
+
+        assert!(writer.write(&file, "target\n").unwrap().is_empty());
+
+        let modified = writer.write(&file, "target\n*.log\n").unwrap();
+        assert_eq!(
+            kinds(&modified),
+            vec![(FileChangeKind::Modify, ".gitignore".to_string())]
+        );
+        assert_eq!(fs::read_to_string(&file).unwrap(), "target\n*.log\n");
+    }
+
+    #[test]
+    fn test_dry_run_reports_without_touching_files() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".claude/agents/nested")).unwrap();
+        fs::write(root.join(".claude/agents/a.md"), "a").unwrap();
+        fs::write(root.join(".claude/agents/nested/b.md"), "b").unwrap();
+        let writer = FileWriter::dry_run(root.to_path_buf());
+
+        let changes = writer.create_dir_all(&root.join(".kiro/steering")).unwrap();
+        assert_eq!(
+            kinds(&changes),
+            vec![(FileChangeKind::Create, ".kiro/steering/".to_string())]
+        );
+
+        let changes = writer.write(&root.join("new.md"), "x").unwrap();
+        assert_eq!(
+            kinds(&changes),
+            vec![(FileChangeKind::Create, "new.md".to_string())]
+        );
+
+        let changes = writer.remove_dir_all(&root.join(".claude/agents")).unwrap();
+        assert_eq!(
+            kinds(&changes),
+            vec![
+                (FileChangeKind::Delete, ".claude/agents/a.md".to_string()),
+                (
+                    FileChangeKind::Delete,
+                    ".claude/agents/nested/b.md".to_string()
+                ),
+                (FileChangeKind::Delete, ".claude/agents/".to_string()),
+            ]
+        );
+
+        assert!(!root.join(".kiro").exists());
+        assert!(!root.join("new.md").exists());
+        assert!(root.join(".claude/agents/nested/b.md").exists());
+    }
+
+    #[test]
+    fn test_dry_run_reports_each_planned_directory_once() {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path();
+        let writer = FileWriter::dry_run(root.to_path_buf());
+        let other = writer.clone();
+
+        let changes = writer.create_dir_all(&root.join(".kiro/steering")).unwrap();
+        assert_eq!(
+            kinds(&changes),
+            vec![(FileChangeKind::Create, ".kiro/steering/".to_string())]
+        );
+        assert!(
+            other
+                .create_dir_all(&root.join(".kiro"))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            other
+                .create_dir_all(&root.join(".kiro/steering"))
+                .unwrap()
+                .is_empty()
+        );
+
+        let changes = other.create_dir_all(&root.join(".kiro/specs")).unwrap();
+        assert_eq!(
+            kinds(&changes),
+            vec![(FileChangeKind::Create, ".kiro/specs/".to_string())]
+        );
+    }
+}
@@ -1 +1,3 @@
+pub mod backup_store;
+pub mod file_writer;
 pub mod path_manager;
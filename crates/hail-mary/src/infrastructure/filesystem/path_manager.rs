@@ -1,5 +1,6 @@
 use crate::application::errors::ApplicationError;
 use std::env;
+use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone)]
@@ -67,6 +68,32 @@ impl PathManager {
         }
     }
 
+    pub fn templates_dir(&self, absolute: bool) -> PathBuf {
+        if absolute {
+            self.kiro_dir(true).join("templates")
+        } else {
+            PathBuf::from(".kiro/templates")
+        }
+    }
+
+    /// Project-local slash commands that overlay the embedded ones
+    pub fn commands_dir(&self, absolute: bool) -> PathBuf {
+        if absolute {
+            self.kiro_dir(true).join("commands")
+        } else {
+            PathBuf::from(".kiro/commands")
+        }
+    }
+
+    /// Manifest of files deployed into `.claude/`
+    pub fn deployment_manifest_path(&self, absolute: bool) -> PathBuf {
+        if absolute {
+            self.kiro_dir(true).join("deployed-files.json")
+        } else {
+            PathBuf::from(".kiro/deployed-files.json")
+        }
+    }
+
     pub fn memory_dir(&self, absolute: bool) -> PathBuf {
         if absolute {
             self.kiro_dir(true).join("memory")
@@ -90,6 +117,28 @@ impl PathManager {
             PathBuf::from(".kiro/archive")
         }
     }
+
+    /// User-level config shared by all projects: `$XDG_CONFIG_HOME/hail-mary/config.toml`,
+    /// or `~/.config/hail-mary/config.toml` when XDG_CONFIG_HOME is unset
+    pub fn user_config_path() -> Option<PathBuf> {
+        Self::user_config_path_from(env::var_os("XDG_CONFIG_HOME"), env::var_os("HOME"))
+    }
+
+    fn user_config_path_from(
+        xdg_config_home: Option<OsString>,
+        home: Option<OsString>,
+    ) -> Option<PathBuf> {
+        // The XDG spec ignores relative values
+        let config_dir = xdg_config_home
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .or_else(|| {
+                home.filter(|home| !home.is_empty())
+                    .map(|home| PathBuf::from(home).join(".config"))
+            })?;
+
+        Some(config_dir.join("hail-mary").join("config.toml"))
+    }
 }
 
 #[cfg(test)]
@@ -213,6 +262,26 @@ mod tests {
         assert_eq!(relative_path, PathBuf::from(".kiro/config.toml"));
     }
 
+    #[test]
+    fn test_user_config_path_prefers_xdg_config_home() {
+        let path = PathManager::user_config_path_from(
+            Some(OsString::from("/xdg")),
+            Some(OsString::from("/home/me")),
+        );
+        assert_eq!(path, Some(PathBuf::from("/xdg/hail-mary/config.toml")));
+
+        // Unset or relative XDG_CONFIG_HOME falls back to ~/.config
+        for xdg in [None, Some(OsString::from("relative"))] {
+            let path = PathManager::user_config_path_from(xdg, Some(OsString::from("/home/me")));
+            assert_eq!(
+                path,
+                Some(PathBuf::from("/home/me/.config/hail-mary/config.toml"))
+            );
+        }
+
+        assert_eq!(PathManager::user_config_path_from(None, None), None);
+    }
+
     #[test]
     fn test_specs_dir_absolute_and_relative() {
         let project_root = PathBuf::from("/test/project");
@@ -227,6 +296,48 @@ mod tests {
         assert_eq!(relative_path, PathBuf::from(".kiro/specs"));
     }
 
+    #[test]
+    fn test_templates_dir_absolute_and_relative() {
+        let path_manager = PathManager::new(PathBuf::from("/test/project"));
+
+        assert_eq!(
+            path_manager.templates_dir(true),
+            PathBuf::from("/test/project/.kiro/templates")
+        );
+        assert_eq!(
+            path_manager.templates_dir(false),
+            PathBuf::from(".kiro/templates")
+        );
+    }
+
+    #[test]
+    fn test_commands_dir_absolute_and_relative() {
+        let path_manager = PathManager::new(PathBuf::from("/test/project"));
+
+        assert_eq!(
+            path_manager.commands_dir(true),
+            PathBuf::from("/test/project/.kiro/commands")
+        );
+        assert_eq!(
+            path_manager.commands_dir(false),
+            PathBuf::from(".kiro/commands")
+        );
+    }
+
+    #[test]
+    fn test_deployment_manifest_path_absolute_and_relative() {
+        let path_manager = PathManager::new(PathBuf::from("/test/project"));
+
+        assert_eq!(
+            path_manager.deployment_manifest_path(true),
+            PathBuf::from("/test/project/.kiro/deployed-files.json")
+        );
+        assert_eq!(
+            path_manager.deployment_manifest_path(false),
+            PathBuf::from(".kiro/deployed-files.json")
+        );
+    }
+
     #[test]
     fn test_memory_dir_absolute_and_relative() {
         let project_root = PathBuf::from("/test/project");
@@ -0,0 +1,123 @@
+use anyhow::Result;
+use std::process::Command;
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::LauncherInterface;
+use crate::domain::value_objects::launch_command::LaunchCommand;
+
+pub struct AgentProcessLauncher;
+
+impl AgentProcessLauncher {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn check_program_availability(program: &str) -> Result<bool> {
+        // Use 'which' on Unix-like systems, 'where' on Windows
+        let command = if cfg!(target_os = "windows") {
+            "where"
+        } else {
+            "which"
+        };
+
+        let output = Command::new(command).arg(program).output()?;
+
+        Ok(output.status.success())
+    }
+}
+
+impl LauncherInterface for AgentProcessLauncher {
+    fn launch(&self, command: &LaunchCommand) -> Result<(), ApplicationError> {
+        // Check if the command exists
+        let exists = Self::check_program_availability(&command.program)
+            .map_err(|e| ApplicationError::ProcessLaunchError(e.to_string()))?;
+
+        if !exists {
+            return Err(ApplicationError::ProcessLaunchError(format!(
+                "'{}' not found. Please install it first or set `command` in the [agent] section of .kiro/config.toml",
+                command.program
+            )));
+        }
+
+        let mut cmd = Command::new(&command.program);
+        cmd.args(&command.args)
+            .envs(command.env.iter().map(|(key, value)| (key, value)));
+
+        // Use exec to replace current process with the agent CLI
+        // This preserves TTY access while allowing backgrounding via shell job control
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+
+            let error = cmd.exec(); // This never returns if successful
+
+            // If we reach here, exec failed
+            Err(ApplicationError::ProcessLaunchError(format!(
+                "Failed to exec {}: {}",
+                command.program, error
+            )))
+        }
+
+        #[cfg(not(unix))]
+        {
+            // Fallback for non-Unix systems
+            cmd.spawn().map_err(|e| {
+                ApplicationError::ProcessLaunchError(format!(
+                    "Failed to spawn {}: {}",
+                    command.program, e
+                ))
+            })?;
+
+            Ok(())
+        }
+    }
+}
+
+impl Default for AgentProcessLauncher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agent_launcher_new() {
+        let launcher = AgentProcessLauncher::new();
+        // Just ensure it can be created without panicking
+        assert!(std::mem::size_of_val(&launcher) == 0);
+    }
+
+    #[test]
+    fn test_agent_launcher_default() {
+        let launcher = AgentProcessLauncher::new();
+        // Just ensure default works
+        assert!(std::mem::size_of_val(&launcher) == 0);
+    }
+
+    #[test]
+    fn test_check_program_availability() {
+        // This test will depend on whether the agent is actually installed
+        // We just test that the function doesn't panic
+        let result = AgentProcessLauncher::check_program_availability("claude");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_launch_missing_program_fails() {
+        let command = LaunchCommand {
+            program: "hail-mary-missing-program".to_string(),
+            args: vec![],
+            env: vec![],
+        };
+
+        let result = AgentProcessLauncher::new().launch(&command);
+        assert!(matches!(
+            result,
+            Err(ApplicationError::ProcessLaunchError(_))
+        ));
+    }
+}
@@ -1 +1,3 @@
-pub mod claude_launcher;
+pub mod agent_launcher;
+#[cfg(any(test, feature = "test-util"))]
+pub mod recording_launcher;
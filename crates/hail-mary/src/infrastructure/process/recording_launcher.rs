@@ -0,0 +1,75 @@
+use std::sync::Mutex;
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::LauncherInterface;
+use crate::domain::value_objects::launch_command::LaunchCommand;
+
+/// Launcher that records commands instead of running them, for tests
+#[derive(Debug, Default)]
+pub struct RecordingLauncher {
+    launches: Mutex<Vec<LaunchCommand>>,
+    failure: Option<String>,
+}
+
+impl RecordingLauncher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A launcher whose launches fail with a process launch error
+    pub fn failing(message: &str) -> Self {
+        Self {
+            launches: Mutex::new(Vec::new()),
+            failure: Some(message.to_string()),
+        }
+    }
+
+    /// All recorded commands, oldest first
+    pub fn launches(&self) -> Vec<LaunchCommand> {
+        self.launches.lock().unwrap().clone()
+    }
+
+    /// The most recent recorded command
+    pub fn last(&self) -> Option<LaunchCommand> {
+        self.launches.lock().unwrap().last().cloned()
+    }
+}
+
+impl LauncherInterface for RecordingLauncher {
+    fn launch(&self, command: &LaunchCommand) -> Result<(), ApplicationError> {
+        self.launches.lock().unwrap().push(command.clone());
+
+        match &self.failure {
+            Some(message) => Err(ApplicationError::ProcessLaunchError(message.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::launch_command::SessionFlags;
+
+    #[test]
+    fn test_records_launches() {
+        let launcher = RecordingLauncher::new();
+        let command = LaunchCommand::claude(None, None, SessionFlags::default());
+
+        launcher.launch(&command).unwrap();
+
+        assert_eq!(launcher.launches(), vec![command.clone()]);
+        assert_eq!(launcher.last(), Some(command));
+    }
+
+    #[test]
+    fn test_failing_launcher_still_records() {
+        let launcher = RecordingLauncher::failing("boom");
+        let command = LaunchCommand::claude(None, None, SessionFlags::default());
+
+        let result = launcher.launch(&command);
+
+        assert!(matches!(result, Err(ApplicationError::ProcessLaunchError(m)) if m == "boom"));
+        assert_eq!(launcher.launches().len(), 1);
+    }
+}
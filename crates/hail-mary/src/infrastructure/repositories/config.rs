@@ -1,11 +1,21 @@
 use crate::application::errors::ApplicationError;
 use crate::application::repositories::ConfigRepositoryInterface;
+use crate::domain::value_objects::agent::AgentConfig;
+use crate::domain::value_objects::config_diagnostic::ConfigFileDiagnostics;
+use crate::domain::value_objects::config_source::{ConfigSource, EffectiveSetting};
+use crate::domain::value_objects::file_change::FileChange;
 use crate::domain::value_objects::steering::{
     Criterion, SpecConfig, SteeringBackupConfig, SteeringConfig, SteeringType,
+    default_include_in_prompt,
 };
+use crate::infrastructure::filesystem::file_writer::FileWriter;
 use crate::infrastructure::filesystem::path_manager::PathManager;
+use crate::infrastructure::repositories::config_layers::LayeredConfig;
+use crate::infrastructure::repositories::config_validation::validate_config_source;
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::{Path, PathBuf};
+use toml_edit::{Array, ArrayOfTables, DocumentMut, Item, Table, value};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct TomlConfig {
@@ -40,6 +50,8 @@ struct SteeringTypeToml {
     criteria: Vec<String>,
     #[serde(default)]
     allowed_operations: Vec<String>,
+    #[serde(default = "default_include_in_prompt")]
+    include_in_prompt: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -54,50 +66,239 @@ fn default_backup_max() -> usize {
 
 pub struct ConfigRepository {
     path_manager: PathManager,
+    user_config_path: Option<PathBuf>,
+    writer: FileWriter,
 }
 
 impl ConfigRepository {
+    /// Project config layered over the user-level config (see [`PathManager::user_config_path`])
     pub fn new(path_manager: PathManager) -> Self {
-        Self { path_manager }
+        Self::with_user_config(path_manager, PathManager::user_config_path())
     }
 
+    /// Use `user_config_path` as the user-level layer, or none at all
+    pub fn with_user_config(path_manager: PathManager, user_config_path: Option<PathBuf>) -> Self {
+        let writer = FileWriter::new(path_manager.project_root().to_path_buf());
+        Self {
+            path_manager,
+            user_config_path,
+            writer,
+        }
+    }
+
+    /// Write through `writer`, e.g. a dry-run writer that only reports changes
+    pub fn with_writer(mut self, writer: FileWriter) -> Self {
+        self.writer = writer;
+        self
+    }
+
+    /// One config file as a table; a missing file is an empty layer
+    fn read_layer(path: &Path) -> Result<Option<toml::Table>, ApplicationError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(path).map_err(|e| {
+            ApplicationError::FileSystemError(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        toml::from_str(&content).map(Some).map_err(|e| {
+            ApplicationError::ConfigurationError(format!(
+                "Failed to parse TOML in {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    fn read_user_layer(&self) -> Result<Option<toml::Table>, ApplicationError> {
+        match &self.user_config_path {
+            Some(path) => Self::read_layer(path),
+            None => Ok(None),
+        }
+    }
+
+    /// Whether the user-level config sets `path`, in which case the `ensure_*` methods
+    /// leave it out of the project file instead of shadowing it with defaults
+    fn user_defines(&self, path: &[&str]) -> Result<bool, ApplicationError> {
+        let Some(user) = self.read_user_layer()? else {
+            return Ok(false);
+        };
+
+        let mut value = user.get(path[0]);
+        for key in &path[1..] {
+            value = value.and_then(|v| v.get(key));
+        }
+        Ok(value.is_some())
+    }
+
+    /// Whether the user-level config has a `[[steering.types]]` entry called `name`
+    fn user_defines_type(&self, name: &str) -> Result<bool, ApplicationError> {
+        let Some(user) = self.read_user_layer()? else {
+            return Ok(false);
+        };
+
+        let types = user
+            .get("steering")
+            .and_then(|steering| steering.get("types"))
+            .and_then(|types| types.as_array());
+        Ok(types.is_some_and(|types| {
+            types
+                .iter()
+                .any(|t| t.get("name").and_then(|n| n.as_str()) == Some(name))
+        }))
+    }
+
+    fn user_type_error(&self, name: &str) -> ApplicationError {
+        let path = self
+            .user_config_path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        ApplicationError::SteeringTypeInUserConfig(name.to_string(), path)
+    }
+
+    fn load_layers(&self) -> Result<LayeredConfig, ApplicationError> {
+        let mut layers = LayeredConfig::default();
+        if let Some(user) = self.read_user_layer()? {
+            layers.apply(ConfigSource::User, user);
+        }
+        if let Some(project) = Self::read_layer(&self.path_manager.config_path(true))? {
+            layers.apply(ConfigSource::Project, project);
+        }
+        Ok(layers)
+    }
+
+    /// The user-level and project configs merged
     fn load_toml(&self) -> Result<toml::Value, ApplicationError> {
+        Ok(toml::Value::Table(self.load_layers()?.to_table()))
+    }
+
+    /// Load config.toml as an editable document that keeps comments and layout
+    fn load_document(&self) -> Result<DocumentMut, ApplicationError> {
         let config_path = self.path_manager.config_path(true);
 
         if !config_path.exists() {
-            return Ok(toml::Value::Table(toml::map::Map::new()));
+            return Ok(DocumentMut::new());
         }
 
         let content = fs::read_to_string(&config_path).map_err(|e| {
             ApplicationError::FileSystemError(format!("Failed to read config file: {}", e))
         })?;
 
-        toml::from_str(&content).map_err(|e| {
+        content.parse().map_err(|e| {
             ApplicationError::ConfigurationError(format!("Failed to parse TOML: {}", e))
         })
     }
 
-    fn save_toml(&self, value: &toml::Value) -> Result<(), ApplicationError> {
+    fn save_document(&self, document: &DocumentMut) -> Result<Vec<FileChange>, ApplicationError> {
         let config_path = self.path_manager.config_path(true);
-        let content = toml::to_string_pretty(value).map_err(|e| {
-            ApplicationError::ConfigurationError(format!("Failed to serialize TOML: {}", e))
-        })?;
 
-        // Ensure parent directory exists
-        if let Some(parent) = config_path.parent() {
-            fs::create_dir_all(parent).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to create config directory: {}",
-                    e
-                ))
-            })?;
+        let mut changes = match config_path.parent() {
+            Some(parent) => self.writer.create_dir_all(parent)?,
+            None => Vec::new(),
+        };
+        changes.extend(self.writer.write(&config_path, &document.to_string())?);
+        Ok(changes)
+    }
+
+    /// The `[steering]` table, created in memory (without a header) when missing
+    fn steering_table_mut(document: &mut DocumentMut) -> Result<&mut Table, ApplicationError> {
+        if !document.contains_key("steering") {
+            let mut steering = Table::new();
+            steering.set_implicit(true);
+            document.insert("steering", Item::Table(steering));
         }
 
-        fs::write(config_path, content).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to write config file: {}", e))
-        })?;
+        document["steering"].as_table_mut().ok_or_else(|| {
+            ApplicationError::ConfigurationError("[steering] must be a table".to_string())
+        })
+    }
 
-        Ok(())
+    /// The `[[steering.types]]` array, created in memory when missing
+    fn steering_types_mut(
+        document: &mut DocumentMut,
+    ) -> Result<&mut ArrayOfTables, ApplicationError> {
+        let steering = Self::steering_table_mut(document)?;
+
+        if !steering.contains_key("types") {
+            steering.insert("types", Item::ArrayOfTables(ArrayOfTables::new()));
+        }
+
+        steering["types"].as_array_of_tables_mut().ok_or_else(|| {
+            ApplicationError::ConfigurationError(
+                "steering.types must be written as [[steering.types]] tables".to_string(),
+            )
+        })
+    }
+
+    /// Add the default steering types when there is no `[steering]` section and the
+    /// user-level config does not provide types, returning whether the document changed
+    fn insert_default_steering(
+        &self,
+        document: &mut DocumentMut,
+    ) -> Result<bool, ApplicationError> {
+        if document.contains_key("steering") {
+            return Ok(false);
+        }
+
+        if self.user_defines(&["steering", "types"])? {
+            return Ok(false);
+        }
+
+        let types = Self::steering_types_mut(document)?;
+        for steering_type in SteeringConfig::default_for_new_project().types {
+            types.push(Self::steering_type_table(&steering_type));
+        }
+        Ok(true)
+    }
+
+    fn type_index(types: &ArrayOfTables, name: &str) -> Option<usize> {
+        types
+            .iter()
+            .position(|table| table.get("name").and_then(|v| v.as_str()) == Some(name))
+    }
+
+    fn steering_type_table(steering_type: &SteeringType) -> Table {
+        let mut table = Table::new();
+        Self::write_steering_type(&mut table, steering_type);
+        table
+    }
+
+    /// Write each field of `steering_type` into `table`, skipping unchanged values
+    /// so their original formatting survives
+    fn write_steering_type(table: &mut Table, steering_type: &SteeringType) {
+        if table.get("name").and_then(|v| v.as_str()) != Some(&steering_type.name) {
+            table["name"] = value(&steering_type.name);
+        }
+        if table.get("purpose").and_then(|v| v.as_str()) != Some(&steering_type.purpose) {
+            table["purpose"] = value(&steering_type.purpose);
+        }
+
+        let criteria: Vec<String> = steering_type
+            .criteria
+            .iter()
+            .map(|c| format!("{}: {}", c.name, c.description))
+            .collect();
+        if string_array(table.get("criteria")) != Some(criteria.clone()) {
+            table["criteria"] = value(multiline_array(&criteria));
+        }
+
+        if string_array(table.get("allowed_operations"))
+            != Some(steering_type.allowed_operations.clone())
+        {
+            let operations: Array = steering_type.allowed_operations.iter().collect();
+            table["allowed_operations"] = value(operations);
+        }
+
+        let include_in_prompt = table.get("include_in_prompt").and_then(|v| v.as_bool());
+        if include_in_prompt.unwrap_or(true) != steering_type.include_in_prompt {
+            table["include_in_prompt"] = value(steering_type.include_in_prompt);
+        }
     }
 
     fn parse_steering_config(steering_section: &SteeringSection) -> SteeringConfig {
@@ -122,6 +323,7 @@ impl ConfigRepository {
                     })
                     .collect(),
                 allowed_operations: t.allowed_operations.clone(),
+                include_in_prompt: t.include_in_prompt,
             })
             .collect();
 
@@ -173,142 +375,67 @@ impl ConfigRepositoryInterface for ConfigRepository {
         }
     }
 
-    fn ensure_steering_config(&self) -> Result<(), ApplicationError> {
-        let mut toml_value = self.load_toml()?;
-        let table = toml_value.as_table_mut().ok_or_else(|| {
-            ApplicationError::ConfigurationError("Invalid TOML structure".to_string())
-        })?;
-
-        // Check if steering section exists
-        if !table.contains_key("steering") {
-            // Add default steering section
-            let default_steering = SteeringConfig::default_for_new_project();
-            let steering_section = SteeringSection {
-                types: default_steering
-                    .types
-                    .iter()
-                    .map(|t| SteeringTypeToml {
-                        name: t.name.clone(),
-                        purpose: t.purpose.clone(),
-                        criteria: t
-                            .criteria
-                            .iter()
-                            .map(|c| format!("{}: {}", c.name, c.description))
-                            .collect(),
-                        allowed_operations: t.allowed_operations.clone(),
-                    })
-                    .collect(),
-                backup: None,
-            };
-
-            let steering_value = toml::Value::try_from(steering_section).map_err(|e| {
-                ApplicationError::ConfigurationError(format!("Failed to serialize steering: {}", e))
-            })?;
+    fn ensure_steering_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        let mut document = self.load_document()?;
 
-            table.insert("steering".to_string(), steering_value);
-            self.save_toml(&toml_value)?;
+        if self.insert_default_steering(&mut document)? {
+            return self.save_document(&document);
         }
 
-        Ok(())
+        Ok(Vec::new())
     }
 
-    fn ensure_steering_backup_config(&self) -> Result<(), ApplicationError> {
-        let mut toml_value = self.load_toml()?;
-        let table = toml_value.as_table_mut().ok_or_else(|| {
-            ApplicationError::ConfigurationError("Invalid TOML structure".to_string())
-        })?;
+    fn ensure_steering_backup_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        let mut document = self.load_document()?;
 
         // Ensure steering section exists first
-        if !table.contains_key("steering") {
-            self.ensure_steering_config()?;
-            toml_value = self.load_toml()?;
-        }
+        let mut modified = self.insert_default_steering(&mut document)?;
 
-        let table = toml_value.as_table_mut().ok_or_else(|| {
-            ApplicationError::ConfigurationError("Invalid TOML structure".to_string())
-        })?;
+        let user_backup = self.user_defines(&["steering", "backup"])?;
+        let steering = Self::steering_table_mut(&mut document)?;
 
-        // Check if backup exists in steering section
-        if let Some(steering) = table.get_mut("steering")
-            && let Some(steering_table) = steering.as_table_mut()
-            && !steering_table.contains_key("backup")
-        {
-            let backup_config = SteeringBackupToml {
-                max: default_backup_max(),
-            };
-            let backup_value = toml::Value::try_from(backup_config).map_err(|e| {
-                ApplicationError::ConfigurationError(format!(
-                    "Failed to serialize backup config: {}",
-                    e
-                ))
-            })?;
-            steering_table.insert("backup".to_string(), backup_value);
-            self.save_toml(&toml_value)?;
+        if !steering.contains_key("backup") && !user_backup {
+            let mut backup = Table::new();
+            backup["max"] = value(default_backup_max() as i64);
+            steering.insert("backup", Item::Table(backup));
+            modified = true;
         }
 
-        Ok(())
+        if modified {
+            return self.save_document(&document);
+        }
+
+        Ok(Vec::new())
     }
 
-    fn ensure_allowed_operations(&self) -> Result<(), ApplicationError> {
-        let mut toml_value = self.load_toml()?;
-        let table = toml_value.as_table_mut().ok_or_else(|| {
-            ApplicationError::ConfigurationError("Invalid TOML structure".to_string())
-        })?;
+    fn ensure_allowed_operations(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        let mut document = self.load_document()?;
 
         // Ensure steering section exists first
-        if !table.contains_key("steering") {
-            self.ensure_steering_config()?;
-            toml_value = self.load_toml()?;
-        }
-
-        let table = toml_value.as_table_mut().ok_or_else(|| {
-            ApplicationError::ConfigurationError("Invalid TOML structure".to_string())
-        })?;
+        let mut modified = self.insert_default_steering(&mut document)?;
 
-        let mut modified = false;
+        for type_table in Self::steering_types_mut(&mut document)?.iter_mut() {
+            // Check if allowed_operations already exists
+            if type_table.contains_key("allowed_operations") {
+                continue;
+            }
 
-        if let Some(steering) = table.get_mut("steering")
-            && let Some(steering_table) = steering.as_table_mut()
-        {
-            // Get the types array
-            if let Some(types) = steering_table.get_mut("types")
-                && let Some(types_array) = types.as_array_mut()
-            {
-                // Iterate through each steering type in the array
-                for type_value in types_array.iter_mut() {
-                    if let Some(type_table) = type_value.as_table_mut() {
-                        // Check if allowed_operations already exists
-                        if !type_table.contains_key("allowed_operations") {
-                            // Get the type name to determine default operations
-                            let type_name = type_table
-                                .get("name")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("");
-
-                            // Add default based on type name
-                            let default_ops = match type_name {
-                                "product" | "tech" | "structure" => {
-                                    vec!["refresh".to_string(), "discover".to_string()]
-                                }
-                                _ => vec![],
-                            };
-
-                            let ops_value = toml::Value::Array(
-                                default_ops.into_iter().map(toml::Value::String).collect(),
-                            );
-                            type_table.insert("allowed_operations".to_string(), ops_value);
-                            modified = true;
-                        }
-                    }
+            // Add default based on type name
+            let default_ops: Array = match type_table.get("name").and_then(|v| v.as_str()) {
+                Some("product" | "tech" | "structure") => {
+                    ["refresh", "discover"].into_iter().collect()
                 }
-            }
+                _ => Array::new(),
+            };
+            type_table.insert("allowed_operations", value(default_ops));
+            modified = true;
         }
 
         if modified {
-            self.save_toml(&toml_value)?;
+            return self.save_document(&document);
         }
 
-        Ok(())
+        Ok(Vec::new())
     }
 
     fn load_spec_config(&self) -> Result<SpecConfig, ApplicationError> {
@@ -327,27 +454,231 @@ impl ConfigRepositoryInterface for ConfigRepository {
         }
     }
 
-    fn ensure_spec_config(&self) -> Result<(), ApplicationError> {
-        let mut toml_value = self.load_toml()?;
-        let table = toml_value.as_table_mut().ok_or_else(|| {
-            ApplicationError::ConfigurationError("Invalid TOML structure".to_string())
-        })?;
+    fn ensure_spec_config(&self) -> Result<Vec<FileChange>, ApplicationError> {
+        let mut document = self.load_document()?;
 
         // Check if spec section exists
-        if !table.contains_key("spec") {
-            // Add default spec section
-            let spec_section = SpecSection {
-                lang: default_spec_lang(),
-            };
+        if !document.contains_key("spec") && !self.user_defines(&["spec"])? {
+            let mut spec = Table::new();
+            spec["lang"] = value(default_spec_lang());
+            document.insert("spec", Item::Table(spec));
+            return self.save_document(&document);
+        }
 
-            let spec_value = toml::Value::try_from(spec_section).map_err(|e| {
-                ApplicationError::ConfigurationError(format!("Failed to serialize spec: {}", e))
-            })?;
+        Ok(Vec::new())
+    }
+
+    fn load_agent_config(&self) -> Result<AgentConfig, ApplicationError> {
+        let toml_value = self.load_toml()?;
+
+        // Parsed on its own so a bad [agent] section is reported instead of
+        // silently falling back to defaults for the whole file
+        match toml_value.get("agent") {
+            Some(agent) => agent.clone().try_into().map_err(|e| {
+                ApplicationError::ConfigurationError(format!("Invalid [agent] section: {}", e))
+            }),
+            None => Ok(AgentConfig::default()),
+        }
+    }
+
+    fn add_steering_type(&self, steering_type: &SteeringType) -> Result<(), ApplicationError> {
+        let mut document = self.load_document()?;
+        let types = Self::steering_types_mut(&mut document)?;
 
-            table.insert("spec".to_string(), spec_value);
-            self.save_toml(&toml_value)?;
+        if Self::type_index(types, &steering_type.name).is_some()
+            || self.user_defines_type(&steering_type.name)?
+        {
+            return Err(ApplicationError::SteeringTypeAlreadyExists(
+                steering_type.name.clone(),
+            ));
         }
 
+        types.push(Self::steering_type_table(steering_type));
+        self.save_document(&document)?;
         Ok(())
     }
+
+    fn update_steering_type(
+        &self,
+        name: &str,
+        steering_type: &SteeringType,
+    ) -> Result<(), ApplicationError> {
+        let user_defined = self.user_defines_type(name)?;
+        let renamed = steering_type.name != name;
+        // Renaming would leave the user-level entry behind under the old name
+        if user_defined && renamed {
+            return Err(self.user_type_error(name));
+        }
+
+        let mut document = self.load_document()?;
+        let types = Self::steering_types_mut(&mut document)?;
+
+        if renamed
+            && (Self::type_index(types, &steering_type.name).is_some()
+                || self.user_defines_type(&steering_type.name)?)
+        {
+            return Err(ApplicationError::SteeringTypeAlreadyExists(
+                steering_type.name.clone(),
+            ));
+        }
+
+        match Self::type_index(types, name) {
+            Some(index) => {
+                if let Some(table) = types.get_mut(index) {
+                    Self::write_steering_type(table, steering_type);
+                }
+            }
+            // Copy the user-level type into the project, where it takes precedence
+            None if user_defined => types.push(Self::steering_type_table(steering_type)),
+            None => return Err(ApplicationError::SteeringTypeNotFound(name.to_string())),
+        }
+        self.save_document(&document)?;
+        Ok(())
+    }
+
+    fn check_steering_type_removable(&self, name: &str) -> Result<(), ApplicationError> {
+        // Removing a project entry would only reveal the user-level one underneath
+        if self.user_defines_type(name)? {
+            return Err(self.user_type_error(name));
+        }
+
+        let mut document = self.load_document()?;
+        let types = Self::steering_types_mut(&mut document)?;
+        match Self::type_index(types, name) {
+            Some(_) => Ok(()),
+            None => Err(ApplicationError::SteeringTypeNotFound(name.to_string())),
+        }
+    }
+
+    fn remove_steering_type(&self, name: &str) -> Result<(), ApplicationError> {
+        self.check_steering_type_removable(name)?;
+
+        let mut document = self.load_document()?;
+        let types = Self::steering_types_mut(&mut document)?;
+
+        let index = Self::type_index(types, name)
+            .ok_or_else(|| ApplicationError::SteeringTypeNotFound(name.to_string()))?;
+        types.remove(index);
+        self.save_document(&document)?;
+        Ok(())
+    }
+
+    fn validate_config(&self) -> Result<Vec<ConfigFileDiagnostics>, ApplicationError> {
+        // (file to read, path to report); project problems are reported relative to
+        // the project root
+        let layers = [
+            self.user_config_path
+                .clone()
+                .map(|path| (path.clone(), path)),
+            Some((
+                self.path_manager.config_path(true),
+                self.path_manager.config_path(false),
+            )),
+        ];
+
+        let mut results = Vec::new();
+        for (config_path, path) in layers.into_iter().flatten() {
+            if !config_path.exists() {
+                continue;
+            }
+
+            let content = fs::read_to_string(&config_path).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to read config file {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+
+            results.push(ConfigFileDiagnostics {
+                path,
+                diagnostics: validate_config_source(&content),
+            });
+        }
+
+        Ok(results)
+    }
+
+    fn load_effective_config(&self) -> Result<Vec<EffectiveSetting>, ApplicationError> {
+        let layers = self.load_layers()?;
+        let spec = self.load_spec_config()?;
+        let steering = self.load_steering_config()?;
+        let agent = self.load_agent_config()?;
+
+        let setting = |path: &[&str], value: toml::Value| EffectiveSetting {
+            key: path.join("."),
+            value: value.to_string(),
+            source: layers.source_of(path).unwrap_or(ConfigSource::Default),
+        };
+        let strings = |values: &[String]| {
+            toml::Value::Array(values.iter().cloned().map(toml::Value::String).collect())
+        };
+
+        let mut settings = vec![
+            setting(&["spec", "lang"], toml::Value::String(spec.lang)),
+            setting(
+                &["steering", "backup", "max"],
+                toml::Value::Integer(steering.backup.max as i64),
+            ),
+        ];
+
+        for steering_type in &steering.types {
+            let name = steering_type.name.as_str();
+            let criteria: Vec<String> = steering_type
+                .criteria
+                .iter()
+                .map(|criterion| criterion.to_string())
+                .collect();
+            settings.extend([
+                setting(
+                    &["steering", "types", name, "purpose"],
+                    toml::Value::String(steering_type.purpose.clone()),
+                ),
+                setting(&["steering", "types", name, "criteria"], strings(&criteria)),
+                setting(
+                    &["steering", "types", name, "allowed_operations"],
+                    strings(&steering_type.allowed_operations),
+                ),
+                setting(
+                    &["steering", "types", name, "include_in_prompt"],
+                    toml::Value::Boolean(steering_type.include_in_prompt),
+                ),
+            ]);
+        }
+
+        settings.push(setting(
+            &["agent", "backend"],
+            toml::Value::String(agent.backend.name().to_string()),
+        ));
+        if let Some(command) = &agent.command {
+            settings.push(setting(
+                &["agent", "command"],
+                toml::Value::String(command.clone()),
+            ));
+        }
+        if !agent.args.is_empty() {
+            settings.push(setting(&["agent", "args"], strings(&agent.args)));
+        }
+
+        Ok(settings)
+    }
+}
+
+fn string_array(item: Option<&Item>) -> Option<Vec<String>> {
+    item?
+        .as_array()?
+        .iter()
+        .map(|v| v.as_str().map(str::to_string))
+        .collect()
+}
+
+/// An array with one element per line, matching how config.toml lists criteria
+fn multiline_array(values: &[String]) -> Array {
+    let mut array: Array = values.iter().collect();
+    for element in array.iter_mut() {
+        element.decor_mut().set_prefix("\n    ");
+    }
+    array.set_trailing_comma(true);
+    array.set_trailing("\n");
+    array
 }
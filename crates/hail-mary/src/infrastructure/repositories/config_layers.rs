@@ -0,0 +1,399 @@
+//! Merging of config layers (user-level file, then project file) with per-key provenance
+//!
+//! Merge rules, applied layer by layer:
+//! - tables merge key by key, the higher layer winning;
+//! - `[[steering.types]]` entries are matched by `name`: a higher layer's entry replaces
+//!   the whole lower entry in place, and new names are appended;
+//! - any other value (including arrays such as `criteria`) replaces the lower value.
+//!
+//! An explicit `types = []` therefore drops the types of lower layers.
+
+use crate::domain::value_objects::config_source::ConfigSource;
+use toml::{Table, Value};
+
+#[derive(Debug, Clone)]
+struct Entry {
+    /// Key segments; `[[steering.types]]` entries are addressed by name as
+    /// `steering.types.<name>.<field>`
+    path: Vec<String>,
+    value: Value,
+    source: ConfigSource,
+}
+
+/// Config layers merged into one list of leaf values
+#[derive(Debug, Clone, Default)]
+pub struct LayeredConfig {
+    entries: Vec<Entry>,
+}
+
+impl LayeredConfig {
+    /// Merge a whole config file over the layers applied so far
+    pub fn apply(&mut self, source: ConfigSource, config: Table) {
+        let mut leaves = Vec::new();
+        flatten(&mut Vec::new(), Value::Table(config), &mut leaves);
+
+        let (types, others): (Vec<_>, Vec<_>) = leaves
+            .into_iter()
+            .partition(|(path, _)| type_name(path).is_some());
+
+        let mut names: Vec<String> = Vec::new();
+        for (path, _) in &types {
+            if let Some(name) = type_name(path)
+                && !names.iter().any(|n| n == name)
+            {
+                names.push(name.to_string());
+            }
+        }
+
+        for name in names {
+            let prefix = type_prefix(&name);
+            let replaced: Vec<Entry> = types
+                .iter()
+                .filter(|(path, _)| path.starts_with(&prefix))
+                .map(|(path, value)| Entry {
+                    path: path.clone(),
+                    value: value.clone(),
+                    source,
+                })
+                .collect();
+
+            self.remove_parents(&prefix);
+            let position = self
+                .entries
+                .iter()
+                .position(|entry| entry.path.starts_with(&prefix));
+            self.entries
+                .retain(|entry| !entry.path.starts_with(&prefix));
+            match position {
+                Some(index) => {
+                    self.entries.splice(index..index, replaced);
+                }
+                None => self.entries.extend(replaced),
+            }
+        }
+
+        for (path, value) in others {
+            self.set(source, path, value);
+        }
+    }
+
+    /// Override a single key, replacing whatever lower layers had at or below it
+    pub fn set(&mut self, source: ConfigSource, path: Vec<String>, value: Value) {
+        // An empty table only matters while nothing else lives under it
+        if is_empty_table(&value) {
+            if self
+                .entries
+                .iter()
+                .any(|entry| entry.path.starts_with(&path))
+            {
+                return;
+            }
+        } else {
+            self.entries
+                .retain(|entry| entry.path == path || !entry.path.starts_with(&path));
+        }
+        self.remove_parents(&path);
+
+        match self.entries.iter_mut().find(|entry| entry.path == path) {
+            Some(entry) => {
+                entry.value = value;
+                entry.source = source;
+            }
+            None => self.entries.push(Entry {
+                path,
+                value,
+                source,
+            }),
+        }
+    }
+
+    /// The layer that set `path`, if any did
+    pub fn source_of(&self, path: &[&str]) -> Option<ConfigSource> {
+        self.entries
+            .iter()
+            .find(|entry| entry.path.iter().eq(path.iter()))
+            .map(|entry| entry.source)
+    }
+
+    /// The merged configuration as one TOML table
+    pub fn to_table(&self) -> Table {
+        let mut root = Table::new();
+        let mut types: Vec<(String, Table)> = Vec::new();
+
+        for entry in &self.entries {
+            let Some(name) = type_name(&entry.path) else {
+                insert_path(&mut root, &entry.path, entry.value.clone());
+                continue;
+            };
+
+            let index = match types.iter().position(|(n, _)| n == name) {
+                Some(index) => index,
+                None => {
+                    types.push((name.to_string(), Table::new()));
+                    types.len() - 1
+                }
+            };
+            if let Some(field) = entry.path.get(3) {
+                types[index].1.insert(field.clone(), entry.value.clone());
+            }
+        }
+
+        if !types.is_empty() {
+            let types = types
+                .into_iter()
+                .map(|(_, table)| Value::Table(table))
+                .collect();
+            insert_path(
+                &mut root,
+                &["steering".to_string(), "types".to_string()],
+                Value::Array(types),
+            );
+        }
+
+        root
+    }
+
+    /// Drop values that sit where `path` needs a table (e.g. `steering = "x"`)
+    fn remove_parents(&mut self, path: &[String]) {
+        self.entries
+            .retain(|entry| entry.path.len() >= path.len() || !path.starts_with(&entry.path));
+    }
+}
+
+fn flatten(path: &mut Vec<String>, value: Value, leaves: &mut Vec<(Vec<String>, Value)>) {
+    match value {
+        Value::Table(table) if !table.is_empty() => {
+            for (key, value) in table {
+                path.push(key);
+                flatten(path, value, leaves);
+                path.pop();
+            }
+        }
+        Value::Array(types) if is_types_path(path) && is_array_of_tables(&types) => {
+            for (index, steering_type) in types.into_iter().enumerate() {
+                let Value::Table(steering_type) = steering_type else {
+                    continue;
+                };
+                // Nameless entries are invalid but must not merge with each other
+                let name = steering_type
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("#{}", index));
+                let prefix = type_prefix(&name);
+
+                if steering_type.is_empty() {
+                    leaves.push((prefix, Value::Table(Table::new())));
+                    continue;
+                }
+                for (field, value) in steering_type {
+                    let mut field_path = prefix.clone();
+                    field_path.push(field);
+                    leaves.push((field_path, value));
+                }
+            }
+        }
+        value => leaves.push((path.clone(), value)),
+    }
+}
+
+fn insert_path(table: &mut Table, path: &[String], value: Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+
+    let mut table = table;
+    for key in parents {
+        let child = table
+            .entry(key.clone())
+            .or_insert_with(|| Value::Table(Table::new()));
+        if !child.is_table() {
+            *child = Value::Table(Table::new());
+        }
+        let Value::Table(child) = child else {
+            return;
+        };
+        table = child;
+    }
+
+    if is_empty_table(&value) {
+        table.entry(last.clone()).or_insert(value);
+    } else {
+        table.insert(last.clone(), value);
+    }
+}
+
+fn type_prefix(name: &str) -> Vec<String> {
+    vec![
+        "steering".to_string(),
+        "types".to_string(),
+        name.to_string(),
+    ]
+}
+
+/// The steering type name if `path` points into a `[[steering.types]]` entry
+fn type_name(path: &[String]) -> Option<&str> {
+    match path {
+        [steering, types, name, ..] if steering == "steering" && types == "types" => {
+            Some(name.as_str())
+        }
+        _ => None,
+    }
+}
+
+fn is_types_path(path: &[String]) -> bool {
+    matches!(path, [steering, types] if steering == "steering" && types == "types")
+}
+
+fn is_array_of_tables(array: &[Value]) -> bool {
+    !array.is_empty() && array.iter().all(Value::is_table)
+}
+
+fn is_empty_table(value: &Value) -> bool {
+    value.as_table().is_some_and(Table::is_empty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layered(user: &str, project: &str) -> LayeredConfig {
+        let mut config = LayeredConfig::default();
+        config.apply(ConfigSource::User, user.parse().unwrap());
+        config.apply(ConfigSource::Project, project.parse().unwrap());
+        config
+    }
+
+    fn type_names(table: &Table) -> Vec<&str> {
+        table["steering"]["types"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap())
+            .collect()
+    }
+
+    const USER: &str = r#"
+[spec]
+lang = "en"
+
+[steering.backup]
+max = 3
+
+[[steering.types]]
+name = "product"
+purpose = "User product"
+criteria = ["Overview: What it is"]
+
+[[steering.types]]
+name = "tech"
+purpose = "User tech"
+criteria = ["Architecture: Design"]
+include_in_prompt = false
+"#;
+
+    #[test]
+    fn test_tables_merge_key_by_key() {
+        let config = layered(
+            USER,
+            "[steering.backup]\nmax = 7\n\n[agent]\nbackend = \"codex\"\n",
+        );
+        let table = config.to_table();
+
+        assert_eq!(table["spec"]["lang"].as_str(), Some("en"));
+        assert_eq!(table["steering"]["backup"]["max"].as_integer(), Some(7));
+        assert_eq!(table["agent"]["backend"].as_str(), Some("codex"));
+        assert_eq!(type_names(&table), vec!["product", "tech"]);
+
+        assert_eq!(
+            config.source_of(&["spec", "lang"]),
+            Some(ConfigSource::User)
+        );
+        assert_eq!(
+            config.source_of(&["steering", "backup", "max"]),
+            Some(ConfigSource::Project)
+        );
+        assert_eq!(config.source_of(&["agent", "command"]), None);
+    }
+
+    #[test]
+    fn test_steering_types_merge_by_name() {
+        let project = r#"
+[[steering.types]]
+name = "security"
+purpose = "Project security"
+criteria = ["Threats: Who attacks"]
+
+[[steering.types]]
+name = "tech"
+purpose = "Project tech"
+criteria = ["Stack: Languages"]
+"#;
+        let config = layered(USER, project);
+        let table = config.to_table();
+
+        // tech keeps its position but is entirely the project's entry
+        assert_eq!(type_names(&table), vec!["product", "tech", "security"]);
+        let tech = &table["steering"]["types"][1];
+        assert_eq!(tech["purpose"].as_str(), Some("Project tech"));
+        assert!(tech.get("include_in_prompt").is_none());
+
+        assert_eq!(
+            config.source_of(&["steering", "types", "product", "purpose"]),
+            Some(ConfigSource::User)
+        );
+        assert_eq!(
+            config.source_of(&["steering", "types", "tech", "criteria"]),
+            Some(ConfigSource::Project)
+        );
+        assert_eq!(
+            config.source_of(&["steering", "types", "tech", "include_in_prompt"]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_empty_types_array_drops_lower_types() {
+        let table = layered(USER, "[steering]\ntypes = []\n").to_table();
+
+        assert_eq!(table["steering"]["types"].as_array().map(Vec::len), Some(0));
+        assert_eq!(table["steering"]["backup"]["max"].as_integer(), Some(3));
+    }
+
+    #[test]
+    fn test_set_overrides_single_keys() {
+        let mut config = layered(USER, "");
+        config.set(
+            ConfigSource::Project,
+            vec![
+                "steering".to_string(),
+                "types".to_string(),
+                "tech".to_string(),
+                "include_in_prompt".to_string(),
+            ],
+            Value::Boolean(true),
+        );
+        config.set(
+            ConfigSource::Project,
+            vec!["spec".to_string()],
+            Value::String("oops".to_string()),
+        );
+        let table = config.to_table();
+
+        let tech = &table["steering"]["types"][1];
+        assert_eq!(tech["purpose"].as_str(), Some("User tech"));
+        assert_eq!(tech["include_in_prompt"].as_bool(), Some(true));
+        assert_eq!(table["spec"].as_str(), Some("oops"));
+    }
+
+    #[test]
+    fn test_empty_layers_merge_to_empty_table() {
+        assert!(layered("", "").to_table().is_empty());
+        assert!(
+            layered("", "[spec]\n").to_table()["spec"]
+                .as_table()
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,503 @@
+//! Validation of `.kiro/config.toml` with source locations
+//!
+//! Loading is lenient (malformed entries fall back to defaults or are skipped), so
+//! this walks the parsed document with its spans and reports every problem instead.
+
+use crate::domain::value_objects::agent::AgentBackend;
+use crate::domain::value_objects::config_diagnostic::ConfigDiagnostic;
+use crate::domain::value_objects::steering::{STEERING_OPERATIONS, SpecConfig, SteeringType};
+use std::collections::HashMap;
+use toml_edit::{Document, Item, TableLike, Value};
+
+/// JSON Schema for `.kiro/config.toml`, for editors such as Taplo / Even Better TOML
+pub const CONFIG_SCHEMA: &str = include_str!("../../../schema/config.schema.json");
+
+const STEERING_TYPE_KEYS: &[&str] = &[
+    "name",
+    "purpose",
+    "criteria",
+    "allowed_operations",
+    "include_in_prompt",
+];
+
+/// Every problem in `source`, in document order
+pub fn validate_config_source(source: &str) -> Vec<ConfigDiagnostic> {
+    let document = match Document::parse(source) {
+        Ok(document) => document,
+        Err(e) => {
+            let offset = e.span().map(|span| span.start).unwrap_or(0);
+            return vec![ConfigDiagnostic::at_offset(
+                source,
+                offset,
+                format!("invalid TOML: {}", e.message().trim()),
+            )];
+        }
+    };
+
+    let mut validator = Validator {
+        source,
+        diagnostics: Vec::new(),
+    };
+    validator.root(document.as_table());
+
+    let mut diagnostics = validator.diagnostics;
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    diagnostics
+}
+
+struct Validator<'a> {
+    source: &'a str,
+    diagnostics: Vec<ConfigDiagnostic>,
+}
+
+impl Validator<'_> {
+    fn report(&mut self, offset: usize, message: impl Into<String>) {
+        self.diagnostics
+            .push(ConfigDiagnostic::at_offset(self.source, offset, message));
+    }
+
+    fn root(&mut self, root: &dyn TableLike) {
+        for (key, item) in root.iter() {
+            let anchor = key_offset(root, key, 0);
+            match key {
+                "spec" => {
+                    if let Some(spec) = self.table(item, anchor, "spec") {
+                        self.spec(spec, anchor);
+                    }
+                }
+                "steering" => {
+                    if let Some(steering) = self.table(item, anchor, "steering") {
+                        self.steering(steering, anchor);
+                    }
+                }
+                "agent" => {
+                    if let Some(agent) = self.table(item, anchor, "agent") {
+                        self.agent(agent, anchor);
+                    }
+                }
+                _ => self.report(anchor, format!("unknown key '{}'", key)),
+            }
+        }
+    }
+
+    fn spec(&mut self, spec: &dyn TableLike, anchor: usize) {
+        for (key, item) in spec.iter() {
+            let offset = key_offset(spec, key, anchor);
+            match key {
+                "lang" => {
+                    if let Some(lang) = self.string(item, offset, "spec.lang")
+                        && let Err(message) = SpecConfig::validate_lang(lang)
+                    {
+                        self.report(
+                            value_offset(item, offset),
+                            format!("spec.lang: {}", message),
+                        );
+                    }
+                }
+                _ => self.report(offset, format!("unknown key 'spec.{}'", key)),
+            }
+        }
+    }
+
+    fn steering(&mut self, steering: &dyn TableLike, anchor: usize) {
+        for (key, item) in steering.iter() {
+            let offset = key_offset(steering, key, anchor);
+            match key {
+                "types" => self.steering_types(item, offset),
+                "backup" => {
+                    if let Some(backup) = self.table(item, offset, "steering.backup") {
+                        self.backup(backup, offset);
+                    }
+                }
+                _ => self.report(offset, format!("unknown key 'steering.{}'", key)),
+            }
+        }
+    }
+
+    fn steering_types(&mut self, item: &Item, anchor: usize) {
+        // Both `[[steering.types]]` and `types = [{ ... }]` are accepted by the loader
+        let types: Vec<(&dyn TableLike, usize)> = match item {
+            Item::ArrayOfTables(tables) => tables
+                .iter()
+                .map(|table| {
+                    let offset = table.span().map(|span| span.start).unwrap_or(anchor);
+                    (table as &dyn TableLike, offset)
+                })
+                .collect(),
+            Item::Value(Value::Array(array)) => {
+                let mut types = Vec::new();
+                for value in array.iter() {
+                    let offset = span_start(value.span(), anchor);
+                    match value.as_inline_table() {
+                        Some(table) => types.push((table as &dyn TableLike, offset)),
+                        None => self.report(
+                            offset,
+                            format!(
+                                "steering.types entries must be tables, found {}",
+                                value.type_name()
+                            ),
+                        ),
+                    }
+                }
+                types
+            }
+            _ => {
+                self.report(
+                    value_offset(item, anchor),
+                    format!(
+                        "steering.types must be an array of tables, found {}",
+                        item.type_name()
+                    ),
+                );
+                return;
+            }
+        };
+
+        let mut first_lines: HashMap<String, usize> = HashMap::new();
+        for (table, offset) in types {
+            if let Some((name, name_offset)) = self.steering_type(table, offset) {
+                let line = ConfigDiagnostic::at_offset(self.source, name_offset, "").line;
+                match first_lines.get(&name) {
+                    Some(first) => self.report(
+                        name_offset,
+                        format!(
+                            "duplicate steering type '{}' (first defined on line {})",
+                            name, first
+                        ),
+                    ),
+                    None => {
+                        first_lines.insert(name, line);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks one `[[steering.types]]` entry and returns its name and location
+    fn steering_type(&mut self, table: &dyn TableLike, anchor: usize) -> Option<(String, usize)> {
+        for required in ["name", "purpose", "criteria"] {
+            if !table.contains_key(required) {
+                self.report(
+                    anchor,
+                    format!("steering type is missing required key '{}'", required),
+                );
+            }
+        }
+
+        let mut name = None;
+        for (key, item) in table.iter() {
+            let offset = key_offset(table, key, anchor);
+            match key {
+                "name" => {
+                    if let Some(value) = self.string(item, offset, "name") {
+                        let value_at = value_offset(item, offset);
+                        if let Err(message) = SteeringType::validate_name(value) {
+                            self.report(value_at, format!("name: {}", message));
+                        }
+                        name = Some((value.to_string(), value_at));
+                    }
+                }
+                "purpose" => {
+                    self.string(item, offset, "purpose");
+                }
+                "criteria" => {
+                    for (criterion, at) in self.strings(item, offset, "criteria") {
+                        if !is_criterion(criterion) {
+                            self.report(
+                                at,
+                                format!(
+                                    "criterion '{}' must be written as \"Name: Description\"",
+                                    criterion
+                                ),
+                            );
+                        }
+                    }
+                }
+                "allowed_operations" => {
+                    for (operation, at) in self.strings(item, offset, "allowed_operations") {
+                        if !STEERING_OPERATIONS.contains(&operation) {
+                            self.report(
+                                at,
+                                format!(
+                                    "unknown operation '{}' (expected one of: {})",
+                                    operation,
+                                    STEERING_OPERATIONS.join(", ")
+                                ),
+                            );
+                        }
+                    }
+                }
+                "include_in_prompt" => {
+                    if !matches!(item.as_value(), Some(Value::Boolean(_))) {
+                        self.mismatch(item, offset, "include_in_prompt", "a boolean");
+                    }
+                }
+                _ => self.report(
+                    offset,
+                    format!(
+                        "unknown key '{}' in steering type (expected one of: {})",
+                        key,
+                        STEERING_TYPE_KEYS.join(", ")
+                    ),
+                ),
+            }
+        }
+
+        name
+    }
+
+    fn backup(&mut self, backup: &dyn TableLike, anchor: usize) {
+        for (key, item) in backup.iter() {
+            let offset = key_offset(backup, key, anchor);
+            match key {
+                "max" => match item.as_integer() {
+                    Some(max) if max >= 0 => {}
+                    Some(_) => self.report(
+                        value_offset(item, offset),
+                        "steering.backup.max must not be negative",
+                    ),
+                    None => self.mismatch(item, offset, "steering.backup.max", "an integer"),
+                },
+                _ => self.report(offset, format!("unknown key 'steering.backup.{}'", key)),
+            }
+        }
+    }
+
+    fn agent(&mut self, agent: &dyn TableLike, anchor: usize) {
+        for (key, item) in agent.iter() {
+            let offset = key_offset(agent, key, anchor);
+            match key {
+                "backend" => {
+                    if let Some(backend) = self.string(item, offset, "agent.backend")
+                        && !AgentBackend::ALL.iter().any(|b| b.name() == backend)
+                    {
+                        let names: Vec<_> = AgentBackend::ALL.iter().map(|b| b.name()).collect();
+                        self.report(
+                            value_offset(item, offset),
+                            format!(
+                                "unknown agent backend '{}' (expected one of: {})",
+                                backend,
+                                names.join(", ")
+                            ),
+                        );
+                    }
+                }
+                "command" => {
+                    self.string(item, offset, "agent.command");
+                }
+                "args" => {
+                    self.strings(item, offset, "agent.args");
+                }
+                _ => self.report(offset, format!("unknown key 'agent.{}'", key)),
+            }
+        }
+    }
+
+    fn table<'i>(
+        &mut self,
+        item: &'i Item,
+        anchor: usize,
+        path: &str,
+    ) -> Option<&'i dyn TableLike> {
+        let table = item.as_table_like();
+        if table.is_none() {
+            self.mismatch(item, anchor, path, "a table");
+        }
+        table
+    }
+
+    fn string<'i>(&mut self, item: &'i Item, anchor: usize, path: &str) -> Option<&'i str> {
+        let string = item.as_str();
+        if string.is_none() {
+            self.mismatch(item, anchor, path, "a string");
+        }
+        string
+    }
+
+    /// The elements of a string array with their locations; other elements are reported
+    fn strings<'i>(&mut self, item: &'i Item, anchor: usize, path: &str) -> Vec<(&'i str, usize)> {
+        let Some(array) = item.as_array() else {
+            self.mismatch(item, anchor, path, "an array of strings");
+            return Vec::new();
+        };
+
+        let mut strings = Vec::new();
+        for value in array.iter() {
+            let offset = span_start(value.span(), anchor);
+            match value.as_str() {
+                Some(string) => strings.push((string, offset)),
+                None => self.report(
+                    offset,
+                    format!("{} must contain strings, found {}", path, value.type_name()),
+                ),
+            }
+        }
+        strings
+    }
+
+    fn mismatch(&mut self, item: &Item, anchor: usize, path: &str, expected: &str) {
+        self.report(
+            value_offset(item, anchor),
+            format!("{} must be {}, found {}", path, expected, item.type_name()),
+        );
+    }
+}
+
+/// Same rule the loader applies: a name, then ": ", then the description
+fn is_criterion(criterion: &str) -> bool {
+    criterion
+        .split_once(": ")
+        .is_some_and(|(name, _)| !name.trim().is_empty())
+}
+
+fn key_offset(table: &dyn TableLike, key: &str, fallback: usize) -> usize {
+    span_start(table.key(key).and_then(|key| key.span()), fallback)
+}
+
+fn value_offset(item: &Item, fallback: usize) -> usize {
+    span_start(item.span(), fallback)
+}
+
+fn span_start(span: Option<std::ops::Range<usize>>, fallback: usize) -> usize {
+    span.map(|span| span.start).unwrap_or(fallback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(source: &str) -> Vec<String> {
+        validate_config_source(source)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_config_has_no_diagnostics() {
+        let source = r#"
+[spec]
+lang = "en"
+
+[agent]
+backend = "codex"
+args = ["--model", "o3"]
+
+[[steering.types]]
+name = "tech"
+purpose = "Technical stack"
+criteria = ["Architecture: High-level design"]
+allowed_operations = ["refresh", "discover"]
+include_in_prompt = false
+
+[steering.backup]
+max = 5
+"#;
+        assert!(messages(source).is_empty());
+        assert!(messages("").is_empty());
+    }
+
+    #[test]
+    fn test_syntax_error_is_located() {
+        assert_eq!(
+            validate_config_source("[spec]\nlang = \n")
+                .iter()
+                .map(|d| (d.line, d.column))
+                .collect::<Vec<_>>(),
+            vec![(2, 8)]
+        );
+    }
+
+    #[test]
+    fn test_reports_every_steering_problem() {
+        let source = r#"[[steering.types]]
+name = "tech"
+purpose = "Technical stack"
+criteria = ["Architecture: Design", "Commands"]
+allowed_operations = ["refresh", "rewrite"]
+
+[[steering.types]]
+name = "tech"
+purpose = "Again"
+criteria = []
+include_in_prompt = "yes"
+
+[[steering.types]]
+name = "Bad Name"
+"#;
+        assert_eq!(
+            messages(source),
+            vec![
+                "4:37: criterion 'Commands' must be written as \"Name: Description\"",
+                "5:34: unknown operation 'rewrite' (expected one of: refresh, discover)",
+                "8:8: duplicate steering type 'tech' (first defined on line 2)",
+                "11:21: include_in_prompt must be a boolean, found string",
+                "13:1: steering type is missing required key 'purpose'",
+                "13:1: steering type is missing required key 'criteria'",
+                "14:8: name: 'Bad Name' must be kebab-case (lowercase letters, digits and hyphens)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reports_spec_agent_and_unknown_keys() {
+        let source = r#"lang = "ja"
+
+[spec]
+lang = "Japanese"
+
+[agent]
+backend = "copilot"
+args = "--fast"
+
+[steering]
+backup = { max = -1 }
+"#;
+        assert_eq!(
+            messages(source),
+            vec![
+                "1:1: unknown key 'lang'",
+                "4:8: spec.lang: 'Japanese' is not a language tag (e.g. \"ja\", \"en\", \"pt-BR\")",
+                "7:11: unknown agent backend 'copilot' (expected one of: claude, opencode, codex, gemini)",
+                "8:8: agent.args must be an array of strings, found string",
+                "11:18: steering.backup.max must not be negative",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inline_steering_types_are_checked() {
+        let source =
+            "[steering]\ntypes = [{ name = \"tech\", purpose = \"p\", criteria = [\"x\"] }, 3]\n";
+        assert_eq!(
+            messages(source),
+            vec![
+                "2:54: criterion 'x' must be written as \"Name: Description\"",
+                "2:62: steering.types entries must be tables, found integer",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schema_lists_known_values() {
+        let schema: serde_json::Value = serde_json::from_str(CONFIG_SCHEMA).unwrap();
+        let steering_type = &schema["$defs"]["steeringType"]["properties"];
+
+        let operations: Vec<_> = steering_type["allowed_operations"]["items"]["enum"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect();
+        assert_eq!(operations, STEERING_OPERATIONS);
+
+        let backends: Vec<_> = schema["properties"]["agent"]["properties"]["backend"]["enum"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect();
+        let expected: Vec<_> = AgentBackend::ALL.iter().map(|b| b.name()).collect();
+        assert_eq!(backends, expected);
+    }
+}
@@ -1,4 +1,6 @@
 pub mod config;
+pub mod config_layers;
+pub mod config_validation;
 pub mod spec;
 pub mod steering;
 
@@ -1,8 +1,12 @@
 use crate::application::errors::ApplicationError;
-use crate::application::repositories::SpecRepositoryInterface;
+use crate::application::repositories::{
+    CollisionPolicy, RestoredSpec, SpecFileInfo, SpecRepositoryInterface,
+};
+use crate::domain::value_objects::spec_template::{SpecTemplateSet, SpecTemplateVars};
 use crate::infrastructure::filesystem::path_manager::PathManager;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 pub struct SpecRepository {
     path_manager: PathManager,
@@ -27,55 +31,92 @@ impl SpecRepository {
         Ok(())
     }
 
-    fn create_template_files(
+    /// Templates of `set`: the built-in documents overlaid with `.kiro/templates/<set>/`
+    fn load_templates(
         &self,
-        spec_dir: &std::path::Path,
-        name: &str,
-        lang: &str,
-    ) -> Result<(), ApplicationError> {
-        // Create essential template files: tasks.md and memo.md
-
-        let memo_content = format!(
-            r#"# Memo: {}
-
-"#,
-            name
-        );
+        set: SpecTemplateSet,
+    ) -> Result<Vec<(String, String)>, ApplicationError> {
+        let mut templates: Vec<(String, String)> = set
+            .builtin()
+            .iter()
+            .map(|(name, content)| (name.to_string(), content.to_string()))
+            .collect();
+
+        let templates_dir = self.path_manager.templates_dir(true).join(set.dir_name());
+        if !templates_dir.is_dir() {
+            return Ok(templates);
+        }
 
-        // Create tasks.md with initial State Tracking and Timeline
-        let tasks_content = format!(
-            r#"# Tasks
+        let entries = fs::read_dir(&templates_dir).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to read templates directory: {}", e))
+        })?;
 
-**Language**: {}
+        let mut custom = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| {
+                ApplicationError::FileSystemError(format!("Failed to read directory entry: {}", e))
+            })?;
+            let file_type = entry.file_type().map_err(|e| {
+                ApplicationError::FileSystemError(format!("Failed to get file type: {}", e))
+            })?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
 
-## State Tracking
+            // Skip subdirectories and dotfiles such as .gitkeep
+            if !file_type.is_file() || file_name.starts_with('.') {
+                continue;
+            }
 
-| Document | Status | Coverage | Next Action |
-|----------|--------|----------|-------------|
-| requirements.md | pending | - | Define requirements |
-| tasks.md#Timeline | pending | 0% | Plan implementation order |
+            let content = fs::read_to_string(entry.path()).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to read template {}: {}",
+                    file_name, e
+                ))
+            })?;
+            custom.push((file_name, content));
+        }
+        custom.sort();
 
-## Timeline
+        for (file_name, content) in custom {
+            match templates.iter_mut().find(|(name, _)| *name == file_name) {
+                Some(template) => template.1 = content,
+                None => templates.push((file_name, content)),
+            }
+        }
 
-- [x] Spec created → {}
-- [ ] Requirements definition
-- [ ] Implementation planning
-"#,
-            lang, name
-        );
+        Ok(templates)
+    }
 
-        fs::write(spec_dir.join("memo.md"), memo_content).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to write memo.md: {}", e))
-        })?;
+    /// Render the template set into `spec_dir`, leaving existing documents untouched
+    fn create_template_files(
+        &self,
+        spec_dir: &Path,
+        set: SpecTemplateSet,
+        vars: &SpecTemplateVars,
+    ) -> Result<(), ApplicationError> {
+        for (file_name, template) in self.load_templates(set)? {
+            let path = spec_dir.join(&file_name);
+            if path.exists() {
+                continue;
+            }
 
-        fs::write(spec_dir.join("tasks.md"), tasks_content).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to write tasks.md: {}", e))
-        })?;
+            fs::write(&path, vars.render(&template)).map_err(|e| {
+                ApplicationError::FileSystemError(format!("Failed to write {}: {}", file_name, e))
+            })?;
+        }
 
         Ok(())
     }
 }
 
+fn template_vars(name: &str, lang: &str, parent: &str) -> SpecTemplateVars {
+    SpecTemplateVars {
+        name: name.to_string(),
+        date: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+        lang: lang.to_string(),
+        parent: parent.to_string(),
+    }
+}
+
 impl SpecRepositoryInterface for SpecRepository {
     fn create_spec(&self, name: &str, lang: &str) -> Result<(), ApplicationError> {
         // Validate spec name
@@ -103,7 +144,11 @@ impl SpecRepositoryInterface for SpecRepository {
         })?;
 
         // Create template files
-        self.create_template_files(&spec_dir, name, lang)?;
+        self.create_template_files(
+            &spec_dir,
+            SpecTemplateSet::Spec,
+            &template_vars(name, lang, ""),
+        )?;
 
         Ok(())
     }
@@ -141,7 +186,11 @@ impl SpecRepositoryInterface for SpecRepository {
         Ok(specs)
     }
 
-    fn mark_spec_complete(&self, name: &str) -> Result<(), ApplicationError> {
+    fn mark_spec_complete(
+        &self,
+        name: &str,
+        policy: CollisionPolicy,
+    ) -> Result<String, ApplicationError> {
         let source_path = self.path_manager.specs_dir(true).join(name);
 
         if !source_path.exists() {
@@ -158,26 +207,141 @@ impl SpecRepositoryInterface for SpecRepository {
             ApplicationError::FileSystemError(format!("Failed to create archive directory: {}", e))
         })?;
 
-        let dest_path = archive_dir.join(name);
+        let dest_name = resolve_destination(&archive_dir, name, policy)
+            .ok_or_else(|| ApplicationError::ArchiveAlreadyExists(name.to_string()))?;
 
-        // If already exists in archive, remove it first to allow overwriting
-        if dest_path.exists() {
-            fs::remove_dir_all(&dest_path).map_err(|e| {
+        // Move directory to archive
+        move_directory(&source_path, &archive_dir.join(&dest_name)).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to move spec to archive: {}", e))
+        })?;
+
+        Ok(dest_name)
+    }
+
+    fn unarchive_spec(
+        &self,
+        name: &str,
+        policy: CollisionPolicy,
+    ) -> Result<RestoredSpec, ApplicationError> {
+        let archive_dir = self.path_manager.archive_dir(true);
+        let source_path = archive_dir.join(name);
+
+        if !source_path.exists() {
+            return Err(ApplicationError::SpecNotFound(name.to_string()));
+        }
+
+        if !source_path.is_dir() {
+            return Err(ApplicationError::InvalidSpecDirectory(name.to_string()));
+        }
+
+        let specs_dir = self.path_manager.specs_dir(true);
+        fs::create_dir_all(&specs_dir).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to create specs directory: {}", e))
+        })?;
+
+        let dest_name = resolve_destination(&specs_dir, name, policy)
+            .ok_or_else(|| ApplicationError::SpecAlreadyExists(name.to_string()))?;
+        let dest_path = specs_dir.join(&dest_name);
+
+        // Never delete active work: the spec being replaced goes to the archive,
+        // under a numbered name since the archived one still holds `name`
+        let replaced = if dest_path.exists() {
+            let replaced_name = resolve_destination(&archive_dir, name, CollisionPolicy::KeepBoth)
+                .ok_or_else(|| ApplicationError::ArchiveAlreadyExists(name.to_string()))?;
+            fs::rename(&dest_path, archive_dir.join(&replaced_name)).map_err(|e| {
                 ApplicationError::FileSystemError(format!(
-                    "Failed to remove existing archive: {}",
+                    "Failed to move replaced spec to archive: {}",
                     e
                 ))
             })?;
+            Some(replaced_name)
+        } else {
+            None
+        };
+
+        fs::rename(&source_path, &dest_path).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to restore spec from archive: {}", e))
+        })?;
+
+        Ok(RestoredSpec {
+            name: dest_name,
+            replaced,
+        })
+    }
+
+    fn rename_spec(&self, name: &str, new_name: &str) -> Result<(), ApplicationError> {
+        let source_path = self.get_spec_path(name)?;
+        let dest_path = self.path_manager.specs_dir(true).join(new_name);
+
+        if dest_path.exists() {
+            return Err(ApplicationError::SpecAlreadyExists(new_name.to_string()));
         }
 
-        // Move directory to archive
         fs::rename(&source_path, &dest_path).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to move spec to archive: {}", e))
+            ApplicationError::FileSystemError(format!("Failed to rename spec: {}", e))
         })?;
 
         Ok(())
     }
 
+    fn delete_spec(&self, name: &str) -> Result<(), ApplicationError> {
+        let spec_path = self.get_spec_path(name)?;
+
+        fs::remove_dir_all(&spec_path).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to delete spec: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    fn list_spec_files(&self, name: &str) -> Result<Vec<SpecFileInfo>, ApplicationError> {
+        let spec_path = self.get_spec_path(name)?;
+        let mut files = Vec::new();
+
+        let entries = fs::read_dir(&spec_path).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to read spec directory: {}", e))
+        })?;
+
+        for entry in entries {
+            let entry = entry.map_err(|e| {
+                ApplicationError::FileSystemError(format!("Failed to read directory entry: {}", e))
+            })?;
+
+            let metadata = entry.metadata().map_err(|e| {
+                ApplicationError::FileSystemError(format!("Failed to get metadata: {}", e))
+            })?;
+
+            if metadata.is_file() {
+                files.push(SpecFileInfo {
+                    name: entry.file_name().to_string_lossy().to_string(),
+                    size: metadata.len(),
+                    modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                });
+            }
+        }
+
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(files)
+    }
+
+    fn read_spec_file(
+        &self,
+        name: &str,
+        file_name: &str,
+    ) -> Result<Option<String>, ApplicationError> {
+        let file_path = self.get_spec_path(name)?.join(file_name);
+
+        if !file_path.is_file() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&file_path).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to read {}: {}", file_name, e))
+        })?;
+
+        Ok(Some(content))
+    }
+
     fn get_spec_path(&self, name: &str) -> Result<PathBuf, ApplicationError> {
         let specs_dir = self.path_manager.specs_dir(true);
         let spec_path = specs_dir.join(name);
@@ -289,9 +453,13 @@ impl SpecRepositoryInterface for SpecRepository {
             ApplicationError::FileSystemError(format!("Failed to create SBI directory: {}", e))
         })?;
 
-        // Reuse create_template_files to generate tasks.md and memo.md
+        // Generate the SBI template set (tasks.md and memo.md unless the project adds more)
         // Note: requirements.md is NOT generated here - created by /decompose or /add-sbi slash commands
-        self.create_template_files(&sbi_path, sbi_name, lang)?;
+        self.create_template_files(
+            &sbi_path,
+            SpecTemplateSet::Sbi,
+            &template_vars(sbi_name, lang, pbi_name),
+        )?;
 
         Ok(())
     }
@@ -313,14 +481,35 @@ impl SpecRepositoryInterface for SpecRepository {
             return Err(ApplicationError::SpecNotFound(sbi_name.to_string()));
         }
 
-        let tasks_path = sbi_path.join("tasks.md");
-        let memo_path = sbi_path.join("memo.md");
+        // Generate whichever template files are missing; existing ones are kept
+        self.create_template_files(
+            &sbi_path,
+            SpecTemplateSet::Sbi,
+            &template_vars(sbi_name, lang, pbi_name),
+        )
+    }
+}
 
-        // Only generate missing files
-        if !tasks_path.exists() || !memo_path.exists() {
-            self.create_template_files(&sbi_path, sbi_name, lang)?;
-        }
+/// Picks the destination name for `name` inside `dest_dir` according to the
+/// collision policy. Returns None when the policy refuses the move.
+fn resolve_destination(dest_dir: &Path, name: &str, policy: CollisionPolicy) -> Option<String> {
+    if !dest_dir.join(name).exists() {
+        return Some(name.to_string());
+    }
 
-        Ok(())
+    match policy {
+        CollisionPolicy::Fail => None,
+        CollisionPolicy::Overwrite => Some(name.to_string()),
+        CollisionPolicy::KeepBoth => (2..)
+            .map(|n| format!("{}-{}", name, n))
+            .find(|candidate| !dest_dir.join(candidate).exists()),
+    }
+}
+
+/// Moves a directory, replacing the destination if it already exists
+fn move_directory(source: &Path, dest: &Path) -> std::io::Result<()> {
+    if dest.exists() {
+        fs::remove_dir_all(dest)?;
     }
+    fs::rename(source, dest)
 }
@@ -1,71 +1,205 @@
 use crate::application::errors::ApplicationError;
 use crate::application::repositories::steering_repository::{
-    BackupInfo, SteeringRepositoryInterface,
+    BackupInfo, SteeringFileScaffold, SteeringRepositoryInterface,
 };
+use crate::domain::value_objects::deployment::{
+    DeployAction, DeployedFile, DeploymentManifest, DeploymentReport, DeploymentWarning,
+    DesiredFile, DesiredSkill,
+};
+use crate::domain::value_objects::file_change::FileChange;
+use crate::domain::value_objects::slash_command::SlashCommand;
 use crate::domain::value_objects::steering::{Steering, SteeringConfig};
+use crate::domain::value_objects::steering_backup::{BackupManifest, BackupReason, content_hash};
+use crate::infrastructure::filesystem::backup_store::BackupStore;
+use crate::infrastructure::filesystem::file_writer::FileWriter;
 use crate::infrastructure::filesystem::path_manager::PathManager;
 use std::fs;
-use std::path::PathBuf;
-use std::time::SystemTime;
+use std::path::{Path, PathBuf};
+
+/// Entries hail-mary needs in the project's `.gitignore`; none since steering
+/// moved from a database to plain files
+const GITIGNORE_ENTRIES: &[&str] = &[];
+
+/// Where the slash commands are deployed, relative to the project root
+const SLASH_COMMANDS_DIR: &str = ".claude/commands/hm";
+
+/// Where the embedded skills are deployed, one directory per skill
+const SKILLS_DIR: &str = ".claude/skills";
+
+/// Command directories deployed by versions before the deployment manifest
+const LEGACY_COMMAND_DIRS: &[&str] = &[".claude/commands/pbi", ".claude/commands/spec"];
 
 pub struct SteeringRepository {
     path_manager: PathManager,
+    writer: FileWriter,
 }
 
 impl SteeringRepository {
     pub fn new(path_manager: PathManager) -> Self {
-        Self { path_manager }
+        let writer = FileWriter::new(path_manager.project_root().to_path_buf());
+        Self {
+            path_manager,
+            writer,
+        }
+    }
+
+    /// Write through `writer`, e.g. a dry-run writer that only reports changes
+    pub fn with_writer(mut self, writer: FileWriter) -> Self {
+        self.writer = writer;
+        self
     }
 
     fn steering_dir(&self) -> PathBuf {
         self.path_manager.kiro_dir(true).join("steering")
     }
 
-    fn backup_dir(&self) -> PathBuf {
-        self.steering_dir().join("backup")
+    fn load_deployment_manifest(&self) -> Result<Option<DeploymentManifest>, ApplicationError> {
+        let manifest_path = self.path_manager.deployment_manifest_path(true);
+        if !manifest_path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&manifest_path).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to read deployment manifest: {}", e))
+        })?;
+        serde_json::from_str(&content).map(Some).map_err(|e| {
+            ApplicationError::FileSystemError(format!(
+                "Failed to parse {}: {}",
+                self.path_manager.deployment_manifest_path(false).display(),
+                e
+            ))
+        })
     }
+
+    /// Manifest for projects deployed before manifests existed. Those versions
+    /// wiped and rewrote `.claude/commands/hm` on every run, so a command file
+    /// hail-mary ships is adopted with its current content, whichever release
+    /// wrote it, and updated like any managed file. Other files are left alone.
+    fn adopt_legacy_slash_commands(&self) -> Result<DeploymentManifest, ApplicationError> {
+        use crate::infrastructure::embedded_resources::EmbeddedSlashCommands;
+
+        let hm_dir = PathBuf::from(SLASH_COMMANDS_DIR);
+        let project_root = self.path_manager.project_root();
+        let mut manifest = DeploymentManifest::default();
+        for (file_name, _) in EmbeddedSlashCommands::get_all() {
+            let path = hm_dir.join(file_name);
+            let full_path = project_root.join(&path);
+            if !full_path.is_file() {
+                continue;
+            }
+            let content = fs::read(&full_path).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+            manifest.files.push(DeployedFile {
+                path,
+                sha256: content_hash(&content),
+            });
+        }
+
+        Ok(manifest)
+    }
+
+    /// Markdown files in `.kiro/commands/` as (file name, content) pairs
+    fn load_command_overlay(&self) -> Result<Vec<(String, String)>, ApplicationError> {
+        let commands_dir = self.path_manager.commands_dir(true);
+        if !commands_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&commands_dir).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to read commands directory: {}", e))
+        })?;
+
+        let mut overlay = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| {
+                ApplicationError::FileSystemError(format!("Failed to read directory entry: {}", e))
+            })?;
+            let file_type = entry.file_type().map_err(|e| {
+                ApplicationError::FileSystemError(format!("Failed to get file type: {}", e))
+            })?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            // Claude Code only picks up markdown; skip subdirectories and dotfiles
+            if !file_type.is_file() || file_name.starts_with('.') || !file_name.ends_with(".md") {
+                continue;
+            }
+
+            let content = fs::read_to_string(entry.path()).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to read command {}: {}",
+                    file_name, e
+                ))
+            })?;
+            overlay.push((file_name, content));
+        }
+        overlay.sort();
+
+        Ok(overlay)
+    }
+
+    fn backup_store(&self) -> BackupStore {
+        BackupStore::new(self.steering_dir().join("backup"))
+    }
+}
+
+/// File name of a steering type inside `.kiro/steering`. Names come from config
+/// files and only new ones must be kebab-case, but none may leave the directory.
+fn steering_file_name(name: &str) -> Result<PathBuf, ApplicationError> {
+    if name.is_empty() || name.starts_with('.') || name.contains(['/', '\\']) {
+        return Err(ApplicationError::InvalidSteeringType(format!(
+            "'{}' cannot be used as a steering file name",
+            name
+        )));
+    }
+    Ok(PathBuf::from(format!("{}.md", name)))
 }
 
 impl SteeringRepositoryInterface for SteeringRepository {
-    fn initialize_steering(&self) -> Result<(), ApplicationError> {
+    fn initialize_steering(&self) -> Result<Vec<FileChange>, ApplicationError> {
         // Create .kiro/steering directory
-        let steering_dir = self.steering_dir();
-        fs::create_dir_all(&steering_dir).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to create steering directory: {}", e))
-        })?;
+        let mut changes = self.writer.create_dir_all(&self.steering_dir())?;
 
         // Create .kiro/specs directory during initialization
-        let specs_dir = self.path_manager.specs_dir(true);
-        fs::create_dir_all(&specs_dir).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to create specs directory: {}", e))
-        })?;
+        changes.extend(
+            self.writer
+                .create_dir_all(&self.path_manager.specs_dir(true))?,
+        );
 
-        Ok(())
+        Ok(changes)
     }
 
-    fn create_steering_files(&self, config: &SteeringConfig) -> Result<(), ApplicationError> {
+    fn create_steering_files(
+        &self,
+        config: &SteeringConfig,
+    ) -> Result<Vec<SteeringFileScaffold>, ApplicationError> {
+        let mut scaffolds = Vec::new();
+
         for steering_type in &config.types {
-            let file_path = self
-                .steering_dir()
-                .join(format!("{}.md", steering_type.name));
+            let file = steering_file_name(&steering_type.name)?;
+            let file_path = self.steering_dir().join(&file);
 
             // Never overwrite existing files
             if file_path.exists() {
+                scaffolds.push(SteeringFileScaffold {
+                    file,
+                    created: false,
+                });
                 continue;
             }
 
-            // Generate simple markdown header only
-            let content = format!("# {}\n\n", steering_type.name);
-
-            fs::write(file_path, content).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to create steering file for {}: {}",
-                    steering_type.name, e
-                ))
-            })?;
+            self.writer.write(&file_path, &steering_type.skeleton())?;
+            scaffolds.push(SteeringFileScaffold {
+                file,
+                created: true,
+            });
         }
 
-        Ok(())
+        Ok(scaffolds)
     }
 
     fn list_steering_files(&self) -> Result<Vec<PathBuf>, ApplicationError> {
@@ -105,179 +239,216 @@ impl SteeringRepositoryInterface for SteeringRepository {
     }
 
     fn get_steering_path(&self, name: &str) -> Result<PathBuf, ApplicationError> {
-        let path = self.steering_dir().join(format!("{}.md", name));
-        Ok(path)
+        Ok(self.steering_dir().join(steering_file_name(name)?))
     }
 
     fn create_steering_backup(
         &self,
-        timestamp: &str,
+        name: &str,
         files: &[PathBuf],
-    ) -> Result<(), ApplicationError> {
+        reason: BackupReason,
+    ) -> Result<BackupManifest, ApplicationError> {
         let steering_dir = self.steering_dir();
-        let backup_dir = self.backup_dir().join(timestamp);
-
-        // Create backup directory
-        fs::create_dir_all(&backup_dir).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to create backup directory: {}", e))
-        })?;
 
-        // Copy each file to backup
+        let mut contents = Vec::new();
         for file in files {
             let source = steering_dir.join(file);
-            let dest = backup_dir.join(file);
-
-            if source.exists() {
-                fs::copy(&source, &dest).map_err(|e| {
-                    ApplicationError::FileSystemError(format!(
-                        "Failed to backup file {}: {}",
-                        file.display(),
-                        e
-                    ))
-                })?;
+            if !source.exists() {
+                continue;
             }
+
+            let content = fs::read(&source).map_err(|e| {
+                ApplicationError::FileSystemError(format!(
+                    "Failed to backup file {}: {}",
+                    file.display(),
+                    e
+                ))
+            })?;
+            contents.push((file.clone(), content));
         }
 
-        Ok(())
+        self.backup_store().create(name, &contents, reason)
+    }
+
+    fn load_backup_manifest(
+        &self,
+        backup: &str,
+    ) -> Result<Option<BackupManifest>, ApplicationError> {
+        self.backup_store().manifest(backup)
     }
 
     fn list_steering_backups(&self) -> Result<Vec<BackupInfo>, ApplicationError> {
-        let backup_dir = self.backup_dir();
+        self.backup_store().list()
+    }
 
-        if !backup_dir.exists() {
-            return Ok(Vec::new());
+    fn delete_oldest_steering_backups(&self, count: usize) -> Result<(), ApplicationError> {
+        let store = self.backup_store();
+
+        // Take the oldest 'count' backups
+        for backup in store.list()?.iter().take(count) {
+            store.delete(backup)?;
         }
 
-        let mut backups = Vec::new();
-        let entries = fs::read_dir(&backup_dir).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to read backup directory: {}", e))
-        })?;
+        // Drop objects only the deleted backups referenced
+        store.collect_garbage()?;
+        Ok(())
+    }
 
-        for entry in entries {
-            let entry = entry.map_err(|e| {
-                ApplicationError::FileSystemError(format!("Failed to read directory entry: {}", e))
-            })?;
+    fn list_backup_files(&self, backup: &str) -> Result<Vec<PathBuf>, ApplicationError> {
+        self.backup_store().files(backup)
+    }
 
-            if entry
-                .file_type()
-                .map_err(|e| {
-                    ApplicationError::FileSystemError(format!("Failed to get file type: {}", e))
-                })?
-                .is_dir()
-            {
-                let metadata = entry.metadata().map_err(|e| {
-                    ApplicationError::FileSystemError(format!("Failed to get metadata: {}", e))
-                })?;
-
-                // Use modified time as it's more reliable across platforms
-                // This is just for the BackupInfo struct, actual sorting will be by name
-                let created_at = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
-
-                backups.push(BackupInfo {
-                    name: entry.file_name().to_string_lossy().to_string(),
-                    created_at,
-                    path: entry.path(),
-                });
-            }
+    fn read_backup_file(&self, backup: &str, file: &Path) -> Result<String, ApplicationError> {
+        self.backup_store().read(backup, file)
+    }
+
+    fn read_steering_file(&self, file: &Path) -> Result<Option<String>, ApplicationError> {
+        let path = self.steering_dir().join(file);
+
+        if !path.is_file() {
+            return Ok(None);
         }
 
-        // Sort by backup name (which contains timestamp) for consistent ordering
-        // This ensures tests work reliably across different filesystems
-        // Format is typically: YYYY-MM-DD-HH-MM
-        backups.sort_by(|a, b| a.name.cmp(&b.name));
-        Ok(backups)
+        fs::read_to_string(&path).map(Some).map_err(|e| {
+            ApplicationError::FileSystemError(format!(
+                "Failed to read steering file {}: {}",
+                file.display(),
+                e
+            ))
+        })
+    }
+
+    fn write_steering_file(&self, file: &Path, content: &str) -> Result<(), ApplicationError> {
+        let steering_dir = self.steering_dir();
+        fs::create_dir_all(&steering_dir).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to create steering directory: {}", e))
+        })?;
+
+        fs::write(steering_dir.join(file), content).map_err(|e| {
+            ApplicationError::FileSystemError(format!(
+                "Failed to write steering file {}: {}",
+                file.display(),
+                e
+            ))
+        })
     }
 
-    fn delete_oldest_steering_backups(&self, count: usize) -> Result<(), ApplicationError> {
-        let backups = self.list_steering_backups()?;
+    fn remove_steering_file(&self, file: &Path) -> Result<bool, ApplicationError> {
+        let path = self.steering_dir().join(file);
 
-        // Take the oldest 'count' backups
-        for backup in backups.iter().take(count) {
-            fs::remove_dir_all(&backup.path).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to delete backup {}: {}",
-                    backup.name, e
-                ))
-            })?;
+        if !path.is_file() {
+            return Ok(false);
         }
 
-        Ok(())
+        fs::remove_file(&path).map_err(|e| {
+            ApplicationError::FileSystemError(format!(
+                "Failed to remove steering file {}: {}",
+                file.display(),
+                e
+            ))
+        })?;
+        Ok(true)
     }
 
-    fn deploy_slash_commands(&self) -> Result<(), ApplicationError> {
+    fn list_slash_commands(&self) -> Result<Vec<SlashCommand>, ApplicationError> {
         use crate::infrastructure::embedded_resources::EmbeddedSlashCommands;
 
-        // Create .claude directory structure
-        let claude_dir = self.path_manager.project_root().join(".claude");
-        let commands_dir = claude_dir.join("commands");
+        Ok(SlashCommand::overlay(
+            &EmbeddedSlashCommands::get_all(),
+            self.load_command_overlay()?,
+        ))
+    }
 
-        // Remove existing .claude/commands/hm directory to ensure clean deployment
-        let hm_dir = commands_dir.join("hm");
-        if hm_dir.exists() {
-            fs::remove_dir_all(&hm_dir).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to remove existing .claude/commands/hm directory: {}",
-                    e
-                ))
-            })?;
-        }
+    fn deploy_claude_resources(&self) -> Result<DeploymentReport, ApplicationError> {
+        use crate::infrastructure::embedded_resources::EmbeddedSkills;
 
-        // Legacy cleanup: remove .claude/commands/pbi directory if present
-        let pbi_dir = commands_dir.join("pbi");
-        if pbi_dir.exists() {
-            fs::remove_dir_all(&pbi_dir).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to remove legacy .claude/commands/pbi directory: {}",
-                    e
-                ))
-            })?;
+        let project_root = self.path_manager.project_root();
+        let manifest = match self.load_deployment_manifest()? {
+            Some(manifest) => manifest,
+            None => self.adopt_legacy_slash_commands()?,
+        };
+
+        let hm_dir = PathBuf::from(SLASH_COMMANDS_DIR);
+        let mut desired: Vec<DesiredFile> = self
+            .list_slash_commands()?
+            .into_iter()
+            .map(|command| DesiredFile {
+                path: hm_dir.join(&command.file_name),
+                content: command.content,
+            })
+            .collect();
+
+        let mut skills = Vec::new();
+        for skill in EmbeddedSkills::get_all() {
+            let dir = PathBuf::from(SKILLS_DIR).join(skill.name);
+            desired.extend(skill.files.iter().map(|(path, content)| DesiredFile {
+                path: dir.join(path),
+                content: content.to_string(),
+            }));
+            skills.push(DesiredSkill {
+                name: skill.name.to_string(),
+                version: skill.version.to_string(),
+                dir,
+            });
         }
 
-        // Legacy cleanup: remove .claude/commands/spec directory if present
-        let spec_dir = commands_dir.join("spec");
-        if spec_dir.exists() {
-            fs::remove_dir_all(&spec_dir).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to remove legacy .claude/commands/spec directory: {}",
-                    e
-                ))
-            })?;
+        let mut plan = manifest.plan(&desired, |path| {
+            fs::read(project_root.join(path))
+                .ok()
+                .map(|content| content_hash(&content))
+        });
+        plan.stamp_skills(&manifest.skills, &skills);
+
+        let mut report = DeploymentReport {
+            changes: Vec::new(),
+            warnings: plan.warnings,
+            upgrades: plan.upgrades,
+        };
+        for action in plan.actions {
+            match action {
+                DeployAction::Write { path, content } => {
+                    let file = project_root.join(path);
+                    if let Some(parent) = file.parent() {
+                        report.changes.extend(self.writer.create_dir_all(parent)?);
+                    }
+                    report.changes.extend(self.writer.write(&file, &content)?);
+                }
+                DeployAction::Remove { path } => {
+                    report
+                        .changes
+                        .extend(self.writer.remove_file(&project_root.join(path))?);
+                }
+            }
         }
 
-        // Create .claude/commands/hm directory
-        fs::create_dir_all(&hm_dir).map_err(|e| {
+        let manifest_json = serde_json::to_string_pretty(&plan.manifest).map_err(|e| {
             ApplicationError::FileSystemError(format!(
-                "Failed to create .claude/commands/hm directory: {}",
+                "Failed to serialize deployment manifest: {}",
                 e
             ))
         })?;
-
-        // Legacy cleanup: remove .claude/agents/ directory if present
-        let agents_dir = claude_dir.join("agents");
-        if agents_dir.exists() {
-            fs::remove_dir_all(&agents_dir).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to remove legacy .claude/agents directory: {}",
-                    e
-                ))
-            })?;
-        }
-
-        // Deploy embedded hm commands (always overwrite for consistency)
-        for (name, content) in EmbeddedSlashCommands::get_all() {
-            let file_path = hm_dir.join(name);
-            fs::write(&file_path, content).map_err(|e| {
-                ApplicationError::FileSystemError(format!(
-                    "Failed to write slash command {}: {}",
-                    name, e
-                ))
-            })?;
+        report.changes.extend(
+            self.writer
+                .create_dir_all(&self.path_manager.kiro_dir(true))?,
+        );
+        report.changes.extend(self.writer.write(
+            &self.path_manager.deployment_manifest_path(true),
+            &format!("{}\n", manifest_json),
+        )?);
+
+        // Older versions deleted these on every run; they are user territory now
+        for legacy in LEGACY_COMMAND_DIRS {
+            if project_root.join(legacy).is_dir() {
+                report
+                    .warnings
+                    .push(DeploymentWarning::Legacy(PathBuf::from(legacy)));
+            }
         }
 
-        Ok(())
+        Ok(report)
     }
 
-    fn update_gitignore(&self) -> Result<(), ApplicationError> {
+    fn update_gitignore(&self) -> Result<Vec<FileChange>, ApplicationError> {
         let gitignore_path = self.path_manager.project_root().join(".gitignore");
 
         let content = if gitignore_path.exists() {
@@ -288,12 +459,25 @@ impl SteeringRepositoryInterface for SteeringRepository {
             String::new()
         };
 
-        // No database files to exclude since we're using file-based steering system
-        fs::write(gitignore_path, content).map_err(|e| {
-            ApplicationError::FileSystemError(format!("Failed to write .gitignore: {}", e))
-        })?;
+        let missing: Vec<&str> = GITIGNORE_ENTRIES
+            .iter()
+            .filter(|entry| !content.lines().any(|line| line.trim() == **entry))
+            .copied()
+            .collect();
+        if missing.is_empty() {
+            return Ok(Vec::new());
+        }
 
-        Ok(())
+        let mut updated = content;
+        if !updated.is_empty() && !updated.ends_with('\n') {
+            updated.push('\n');
+        }
+        for entry in missing {
+            updated.push_str(entry);
+            updated.push('\n');
+        }
+
+        self.writer.write(&gitignore_path, &updated)
     }
 
     fn exists(&self) -> Result<bool, ApplicationError> {
@@ -308,7 +492,7 @@ impl SteeringRepositoryInterface for SteeringRepository {
         let steering_dir = self.steering_dir();
 
         for steering_type in &config.types {
-            let file_path = steering_dir.join(format!("{}.md", steering_type.name));
+            let file_path = steering_dir.join(steering_file_name(&steering_type.name)?);
 
             // Skip if file doesn't exist
             if !file_path.exists() {
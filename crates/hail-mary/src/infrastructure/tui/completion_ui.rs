@@ -1,23 +1,54 @@
 use anyhow::Result;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind},
+    event::{Event, KeyCode, KeyEventKind},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{
     Frame, Terminal,
-    backend::CrosstermBackend,
+    backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
 };
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io;
 
-/// Displays a TUI for selecting specifications to complete
-pub fn select_specs_for_completion(specs: Vec<(String, bool)>) -> Result<Option<Vec<String>>> {
-    if specs.is_empty() {
-        return Ok(Some(Vec::new()));
+use crate::domain::value_objects::spec::SpecMatcher;
+use crate::infrastructure::tui::events::{CrosstermEvents, EventSource};
+use crate::infrastructure::tui::filter::{FilterInput, highlighted_spans};
+
+/// Action chosen in the completion TUI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompletionAction {
+    /// Move the selected active specs to the archive
+    Archive(Vec<String>),
+    /// Restore the selected archived specs
+    Unarchive(Vec<String>),
+}
+
+/// What the event loop should do after a key press
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    Continue,
+    Finish(Option<CompletionAction>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    Active,
+    Archive,
+}
+
+/// Displays a TUI for selecting specifications to complete, with an archive
+/// view (Tab) for restoring specs that were completed by mistake
+pub fn select_specs_for_completion(
+    specs: Vec<(String, bool)>,
+    archived: Vec<String>,
+) -> Result<Option<CompletionAction>> {
+    if specs.is_empty() && archived.is_empty() {
+        return Ok(Some(CompletionAction::Archive(Vec::new())));
     }
 
     // Terminal initialization
@@ -27,51 +58,45 @@ pub fn select_specs_for_completion(specs: Vec<(String, bool)>) -> Result<Option<
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    let result = run_completion_ui(&mut terminal, &mut CrosstermEvents, specs, archived);
+
+    // Terminal cleanup
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+/// Runs the completion TUI on any backend, reading key presses from `events`
+pub fn run_completion_ui<B: Backend>(
+    terminal: &mut Terminal<B>,
+    events: &mut dyn EventSource,
+    specs: Vec<(String, bool)>,
+    archived: Vec<String>,
+) -> Result<Option<CompletionAction>> {
     // Application state
-    let mut app = App::new(specs);
+    let mut app = App::new(specs, archived);
     let mut list_state = ListState::default();
-    list_state.select(Some(0));
+    if app.specs.is_empty() {
+        app.toggle_view(&mut list_state);
+    } else {
+        list_state.select(Some(0));
+    }
 
     // Main loop
-    let result = loop {
+    loop {
         // Draw UI
         terminal.draw(|f| draw_ui(f, &mut app, &mut list_state))?;
 
         // Event handling
-        if let Event::Key(key) = event::read()?
+        if let Event::Key(key) = events.next_event()?
             && key.kind == KeyEventKind::Press
+            && let Step::Finish(outcome) = app.handle_key(key.code, &mut list_state)
         {
-            match key.code {
-                KeyCode::Char('q') | KeyCode::Esc => {
-                    break Ok(None); // User cancelled
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    app.move_cursor_up(&mut list_state);
-                }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    app.move_cursor_down(&mut list_state);
-                }
-                KeyCode::Char(' ') => {
-                    app.toggle_selection(&list_state);
-                }
-                KeyCode::Enter => {
-                    let selected_specs = app.get_selected_specs();
-                    if selected_specs.is_empty() {
-                        continue;
-                    }
-                    break Ok(Some(selected_specs));
-                }
-                _ => {}
-            }
+            return Ok(outcome);
         }
-    };
-
-    // Terminal cleanup
-    disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    terminal.show_cursor()?;
-
-    result
+    }
 }
 
 fn draw_ui(f: &mut Frame, app: &mut App, list_state: &mut ListState) {
@@ -84,23 +109,25 @@ fn draw_ui(f: &mut Frame, app: &mut App, list_state: &mut ListState) {
         ])
         .split(f.area());
 
-    // Selected count
-    let selected_text = format!("Selected: {} items", app.selected.len());
+    // Selected count and filter
+    let mut selected_text = format!("Selected: {} items", app.selected.len());
+    if app.filter.is_active() {
+        selected_text.push_str(&format!("  {}", app.filter.prompt()));
+    }
     let selected_paragraph = Paragraph::new(selected_text).style(Style::default().fg(Color::Cyan));
     f.render_widget(selected_paragraph, chunks[0]);
 
     // Spec list
+    let names = app.current_items();
     let items: Vec<ListItem> = app
-        .specs
+        .visible
         .iter()
-        .enumerate()
-        .map(|(i, (name, _))| {
+        .map(|&i| {
             let checkbox = if app.selected.contains(&i) {
-                "[x]"
+                "[x] "
             } else {
-                "[ ]"
+                "[ ] "
             };
-            let content = format!("{} {}", checkbox, name);
 
             let style = if app.selected.contains(&i) {
                 Style::default().fg(Color::Green)
@@ -108,44 +135,183 @@ fn draw_ui(f: &mut Frame, app: &mut App, list_state: &mut ListState) {
                 Style::default()
             };
 
-            ListItem::new(content).style(style)
+            let positions = app.highlights.get(&i).map(Vec::as_slice).unwrap_or(&[]);
+            let mut spans = vec![Span::styled(checkbox, style)];
+            spans.extend(highlighted_spans(names[i], positions, style));
+
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
+    let title = match app.view {
+        View::Active => "Specifications",
+        View::Archive => "Archive",
+    };
+
     let list = List::new(items)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Specifications"),
-        )
+        .block(Block::default().borders(Borders::ALL).title(title))
         .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
 
     f.render_stateful_widget(list, chunks[1], list_state);
 
     // Help text
-    let help_text = "Space: Select  Enter: Archive  q: Quit  ↑↓/jk: Navigate";
+    let help_text = match app.view {
+        _ if app.filter.editing => "Type to filter  ↑↓: Navigate  Enter: Apply  Esc: Clear filter",
+        View::Active => {
+            "Space: Select  Enter: Archive  Tab: Show archive  /: Filter  q: Quit  ↑↓/jk: Navigate"
+        }
+        View::Archive => {
+            "Space: Select  Enter: Restore  Tab: Show specifications  /: Filter  q: Quit  ↑↓/jk: Navigate"
+        }
+    };
     let help_paragraph = Paragraph::new(help_text).style(Style::default().fg(Color::Gray));
     f.render_widget(help_paragraph, chunks[2]);
 }
 
 struct App {
     specs: Vec<(String, bool)>,
+    archived: Vec<String>,
+    view: View,
+    /// Indices into the current view's items
     selected: HashSet<usize>,
+    filter: FilterInput,
+    /// Indices into the current view's items that pass the filter, in display order
+    visible: Vec<usize>,
+    /// Matched char positions by item index
+    highlights: HashMap<usize, Vec<usize>>,
 }
 
 impl App {
-    fn new(specs: Vec<(String, bool)>) -> Self {
+    fn new(specs: Vec<(String, bool)>, archived: Vec<String>) -> Self {
+        let visible = (0..specs.len()).collect();
         Self {
             specs,
+            archived,
+            view: View::Active,
             selected: HashSet::new(),
+            filter: FilterInput::default(),
+            visible,
+            highlights: HashMap::new(),
+        }
+    }
+
+    fn handle_key(&mut self, code: KeyCode, list_state: &mut ListState) -> Step {
+        if self.filter.editing {
+            match code {
+                KeyCode::Up => self.move_cursor_up(list_state),
+                KeyCode::Down => self.move_cursor_down(list_state),
+                _ => {
+                    if self.filter.handle_key(code) {
+                        self.apply_filter(list_state);
+                    }
+                }
+            }
+            return Step::Continue;
+        }
+
+        match code {
+            KeyCode::Esc if self.filter.is_active() => {
+                self.filter.clear();
+                self.apply_filter(list_state);
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                return Step::Finish(None); // User cancelled
+            }
+            KeyCode::Char('/') => {
+                self.filter.start();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.move_cursor_up(list_state);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.move_cursor_down(list_state);
+            }
+            KeyCode::Char(' ') => {
+                self.toggle_selection(list_state);
+            }
+            KeyCode::Tab => {
+                self.toggle_view(list_state);
+            }
+            KeyCode::Enter => {
+                let selected_specs = self.get_selected_specs();
+                if !selected_specs.is_empty() {
+                    return Step::Finish(Some(self.action(selected_specs)));
+                }
+            }
+            _ => {}
+        }
+
+        Step::Continue
+    }
+
+    /// Recomputes the visible items for the current query, best matches first.
+    /// Selections are kept even when the selected items are filtered out.
+    fn apply_filter(&mut self, list_state: &mut ListState) {
+        self.highlights.clear();
+        let query = self.filter.query.clone();
+
+        if query.is_empty() {
+            self.visible = (0..self.current_items().len()).collect();
+        } else {
+            let mut matches: Vec<(usize, i64, Vec<usize>)> = self
+                .current_items()
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, name)| {
+                    SpecMatcher::fuzzy_match(&query, name).map(|m| (i, m.score, m.positions))
+                })
+                .collect();
+            matches.sort_by_key(|(_, score, _)| std::cmp::Reverse(*score));
+            self.visible = matches
+                .into_iter()
+                .map(|(i, _, positions)| {
+                    self.highlights.insert(i, positions);
+                    i
+                })
+                .collect();
+        }
+
+        list_state.select(if self.visible.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+    }
+
+    fn current_items(&self) -> Vec<&str> {
+        match self.view {
+            View::Active => self.specs.iter().map(|(name, _)| name.as_str()).collect(),
+            View::Archive => self.archived.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Switches between active specs and the archive, clearing the selection
+    fn toggle_view(&mut self, list_state: &mut ListState) {
+        self.view = match self.view {
+            View::Active => View::Archive,
+            View::Archive => View::Active,
+        };
+        self.selected.clear();
+        self.filter = FilterInput::default();
+        self.apply_filter(list_state);
+    }
+
+    fn action(&self, selected_specs: Vec<String>) -> CompletionAction {
+        match self.view {
+            View::Active => CompletionAction::Archive(selected_specs),
+            View::Archive => CompletionAction::Unarchive(selected_specs),
         }
     }
 
     fn move_cursor_up(&self, list_state: &mut ListState) {
+        let len = self.visible.len();
+        if len == 0 {
+            return;
+        }
         let i = match list_state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.specs.len() - 1
+                    len - 1
                 } else {
                     i - 1
                 }
@@ -156,9 +322,13 @@ impl App {
     }
 
     fn move_cursor_down(&self, list_state: &mut ListState) {
+        let len = self.visible.len();
+        if len == 0 {
+            return;
+        }
         let i = match list_state.selected() {
             Some(i) => {
-                if i >= self.specs.len() - 1 {
+                if i >= len - 1 {
                     0
                 } else {
                     i + 1
@@ -170,7 +340,7 @@ impl App {
     }
 
     fn toggle_selection(&mut self, list_state: &ListState) {
-        if let Some(i) = list_state.selected() {
+        if let Some(&i) = list_state.selected().and_then(|i| self.visible.get(i)) {
             if self.selected.contains(&i) {
                 self.selected.remove(&i);
             } else {
@@ -179,17 +349,19 @@ impl App {
         }
     }
 
+    /// Selected specs in list order
     fn get_selected_specs(&self) -> Vec<String> {
-        self.selected
-            .iter()
-            .map(|&i| self.specs[i].0.clone())
-            .collect()
+        let items = self.current_items();
+        let mut indices: Vec<usize> = self.selected.iter().copied().collect();
+        indices.sort_unstable();
+        indices.into_iter().map(|i| items[i].to_string()).collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::tui::testing::{ScriptedEvents, assert_screen, test_terminal};
 
     #[test]
     fn test_app_new() {
@@ -197,7 +369,7 @@ mod tests {
             ("2025-01-01-feature-a".to_string(), false),
             ("2025-01-02-feature-b".to_string(), false),
         ];
-        let app = App::new(specs.clone());
+        let app = App::new(specs.clone(), Vec::new());
 
         assert_eq!(app.specs.len(), 2);
         assert!(app.selected.is_empty());
@@ -209,7 +381,7 @@ mod tests {
             ("2025-01-01-feature-a".to_string(), false),
             ("2025-01-02-feature-b".to_string(), false),
         ];
-        let mut app = App::new(specs);
+        let mut app = App::new(specs, Vec::new());
         let mut list_state = ListState::default();
         list_state.select(Some(0));
 
@@ -229,7 +401,7 @@ mod tests {
             ("2025-01-02-feature-b".to_string(), false),
             ("2025-01-03-feature-c".to_string(), false),
         ];
-        let mut app = App::new(specs);
+        let mut app = App::new(specs, Vec::new());
 
         app.selected.insert(0);
         app.selected.insert(2);
@@ -247,7 +419,7 @@ mod tests {
             ("2025-01-02-feature-b".to_string(), false),
             ("2025-01-03-feature-c".to_string(), false),
         ];
-        let app = App::new(specs);
+        let app = App::new(specs, Vec::new());
         let mut list_state = ListState::default();
 
         // Start at position 1
@@ -269,7 +441,7 @@ mod tests {
             ("2025-01-02-feature-b".to_string(), false),
             ("2025-01-03-feature-c".to_string(), false),
         ];
-        let app = App::new(specs);
+        let app = App::new(specs, Vec::new());
         let mut list_state = ListState::default();
 
         // Start at position 1
@@ -283,4 +455,247 @@ mod tests {
         app.move_cursor_down(&mut list_state);
         assert_eq!(list_state.selected(), Some(0));
     }
+
+    #[test]
+    fn test_app_toggle_view_to_archive() {
+        let specs = vec![("2025-01-01-feature-a".to_string(), false)];
+        let archived = vec![
+            "2024-12-01-old-a".to_string(),
+            "2024-12-02-old-b".to_string(),
+        ];
+        let mut app = App::new(specs, archived);
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        app.toggle_selection(&list_state);
+        app.toggle_view(&mut list_state);
+
+        // Selection is cleared and the cursor starts at the top of the archive
+        assert_eq!(app.view, View::Archive);
+        assert!(app.selected.is_empty());
+        assert_eq!(list_state.selected(), Some(0));
+
+        app.move_cursor_down(&mut list_state);
+        app.toggle_selection(&list_state);
+        let selected = app.get_selected_specs();
+        assert_eq!(
+            app.action(selected),
+            CompletionAction::Unarchive(vec!["2024-12-02-old-b".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_app_empty_archive_view() {
+        let specs = vec![("2025-01-01-feature-a".to_string(), false)];
+        let mut app = App::new(specs, Vec::new());
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        app.toggle_view(&mut list_state);
+        assert_eq!(list_state.selected(), None);
+
+        // Navigation is a no-op on an empty view
+        app.move_cursor_down(&mut list_state);
+        app.move_cursor_up(&mut list_state);
+        assert_eq!(list_state.selected(), None);
+
+        app.toggle_view(&mut list_state);
+        assert_eq!(app.view, View::Active);
+        assert_eq!(list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_app_action_for_active_view() {
+        let app = App::new(Vec::new(), Vec::new());
+        assert_eq!(
+            app.action(vec!["spec".to_string()]),
+            CompletionAction::Archive(vec!["spec".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_app_filter_and_multi_select() {
+        let specs = vec![
+            ("2025-01-03-login-flow".to_string(), false),
+            ("2025-01-02-payments".to_string(), false),
+            ("2025-01-01-logout".to_string(), false),
+        ];
+        let mut app = App::new(specs, Vec::new());
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        app.handle_key(KeyCode::Char('/'), &mut list_state);
+        for c in "log".chars() {
+            app.handle_key(KeyCode::Char(c), &mut list_state);
+        }
+        app.handle_key(KeyCode::Enter, &mut list_state);
+
+        // payments is filtered out; both log* specs remain and are highlighted
+        assert_eq!(app.visible.len(), 2);
+        assert!(!app.visible.contains(&1));
+        assert_eq!(app.highlights[&0], vec![11, 12, 13]);
+
+        app.handle_key(KeyCode::Char(' '), &mut list_state);
+        app.handle_key(KeyCode::Char('j'), &mut list_state);
+        app.handle_key(KeyCode::Char(' '), &mut list_state);
+
+        let mut selected = app.get_selected_specs();
+        selected.sort();
+        assert_eq!(
+            selected,
+            vec![
+                "2025-01-01-logout".to_string(),
+                "2025-01-03-login-flow".to_string()
+            ]
+        );
+
+        // Clearing the filter keeps the selection
+        app.handle_key(KeyCode::Esc, &mut list_state);
+        assert_eq!(app.visible, vec![0, 1, 2]);
+        assert_eq!(app.selected.len(), 2);
+    }
+
+    #[test]
+    fn test_app_handle_key_finish_and_cancel() {
+        let specs = vec![("2025-01-01-feature-a".to_string(), false)];
+        let mut app = App::new(specs, Vec::new());
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        // Enter without a selection does nothing
+        assert_eq!(
+            app.handle_key(KeyCode::Enter, &mut list_state),
+            Step::Continue
+        );
+
+        app.handle_key(KeyCode::Char(' '), &mut list_state);
+        assert_eq!(
+            app.handle_key(KeyCode::Enter, &mut list_state),
+            Step::Finish(Some(CompletionAction::Archive(vec![
+                "2025-01-01-feature-a".to_string()
+            ])))
+        );
+
+        assert_eq!(
+            app.handle_key(KeyCode::Char('q'), &mut list_state),
+            Step::Finish(None)
+        );
+    }
+
+    fn snapshot_specs() -> (Vec<(String, bool)>, Vec<String>) {
+        (
+            vec![
+                ("2025-01-03-login-flow".to_string(), false),
+                ("2025-01-02-payments".to_string(), false),
+                ("2025-01-01-logout".to_string(), false),
+            ],
+            vec!["2024-12-01-old-search".to_string()],
+        )
+    }
+
+    #[test]
+    fn test_run_multi_select_and_archive() {
+        let (specs, archived) = snapshot_specs();
+        let mut terminal = test_terminal(60, 7);
+        let mut events = ScriptedEvents::keys([
+            KeyCode::Char(' '),
+            KeyCode::Char('j'),
+            KeyCode::Char('j'),
+            KeyCode::Char(' '),
+        ]);
+
+        // Stop before Enter to capture the selection state
+        assert!(
+            run_completion_ui(&mut terminal, &mut events, specs.clone(), archived.clone()).is_err()
+        );
+        assert_screen(
+            &terminal,
+            &[
+                "Selected: 2 items",
+                "┌Specifications────────────────────────────────────────────┐",
+                "│[x] 2025-01-03-login-flow                                 │",
+                "│[ ] 2025-01-02-payments                                   │",
+                "│[x] 2025-01-01-logout                                     │",
+                "└──────────────────────────────────────────────────────────┘",
+                "Space: Select  Enter: Archive  Tab: Show archive  /: Filter",
+            ],
+        );
+
+        let mut events = ScriptedEvents::keys([
+            KeyCode::Char(' '),
+            KeyCode::Char('j'),
+            KeyCode::Char('j'),
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ]);
+        let result = run_completion_ui(&mut terminal, &mut events, specs, archived).unwrap();
+        assert_eq!(
+            result,
+            Some(CompletionAction::Archive(vec![
+                "2025-01-03-login-flow".to_string(),
+                "2025-01-01-logout".to_string(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_run_archive_view_and_restore() {
+        let (specs, archived) = snapshot_specs();
+        let mut terminal = test_terminal(60, 7);
+        let mut events = ScriptedEvents::keys([KeyCode::Tab, KeyCode::Char(' '), KeyCode::Enter]);
+
+        let result = run_completion_ui(&mut terminal, &mut events, specs, archived).unwrap();
+
+        assert_eq!(
+            result,
+            Some(CompletionAction::Unarchive(vec![
+                "2024-12-01-old-search".to_string()
+            ]))
+        );
+        assert_screen(
+            &terminal,
+            &[
+                "Selected: 1 items",
+                "┌Archive───────────────────────────────────────────────────┐",
+                "│[x] 2024-12-01-old-search                                 │",
+                "│                                                          │",
+                "│                                                          │",
+                "└──────────────────────────────────────────────────────────┘",
+                "Space: Select  Enter: Restore  Tab: Show specifications  /:",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_run_filter_screen() {
+        let (specs, archived) = snapshot_specs();
+        let mut terminal = test_terminal(60, 7);
+        let mut events = ScriptedEvents::new()
+            .press(KeyCode::Char('/'))
+            .type_text("pay");
+
+        assert!(run_completion_ui(&mut terminal, &mut events, specs, archived).is_err());
+        assert_screen(
+            &terminal,
+            &[
+                "Selected: 0 items  /pay▏",
+                "┌Specifications────────────────────────────────────────────┐",
+                "│[ ] 2025-01-02-payments                                   │",
+                "│                                                          │",
+                "│                                                          │",
+                "└──────────────────────────────────────────────────────────┘",
+                "Type to filter  ↑↓: Navigate  Enter: Apply  Esc: Clear filte",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_run_cancel() {
+        let (specs, archived) = snapshot_specs();
+        let mut terminal = test_terminal(60, 7);
+        let mut events = ScriptedEvents::keys([KeyCode::Char(' '), KeyCode::Esc]);
+
+        let result = run_completion_ui(&mut terminal, &mut events, specs, archived).unwrap();
+        assert_eq!(result, None);
+    }
 }
@@ -0,0 +1,17 @@
+use crossterm::event::{self, Event};
+use std::io;
+
+/// Source of terminal events, so the TUIs can be driven without a real terminal
+pub trait EventSource {
+    /// Block until the next event is available
+    fn next_event(&mut self) -> io::Result<Event>;
+}
+
+/// Reads events from the terminal via crossterm
+pub struct CrosstermEvents;
+
+impl EventSource for CrosstermEvents {
+    fn next_event(&mut self) -> io::Result<Event> {
+        event::read()
+    }
+}
@@ -0,0 +1,141 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+
+/// Type-to-filter state shared by the TUIs; `/` starts editing the query
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FilterInput {
+    pub query: String,
+    pub editing: bool,
+}
+
+impl FilterInput {
+    pub fn start(&mut self) {
+        self.editing = true;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.editing || !self.query.is_empty()
+    }
+
+    /// Handles a key while editing. Enter keeps the query, Esc clears it.
+    /// Returns true if the query changed.
+    pub fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                true
+            }
+            KeyCode::Backspace => self.query.pop().is_some(),
+            KeyCode::Enter => {
+                self.editing = false;
+                false
+            }
+            KeyCode::Esc => {
+                self.editing = false;
+                self.clear()
+            }
+            _ => false,
+        }
+    }
+
+    /// Clears the query, returning true if it was not empty
+    pub fn clear(&mut self) -> bool {
+        let changed = !self.query.is_empty();
+        self.query.clear();
+        changed
+    }
+
+    /// Text shown in the filter prompt
+    pub fn prompt(&self) -> String {
+        if self.editing {
+            format!("/{}▏", self.query)
+        } else {
+            format!("/{}", self.query)
+        }
+    }
+}
+
+/// Style used for characters matched by the filter
+pub fn highlight_style(base: Style) -> Style {
+    base.fg(Color::Magenta).add_modifier(Modifier::BOLD)
+}
+
+/// Splits `text` into spans, highlighting the chars at `positions` (char indices)
+pub fn highlighted_spans(text: &str, positions: &[usize], base: Style) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut current = String::new();
+    let mut current_highlighted = false;
+
+    for (i, c) in text.chars().enumerate() {
+        let highlighted = positions.contains(&i);
+        if highlighted != current_highlighted && !current.is_empty() {
+            let style = if current_highlighted {
+                highlight_style(base)
+            } else {
+                base
+            };
+            spans.push(Span::styled(std::mem::take(&mut current), style));
+        }
+        current_highlighted = highlighted;
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        let style = if current_highlighted {
+            highlight_style(base)
+        } else {
+            base
+        };
+        spans.push(Span::styled(current, style));
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_input_editing() {
+        let mut filter = FilterInput::default();
+        assert!(!filter.is_active());
+
+        filter.start();
+        assert!(filter.handle_key(KeyCode::Char('a')));
+        assert!(filter.handle_key(KeyCode::Char('p')));
+        assert!(filter.handle_key(KeyCode::Backspace));
+        assert_eq!(filter.prompt(), "/a▏");
+
+        assert!(!filter.handle_key(KeyCode::Enter));
+        assert!(!filter.editing);
+        assert!(filter.is_active());
+        assert_eq!(filter.prompt(), "/a");
+    }
+
+    #[test]
+    fn test_filter_input_escape_clears() {
+        let mut filter = FilterInput::default();
+        filter.start();
+        filter.handle_key(KeyCode::Char('x'));
+
+        assert!(filter.handle_key(KeyCode::Esc));
+        assert!(!filter.is_active());
+        assert!(filter.query.is_empty());
+    }
+
+    #[test]
+    fn test_highlighted_spans() {
+        let base = Style::default();
+        let spans = highlighted_spans("login", &[0, 1, 4], base);
+
+        let parts: Vec<(&str, bool)> = spans
+            .iter()
+            .map(|span| (span.content.as_ref(), span.style == highlight_style(base)))
+            .collect();
+        assert_eq!(parts, vec![("lo", true), ("gi", false), ("n", true)]);
+    }
+}
@@ -1,2 +1,9 @@
 pub mod completion_ui;
+pub mod events;
+pub mod filter;
+pub mod spec_metadata;
 pub mod spec_selector;
+pub mod terminal_prompt;
+
+#[cfg(test)]
+pub mod testing;
@@ -0,0 +1,180 @@
+use crate::application::repositories::SpecRepositoryInterface;
+use crate::domain::value_objects::spec::SpecMatcher;
+use crate::domain::value_objects::tasks::TaskProgress;
+use chrono::NaiveDate;
+use std::time::{Duration, SystemTime};
+
+/// Spec documents shown in the selector, with their short labels
+const KNOWN_DOCUMENTS: [(&str, &str); 5] = [
+    ("prd.md", "prd"),
+    ("requirements.md", "req"),
+    ("design.md", "design"),
+    ("investigation.md", "inv"),
+    ("brainstorming.md", "brain"),
+];
+
+/// Row metadata and preview content for a spec (or `pbi/sbi`) in the selector
+#[derive(Debug, Clone, Default)]
+pub struct SpecMetadata {
+    /// Creation date parsed from the `YYYY-MM-DD-` prefix
+    pub created: Option<NaiveDate>,
+    pub progress: Option<TaskProgress>,
+    /// Short labels of the known documents that exist
+    pub documents: Vec<&'static str>,
+    /// Most recent modification time of the spec's files
+    pub modified: Option<SystemTime>,
+    /// Raw tasks.md content for the preview pane
+    pub tasks: Option<String>,
+}
+
+impl SpecMetadata {
+    /// Collects metadata for `name`; `date_source` is the directory name carrying the
+    /// date prefix (the PBI for an SBI). Repository errors leave fields empty.
+    pub fn load(spec_repo: &dyn SpecRepositoryInterface, name: &str, date_source: &str) -> Self {
+        let files = spec_repo.list_spec_files(name).unwrap_or_default();
+        let tasks = spec_repo.read_spec_file(name, "tasks.md").ok().flatten();
+
+        Self {
+            created: parse_date_prefix(date_source),
+            progress: tasks.as_deref().map(TaskProgress::from_markdown),
+            documents: KNOWN_DOCUMENTS
+                .iter()
+                .filter(|(file, _)| files.iter().any(|f| f.name == *file))
+                .map(|(_, label)| *label)
+                .collect(),
+            modified: files.iter().map(|f| f.modified).max(),
+            tasks,
+        }
+    }
+
+    /// One-line summary shown next to the spec name
+    pub fn summary(&self, today: NaiveDate, now: SystemTime) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(created) = self.created {
+            parts.push(format_age(created, today));
+        }
+
+        if let Some(progress) = self.progress {
+            parts.push(format!("{}/{}", progress.completed, progress.total));
+        }
+
+        if !self.documents.is_empty() {
+            parts.push(self.documents.join(" "));
+        }
+
+        if let Some(modified) = self.modified {
+            parts.push(format_modified(modified, now));
+        }
+
+        parts.join(" · ")
+    }
+}
+
+fn parse_date_prefix(name: &str) -> Option<NaiveDate> {
+    let prefix_len = name.len() - SpecMatcher::strip_date_prefix(name).len();
+    if prefix_len == 0 {
+        return None;
+    }
+    NaiveDate::parse_from_str(&name[..prefix_len - 1], "%Y-%m-%d").ok()
+}
+
+fn format_age(created: NaiveDate, today: NaiveDate) -> String {
+    match (today - created).num_days() {
+        days if days <= 0 => "today".to_string(),
+        days => format!("{}d", days),
+    }
+}
+
+fn format_modified(modified: SystemTime, now: SystemTime) -> String {
+    let elapsed = now
+        .duration_since(modified)
+        .unwrap_or(Duration::ZERO)
+        .as_secs();
+
+    match elapsed {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", elapsed / 60),
+        3600..86400 => format!("{}h ago", elapsed / 3600),
+        _ => format!("{}d ago", elapsed / 86400),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::test_helpers::MockSpecRepository;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_load_collects_documents_and_progress() {
+        let repo = MockSpecRepository::with_specs(vec!["2025-01-01-feature".to_string()]);
+        repo.add_file("2025-01-01-feature", "tasks.md", "- [x] a\n- [ ] b\n");
+        repo.add_file("2025-01-01-feature", "requirements.md", "# Req");
+        repo.add_file("2025-01-01-feature", "design.md", "# Design");
+
+        let metadata = SpecMetadata::load(&repo, "2025-01-01-feature", "2025-01-01-feature");
+
+        assert_eq!(metadata.created, Some(date("2025-01-01")));
+        assert_eq!(
+            metadata.progress,
+            Some(TaskProgress {
+                completed: 1,
+                total: 2
+            })
+        );
+        assert_eq!(metadata.documents, vec!["req", "design"]);
+        assert!(metadata.tasks.is_some());
+    }
+
+    #[test]
+    fn test_load_without_files() {
+        let repo = MockSpecRepository::new();
+        let metadata = SpecMetadata::load(&repo, "legacy-spec", "legacy-spec");
+
+        assert_eq!(metadata.created, None);
+        assert_eq!(metadata.progress, None);
+        assert!(metadata.documents.is_empty());
+        assert_eq!(metadata.summary(date("2025-01-10"), SystemTime::now()), "");
+    }
+
+    #[test]
+    fn test_summary() {
+        let now = SystemTime::now();
+        let metadata = SpecMetadata {
+            created: Some(date("2025-01-01")),
+            progress: Some(TaskProgress {
+                completed: 3,
+                total: 5,
+            }),
+            documents: vec!["req", "inv"],
+            modified: Some(now - Duration::from_secs(2 * 3600)),
+            tasks: None,
+        };
+
+        assert_eq!(
+            metadata.summary(date("2025-01-10"), now),
+            "9d · 3/5 · req inv · 2h ago"
+        );
+    }
+
+    #[test]
+    fn test_format_age_and_modified() {
+        assert_eq!(format_age(date("2025-01-10"), date("2025-01-10")), "today");
+        assert_eq!(format_age(date("2025-01-01"), date("2025-01-10")), "9d");
+
+        let now = SystemTime::now();
+        assert_eq!(format_modified(now, now), "just now");
+        assert_eq!(
+            format_modified(now - Duration::from_secs(300), now),
+            "5m ago"
+        );
+        assert_eq!(
+            format_modified(now - Duration::from_secs(3 * 86400), now),
+            "3d ago"
+        );
+    }
+}
@@ -1,17 +1,27 @@
+use crate::application::repositories::SpecSelectionResult;
 use anyhow::Result;
+use chrono::NaiveDate;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind},
+    event::{Event, KeyCode, KeyEventKind},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{
     Frame, Terminal,
-    backend::CrosstermBackend,
+    backend::{Backend, CrosstermBackend},
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
 };
+use std::collections::HashMap;
 use std::io;
+use std::time::SystemTime;
+
+use crate::domain::value_objects::spec::SpecMatcher;
+use crate::infrastructure::tui::events::{CrosstermEvents, EventSource};
+use crate::infrastructure::tui::filter::{FilterInput, highlighted_spans};
+use crate::infrastructure::tui::spec_metadata::SpecMetadata;
 
 #[derive(Debug, Clone)]
 enum TuiItem {
@@ -34,8 +44,28 @@ enum TuiItem {
     },
 }
 
+impl TuiItem {
+    /// Key into the metadata map (`pbi/sbi` for SBIs)
+    fn metadata_key(&self) -> Option<String> {
+        match self {
+            TuiItem::Pbi { name, .. } | TuiItem::SingleSpec { name } => Some(name.clone()),
+            TuiItem::Sbi { pbi_name, sbi_name } => Some(format!("{}/{}", pbi_name, sbi_name)),
+            _ => None,
+        }
+    }
+}
+
 pub struct SpecSelectorTui {
     items: Vec<TuiItem>,
+    metadata: HashMap<String, SpecMetadata>,
+    filter: FilterInput,
+    /// Indices into `items` currently shown, in display order
+    visible: Vec<usize>,
+    /// Matched char positions of the displayed name, by item index
+    highlights: HashMap<usize, Vec<usize>>,
+    /// Reference times for ages and last-modified labels
+    today: NaiveDate,
+    now: SystemTime,
 }
 
 impl SpecSelectorTui {
@@ -85,7 +115,30 @@ impl SpecSelectorTui {
             }
         }
 
-        Self { items }
+        // Collect row metadata and tasks.md previews up front
+        let mut metadata = HashMap::new();
+        for item in &items {
+            if let Some(key) = item.metadata_key() {
+                let date_source = match item {
+                    TuiItem::Sbi { pbi_name, .. } => pbi_name.as_str(),
+                    _ => key.as_str(),
+                };
+                let spec_metadata = SpecMetadata::load(spec_repo, &key, date_source);
+                metadata.insert(key, spec_metadata);
+            }
+        }
+
+        let visible = (0..items.len()).collect();
+
+        Self {
+            items,
+            metadata,
+            filter: FilterInput::default(),
+            visible,
+            highlights: HashMap::new(),
+            today: chrono::Local::now().date_naive(),
+            now: SystemTime::now(),
+        }
     }
 
     pub fn run(&mut self) -> Result<SpecSelectionResult> {
@@ -96,56 +149,179 @@ impl SpecSelectorTui {
         let backend = CrosstermBackend::new(stdout);
         let mut terminal = Terminal::new(backend)?;
 
+        let result = self.run_with(&mut terminal, &mut CrosstermEvents);
+
+        // Terminal cleanup
+        disable_raw_mode()?;
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+
+        result
+    }
+
+    /// Runs the selector on any backend, reading key presses from `events`
+    pub fn run_with<B: Backend>(
+        &mut self,
+        terminal: &mut Terminal<B>,
+        events: &mut dyn EventSource,
+    ) -> Result<SpecSelectionResult> {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
 
-        let result = loop {
+        loop {
             terminal.draw(|f| self.draw_ui(f, &mut list_state))?;
 
-            if let Event::Key(key) = event::read()?
+            if let Event::Key(key) = events.next_event()?
                 && key.kind == KeyEventKind::Press
+                && let Some(result) = self.handle_key(key.code, &mut list_state)
             {
-                match key.code {
-                    KeyCode::Char('q') | KeyCode::Esc => {
-                        break Ok(SpecSelectionResult::Cancelled);
+                return Ok(result);
+            }
+        }
+    }
+
+    /// Applies a key press, returning the selection once the user has made one
+    fn handle_key(
+        &mut self,
+        code: KeyCode,
+        list_state: &mut ListState,
+    ) -> Option<SpecSelectionResult> {
+        if self.filter.editing {
+            match code {
+                KeyCode::Up => self.move_cursor_up(list_state),
+                KeyCode::Down => self.move_cursor_down(list_state),
+                _ => {
+                    if self.filter.handle_key(code) {
+                        self.apply_filter(list_state);
                     }
-                    KeyCode::Enter => {
-                        if let Some(selected) = list_state.selected()
-                            && selected < self.items.len()
-                        {
-                            let result = match &self.items[selected] {
-                                TuiItem::LaunchWithoutSpec => SpecSelectionResult::NoSpec,
-                                TuiItem::CreateNewSpec => SpecSelectionResult::CreateNew,
-                                TuiItem::Pbi { name, .. } => SpecSelectionResult::Pbi(name.clone()),
-                                TuiItem::Sbi { pbi_name, sbi_name } => {
-                                    SpecSelectionResult::Sbi(pbi_name.clone(), sbi_name.clone())
-                                }
-                                TuiItem::CreateNewSbi { pbi_name } => {
-                                    SpecSelectionResult::CreateNewSbi(pbi_name.clone())
+                }
+            }
+            return None;
+        }
+
+        match code {
+            KeyCode::Esc if self.filter.is_active() => {
+                self.filter.clear();
+                self.apply_filter(list_state);
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                return Some(SpecSelectionResult::Cancelled);
+            }
+            KeyCode::Char('/') => {
+                self.filter.start();
+            }
+            KeyCode::Enter => {
+                if let Some(&index) = list_state.selected().and_then(|i| self.visible.get(i)) {
+                    let result = match &self.items[index] {
+                        TuiItem::LaunchWithoutSpec => SpecSelectionResult::NoSpec,
+                        TuiItem::CreateNewSpec => SpecSelectionResult::CreateNew,
+                        TuiItem::Pbi { name, .. } => SpecSelectionResult::Pbi(name.clone()),
+                        TuiItem::Sbi { pbi_name, sbi_name } => {
+                            SpecSelectionResult::Sbi(pbi_name.clone(), sbi_name.clone())
+                        }
+                        TuiItem::CreateNewSbi { pbi_name } => {
+                            SpecSelectionResult::CreateNewSbi(pbi_name.clone())
+                        }
+                        TuiItem::SingleSpec { name } => {
+                            SpecSelectionResult::SingleSpec(name.clone())
+                        }
+                    };
+                    return Some(result);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.move_cursor_up(list_state);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.move_cursor_down(list_state);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Recomputes the visible items for the current query. The launch and
+    /// create actions always stay on top. A PBI group stays together: a
+    /// matching PBI shows all of its SBIs, otherwise only the matching SBIs are
+    /// listed under it. Groups are ordered by best score, and the cursor lands
+    /// on the best match when there is one.
+    fn apply_filter(&mut self, list_state: &mut ListState) {
+        self.highlights.clear();
+
+        if self.filter.query.is_empty() {
+            self.visible = (0..self.items.len()).collect();
+            list_state.select(Some(0));
+        } else {
+            let query = self.filter.query.clone();
+            let actions: Vec<usize> = self
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| {
+                    matches!(item, TuiItem::LaunchWithoutSpec | TuiItem::CreateNewSpec)
+                })
+                .map(|(i, _)| i)
+                .collect();
+            let mut groups: Vec<(i64, Vec<usize>)> = Vec::new();
+            let mut i = 0;
+
+            while i < self.items.len() {
+                match &self.items[i] {
+                    TuiItem::Pbi { name, .. } => {
+                        let pbi_match = SpecMatcher::fuzzy_match(&query, name);
+                        let mut best = pbi_match.as_ref().map(|m| m.score);
+                        let mut members = vec![i];
+                        if let Some(m) = pbi_match.as_ref() {
+                            self.highlights.insert(i, m.positions.clone());
+                        }
+
+                        let mut j = i + 1;
+                        while j < self.items.len() {
+                            match &self.items[j] {
+                                TuiItem::Sbi { sbi_name, .. } => {
+                                    if let Some(m) = SpecMatcher::fuzzy_match(&query, sbi_name) {
+                                        best = best.max(Some(m.score));
+                                        self.highlights.insert(j, m.positions);
+                                        members.push(j);
+                                    } else if pbi_match.is_some() {
+                                        members.push(j);
+                                    }
                                 }
-                                TuiItem::SingleSpec { name } => {
-                                    SpecSelectionResult::SingleSpec(name.clone())
+                                TuiItem::CreateNewSbi { .. } => {
+                                    if pbi_match.is_some() {
+                                        members.push(j);
+                                    }
                                 }
-                            };
-                            break Ok(result);
+                                _ => break,
+                            }
+                            j += 1;
                         }
+
+                        if let Some(score) = best {
+                            groups.push((score, members));
+                        }
+                        i = j;
+                        continue;
                     }
-                    KeyCode::Up | KeyCode::Char('k') => {
-                        self.move_cursor_up(&mut list_state);
-                    }
-                    KeyCode::Down | KeyCode::Char('j') => {
-                        self.move_cursor_down(&mut list_state);
+                    TuiItem::SingleSpec { name } => {
+                        if let Some(m) = SpecMatcher::fuzzy_match(&query, name) {
+                            self.highlights.insert(i, m.positions);
+                            groups.push((m.score, vec![i]));
+                        }
                     }
                     _ => {}
                 }
+                i += 1;
             }
-        };
-
-        // Terminal cleanup
-        disable_raw_mode()?;
-        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
 
-        result
+            groups.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            let first_match = if groups.is_empty() { 0 } else { actions.len() };
+            self.visible = actions
+                .into_iter()
+                .chain(groups.into_iter().flat_map(|(_, members)| members))
+                .collect();
+            list_state.select(Some(first_match));
+        }
     }
 
     fn draw_ui(&self, frame: &mut Frame, list_state: &mut ListState) {
@@ -159,38 +335,72 @@ impl SpecSelectorTui {
             .split(frame.area());
 
         // Title
-        let title = Paragraph::new("Select a specification to work on").block(
+        let title_text = if self.filter.is_active() {
+            self.filter.prompt()
+        } else {
+            "Select a specification to work on".to_string()
+        };
+        let title = Paragraph::new(title_text).block(
             Block::default()
                 .borders(Borders::ALL)
                 .title("Kiro Specifications"),
         );
         frame.render_widget(title, chunks[0]);
 
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(chunks[1]);
+
         // List items
         let mut list_items: Vec<ListItem> = Vec::new();
 
-        for item in &self.items {
-            let (text, style) = match item {
+        for &index in &self.visible {
+            let item = &self.items[index];
+            let (prefix, text, style) = match item {
                 TuiItem::LaunchWithoutSpec => (
+                    "",
                     "🚀 Launch without specification".to_string(),
                     Style::default().fg(Color::Cyan),
                 ),
                 TuiItem::CreateNewSpec => (
+                    "",
                     "📝 Create new specification".to_string(),
                     Style::default().fg(Color::Green),
                 ),
-                TuiItem::Pbi { name, .. } => (format!("   {}", name), Style::default()),
+                TuiItem::Pbi { name, .. } => ("   ", name.clone(), Style::default()),
                 TuiItem::Sbi { sbi_name, .. } => (
-                    format!("     {}", sbi_name),
+                    "     ",
+                    sbi_name.clone(),
                     Style::default().fg(Color::Yellow),
                 ),
                 TuiItem::CreateNewSbi { .. } => (
-                    "     📝 Create new SBI specification".to_string(),
+                    "     ",
+                    "📝 Create new SBI specification".to_string(),
                     Style::default().fg(Color::Green),
                 ),
-                TuiItem::SingleSpec { name } => (format!("   {}", name), Style::default()),
+                TuiItem::SingleSpec { name } => ("   ", name.clone(), Style::default()),
             };
-            list_items.push(ListItem::new(text).style(style));
+
+            let positions = self
+                .highlights
+                .get(&index)
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            let mut spans = vec![Span::styled(prefix, style)];
+            spans.extend(highlighted_spans(&text, positions, style));
+            if let Some(summary) = item
+                .metadata_key()
+                .and_then(|key| self.metadata.get(&key))
+                .map(|metadata| metadata.summary(self.today, self.now))
+                .filter(|summary| !summary.is_empty())
+            {
+                spans.push(Span::styled(
+                    format!("  {}", summary),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            list_items.push(ListItem::new(Line::from(spans)));
         }
 
         let list = List::new(list_items)
@@ -202,16 +412,49 @@ impl SpecSelectorTui {
             )
             .highlight_symbol("> ");
 
-        frame.render_stateful_widget(list, chunks[1], list_state);
+        frame.render_stateful_widget(list, body[0], list_state);
+
+        // Preview of the selected spec's tasks.md
+        let preview = Paragraph::new(self.preview_text(list_state.selected()))
+            .block(Block::default().borders(Borders::ALL).title("tasks.md"))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(preview, body[1]);
 
         // Instructions
-        let instructions = Paragraph::new("↑/↓/j/k: Navigate | Enter: Select | q/Esc: Cancel")
-            .block(Block::default().borders(Borders::ALL));
+        let help_text = if self.filter.editing {
+            "Type to filter | ↑/↓: Navigate | Enter: Apply | Esc: Clear filter"
+        } else if self.filter.is_active() {
+            "↑/↓/j/k: Navigate | /: Edit filter | Enter: Select | Esc: Clear filter"
+        } else {
+            "↑/↓/j/k: Navigate | /: Filter | Enter: Select | q/Esc: Cancel"
+        };
+        let instructions = Paragraph::new(help_text).block(Block::default().borders(Borders::ALL));
         frame.render_widget(instructions, chunks[2]);
     }
 
+    fn preview_text(&self, selected: Option<usize>) -> String {
+        let Some(item) = selected
+            .and_then(|i| self.visible.get(i))
+            .map(|&index| &self.items[index])
+        else {
+            return String::new();
+        };
+
+        match item.metadata_key() {
+            Some(key) => self
+                .metadata
+                .get(&key)
+                .and_then(|metadata| metadata.tasks.clone())
+                .unwrap_or_else(|| "No tasks.md".to_string()),
+            None => String::new(),
+        }
+    }
+
     fn move_cursor_up(&self, list_state: &mut ListState) {
-        let total_items = self.items.len();
+        let total_items = self.visible.len();
+        if total_items == 0 {
+            return;
+        }
         let i = match list_state.selected() {
             Some(i) => {
                 if i == 0 {
@@ -226,7 +469,10 @@ impl SpecSelectorTui {
     }
 
     fn move_cursor_down(&self, list_state: &mut ListState) {
-        let total_items = self.items.len();
+        let total_items = self.visible.len();
+        if total_items == 0 {
+            return;
+        }
         let i = match list_state.selected() {
             Some(i) => {
                 if i >= total_items - 1 {
@@ -241,21 +487,11 @@ impl SpecSelectorTui {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum SpecSelectionResult {
-    SingleSpec(String),
-    Pbi(String),
-    Sbi(String, String), // (pbi_name, sbi_name)
-    CreateNew,
-    CreateNewSbi(String), // pbi_name
-    NoSpec,
-    Cancelled,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::application::test_helpers::MockSpecRepository;
+    use crate::infrastructure::tui::testing::{ScriptedEvents, assert_screen, test_terminal};
 
     #[test]
     fn test_spec_selector_new() {
@@ -282,6 +518,291 @@ mod tests {
         assert_eq!(selector.items.len(), 2);
     }
 
-    // Note: We don't test run() method in unit tests as it requires terminal interaction
-    // This should be tested in integration tests with mock terminals
+    #[test]
+    fn test_spec_selector_collects_metadata() {
+        let specs = vec![
+            ("2025-01-02-pbi".to_string(), false),
+            ("2025-01-01-feature".to_string(), false),
+        ];
+
+        let mock_repo = MockSpecRepository::with_specs(vec![
+            "2025-01-02-pbi".to_string(),
+            "2025-01-01-feature".to_string(),
+        ]);
+        mock_repo.add_sbi("2025-01-02-pbi", "sbi-1-api");
+        mock_repo.add_file("2025-01-01-feature", "tasks.md", "- [x] done\n");
+        mock_repo.add_file("2025-01-02-pbi/sbi-1-api", "design.md", "# Design");
+
+        let selector = SpecSelectorTui::new(specs, &mock_repo);
+
+        let feature = &selector.metadata["2025-01-01-feature"];
+        assert_eq!(feature.progress.unwrap().percentage(), 100);
+
+        // SBIs take their age from the PBI date prefix
+        let sbi = &selector.metadata["2025-01-02-pbi/sbi-1-api"];
+        assert_eq!(sbi.created.unwrap().to_string(), "2025-01-02");
+        assert_eq!(sbi.documents, vec!["design"]);
+    }
+
+    #[test]
+    fn test_spec_selector_preview_text() {
+        let specs = vec![
+            ("2025-01-01-feature".to_string(), false),
+            ("2025-01-02-empty".to_string(), false),
+        ];
+        let mock_repo = MockSpecRepository::new();
+        mock_repo.add_file("2025-01-01-feature", "tasks.md", "# Tasks");
+
+        let selector = SpecSelectorTui::new(specs, &mock_repo);
+
+        // Items: LaunchWithoutSpec, CreateNewSpec, feature, empty
+        assert_eq!(selector.preview_text(Some(0)), "");
+        assert_eq!(selector.preview_text(Some(2)), "# Tasks");
+        assert_eq!(selector.preview_text(Some(3)), "No tasks.md");
+        assert_eq!(selector.preview_text(None), "");
+    }
+
+    fn type_filter(selector: &mut SpecSelectorTui, list_state: &mut ListState, query: &str) {
+        selector.handle_key(KeyCode::Char('/'), list_state);
+        for c in query.chars() {
+            selector.handle_key(KeyCode::Char(c), list_state);
+        }
+    }
+
+    fn grouped_selector(mock_repo: &MockSpecRepository) -> SpecSelectorTui {
+        mock_repo.add_sbi("2025-01-02-payments", "sbi-1-api");
+        mock_repo.add_sbi("2025-01-02-payments", "sbi-2-ui");
+        let specs = vec![
+            ("2025-01-02-payments".to_string(), false),
+            ("2025-01-01-login".to_string(), false),
+        ];
+        SpecSelectorTui::new(specs, mock_repo)
+    }
+
+    #[test]
+    fn test_filter_keeps_pbi_with_matching_sbi() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = grouped_selector(&mock_repo);
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        type_filter(&mut selector, &mut list_state, "ui");
+
+        // The PBI header stays above its matching SBI; the non-matching SBI is hidden
+        let visible: Vec<_> = selector
+            .visible
+            .iter()
+            .map(|&i| selector.items[i].clone())
+            .collect();
+        assert!(matches!(&visible[0], TuiItem::LaunchWithoutSpec));
+        assert!(matches!(&visible[1], TuiItem::CreateNewSpec));
+        assert!(matches!(&visible[2], TuiItem::Pbi { name, .. } if name == "2025-01-02-payments"));
+        assert!(matches!(&visible[3], TuiItem::Sbi { sbi_name, .. } if sbi_name == "sbi-2-ui"));
+        assert_eq!(visible.len(), 4);
+        assert_eq!(list_state.selected(), Some(2));
+    }
+
+    #[test]
+    fn test_filter_matching_pbi_shows_whole_group() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = grouped_selector(&mock_repo);
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        type_filter(&mut selector, &mut list_state, "paym");
+
+        // The two actions, then the PBI, two SBIs and "Create new SBI"
+        assert_eq!(selector.visible.len(), 6);
+        assert!(selector.highlights.contains_key(&selector.visible[2]));
+    }
+
+    #[test]
+    fn test_filter_then_select() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = grouped_selector(&mock_repo);
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        type_filter(&mut selector, &mut list_state, "login");
+        assert_eq!(selector.handle_key(KeyCode::Enter, &mut list_state), None);
+        assert!(!selector.filter.editing);
+
+        // The cursor starts on the match
+        assert_eq!(
+            selector.handle_key(KeyCode::Enter, &mut list_state),
+            Some(SpecSelectionResult::SingleSpec(
+                "2025-01-01-login".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_filter_escape_restores_list() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = grouped_selector(&mock_repo);
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        // Without matches the actions remain, with the cursor on the first one
+        type_filter(&mut selector, &mut list_state, "zzz");
+        assert_eq!(selector.visible, vec![0, 1]);
+        assert_eq!(list_state.selected(), Some(0));
+        selector.handle_key(KeyCode::Down, &mut list_state);
+
+        // Esc while editing clears the filter
+        selector.handle_key(KeyCode::Esc, &mut list_state);
+        assert_eq!(selector.visible.len(), selector.items.len());
+        assert_eq!(list_state.selected(), Some(0));
+
+        // Esc without a filter cancels
+        assert_eq!(
+            selector.handle_key(KeyCode::Esc, &mut list_state),
+            Some(SpecSelectionResult::Cancelled)
+        );
+    }
+
+    #[test]
+    fn test_typing_q_while_filtering_does_not_cancel() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = grouped_selector(&mock_repo);
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        selector.handle_key(KeyCode::Char('/'), &mut list_state);
+        assert_eq!(
+            selector.handle_key(KeyCode::Char('q'), &mut list_state),
+            None
+        );
+        assert_eq!(selector.filter.query, "q");
+    }
+
+    fn snapshot_selector(mock_repo: &MockSpecRepository) -> SpecSelectorTui {
+        mock_repo.add_sbi("2025-01-02-payments", "sbi-1-api");
+        mock_repo.add_file(
+            "2025-01-02-payments/sbi-1-api",
+            "tasks.md",
+            "## Timeline\n\n- [x] Spec created\n- [ ] Design",
+        );
+        mock_repo.add_file("2025-01-01-login", "requirements.md", "# Req");
+
+        let specs = vec![
+            ("2025-01-02-payments".to_string(), false),
+            ("2025-01-01-login".to_string(), false),
+        ];
+        let mut selector = SpecSelectorTui::new(specs, mock_repo);
+        // Pin the clock so ages and modification times are stable
+        selector.today = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+        selector.now = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(2 * 3600);
+        selector
+    }
+
+    #[test]
+    fn test_run_renders_initial_screen_and_cancels() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = snapshot_selector(&mock_repo);
+        let mut terminal = test_terminal(80, 14);
+        let mut events = ScriptedEvents::keys([KeyCode::Char('q')]);
+
+        let result = selector.run_with(&mut terminal, &mut events).unwrap();
+
+        assert_eq!(result, SpecSelectionResult::Cancelled);
+        assert_screen(
+            &terminal,
+            &[
+                "┌Kiro Specifications───────────────────────────────────────────────────────────┐",
+                "│Select a specification to work on                                             │",
+                "└──────────────────────────────────────────────────────────────────────────────┘",
+                "┌──────────────────────────────────────────────┐┌tasks.md──────────────────────┐",
+                "│> 🚀 Launch without specification             ││                              │",
+                "│  📝 Create new specification                 ││                              │",
+                "│     2025-01-02-payments  1d                  ││                              │",
+                "│       sbi-1-api  1d · 1/2 · 2h ago           ││                              │",
+                "│       📝 Create new SBI specification        ││                              │",
+                "│     2025-01-01-login  2d · req · 2h ago      ││                              │",
+                "└──────────────────────────────────────────────┘└──────────────────────────────┘",
+                "┌──────────────────────────────────────────────────────────────────────────────┐",
+                "│↑/↓/j/k: Navigate | /: Filter | Enter: Select | q/Esc: Cancel                 │",
+                "└──────────────────────────────────────────────────────────────────────────────┘",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_run_navigates_to_sbi_with_preview() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = snapshot_selector(&mock_repo);
+        let mut terminal = test_terminal(80, 14);
+        let mut events = ScriptedEvents::keys([KeyCode::Down, KeyCode::Char('j'), KeyCode::Down])
+            .release(KeyCode::Down)
+            .press(KeyCode::Enter);
+
+        let result = selector.run_with(&mut terminal, &mut events).unwrap();
+
+        // The key release is ignored, so the cursor stops on the SBI
+        assert_eq!(
+            result,
+            SpecSelectionResult::Sbi("2025-01-02-payments".to_string(), "sbi-1-api".to_string())
+        );
+        assert_screen(
+            &terminal,
+            &[
+                "┌Kiro Specifications───────────────────────────────────────────────────────────┐",
+                "│Select a specification to work on                                             │",
+                "└──────────────────────────────────────────────────────────────────────────────┘",
+                "┌──────────────────────────────────────────────┐┌tasks.md──────────────────────┐",
+                "│  🚀 Launch without specification             ││## Timeline                   │",
+                "│  📝 Create new specification                 ││                              │",
+                "│     2025-01-02-payments  1d                  ││- [x] Spec created            │",
+                "│>      sbi-1-api  1d · 1/2 · 2h ago           ││- [ ] Design                  │",
+                "│       📝 Create new SBI specification        ││                              │",
+                "│     2025-01-01-login  2d · req · 2h ago      ││                              │",
+                "└──────────────────────────────────────────────┘└──────────────────────────────┘",
+                "┌──────────────────────────────────────────────────────────────────────────────┐",
+                "│↑/↓/j/k: Navigate | /: Filter | Enter: Select | q/Esc: Cancel                 │",
+                "└──────────────────────────────────────────────────────────────────────────────┘",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_run_filters_and_selects() {
+        let mock_repo = MockSpecRepository::new();
+        let mut selector = snapshot_selector(&mock_repo);
+        let mut terminal = test_terminal(80, 14);
+        let mut events = ScriptedEvents::new()
+            .press(KeyCode::Char('/'))
+            .type_text("lgn");
+
+        // The script runs out while the filter is being edited
+        assert!(selector.run_with(&mut terminal, &mut events).is_err());
+        assert_screen(
+            &terminal,
+            &[
+                "┌Kiro Specifications───────────────────────────────────────────────────────────┐",
+                "│/lgn▏                                                                         │",
+                "└──────────────────────────────────────────────────────────────────────────────┘",
+                "┌──────────────────────────────────────────────┐┌tasks.md──────────────────────┐",
+                "│  🚀 Launch without specification             ││No tasks.md                   │",
+                "│  📝 Create new specification                 ││                              │",
+                "│>    2025-01-01-login  2d · req · 2h ago      ││                              │",
+                "│                                              ││                              │",
+                "│                                              ││                              │",
+                "│                                              ││                              │",
+                "└──────────────────────────────────────────────┘└──────────────────────────────┘",
+                "┌──────────────────────────────────────────────────────────────────────────────┐",
+                "│Type to filter | ↑/↓: Navigate | Enter: Apply | Esc: Clear filter             │",
+                "└──────────────────────────────────────────────────────────────────────────────┘",
+            ],
+        );
+
+        // A new run starts on the first row; the actions stay above the match
+        let mut events =
+            ScriptedEvents::keys([KeyCode::Enter, KeyCode::Down, KeyCode::Down, KeyCode::Enter]);
+        let result = selector.run_with(&mut terminal, &mut events).unwrap();
+        assert_eq!(
+            result,
+            SpecSelectionResult::SingleSpec("2025-01-01-login".to_string())
+        );
+        assert_eq!(events.remaining(), 0);
+    }
 }
@@ -0,0 +1,60 @@
+use std::io::{self, Write};
+
+use crate::application::errors::ApplicationError;
+use crate::application::repositories::{
+    SpecPromptInterface, SpecRepositoryInterface, SpecSelectionResult,
+};
+use crate::infrastructure::tui::spec_selector::SpecSelectorTui;
+
+/// Interactive prompts on the real terminal: the spec selector TUI and stdin questions
+pub struct TerminalPrompt;
+
+impl TerminalPrompt {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn ask(message: &str) -> Result<String, ApplicationError> {
+        print!("{}", message);
+        io::stdout().flush().map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to flush stdout: {}", e))
+        })?;
+
+        let mut name = String::new();
+        io::stdin().read_line(&mut name).map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to read input: {}", e))
+        })?;
+
+        Ok(name.trim().to_string())
+    }
+}
+
+impl Default for TerminalPrompt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpecPromptInterface for TerminalPrompt {
+    fn select_spec(
+        &self,
+        spec_repo: &dyn SpecRepositoryInterface,
+    ) -> Result<SpecSelectionResult, ApplicationError> {
+        let specs = spec_repo.list_spec_directories().map_err(|e| {
+            ApplicationError::FileSystemError(format!("Failed to list specifications: {}", e))
+        })?;
+
+        // Includes new spec and SBI options
+        let mut tui = SpecSelectorTui::new(specs, spec_repo);
+        tui.run()
+            .map_err(|e| ApplicationError::FileSystemError(format!("TUI error: {}", e)))
+    }
+
+    fn ask_spec_name(&self) -> Result<String, ApplicationError> {
+        Self::ask("Enter specification name: ")
+    }
+
+    fn ask_sbi_name(&self) -> Result<String, ApplicationError> {
+        Self::ask("Enter SBI name (kebab-case): ")
+    }
+}
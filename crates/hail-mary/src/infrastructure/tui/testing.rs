@@ -0,0 +1,101 @@
+//! Headless harness for driving the TUIs against ratatui's `TestBackend`
+
+use crate::infrastructure::tui::events::EventSource;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer, text::Span};
+use std::collections::VecDeque;
+use std::io;
+
+/// Replays a fixed sequence of key events; fails once the script runs out
+#[derive(Default)]
+pub struct ScriptedEvents {
+    events: VecDeque<Event>,
+}
+
+impl ScriptedEvents {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn keys(codes: impl IntoIterator<Item = KeyCode>) -> Self {
+        let mut events = Self::new();
+        for code in codes {
+            events = events.press(code);
+        }
+        events
+    }
+
+    pub fn press(mut self, code: KeyCode) -> Self {
+        self.events
+            .push_back(Event::Key(KeyEvent::new(code, KeyModifiers::NONE)));
+        self
+    }
+
+    /// Key presses for each character of `text`
+    pub fn type_text(mut self, text: &str) -> Self {
+        for c in text.chars() {
+            self = self.press(KeyCode::Char(c));
+        }
+        self
+    }
+
+    pub fn release(mut self, code: KeyCode) -> Self {
+        let mut key = KeyEvent::new(code, KeyModifiers::NONE);
+        key.kind = KeyEventKind::Release;
+        self.events.push_back(Event::Key(key));
+        self
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.events.len()
+    }
+}
+
+impl EventSource for ScriptedEvents {
+    fn next_event(&mut self) -> io::Result<Event> {
+        self.events
+            .pop_front()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "event script exhausted"))
+    }
+}
+
+pub fn test_terminal(width: u16, height: u16) -> Terminal<TestBackend> {
+    Terminal::new(TestBackend::new(width, height)).expect("test terminal")
+}
+
+/// Compares the rendered text of the terminal with `expected`, ignoring styles.
+/// Cells covered by a wide character (e.g. emoji) are skipped, since the backend
+/// never receives updates for them.
+pub fn assert_screen(terminal: &Terminal<TestBackend>, expected: &[&str]) {
+    let actual = terminal.backend().buffer();
+    let expected_buffer = Buffer::with_lines(expected.iter().copied());
+
+    let mut matches = actual.area == expected_buffer.area;
+    let width = expected_buffer.area.width as usize;
+    let mut skip = 0;
+
+    for (i, (actual_cell, expected_cell)) in actual
+        .content
+        .iter()
+        .zip(&expected_buffer.content)
+        .enumerate()
+    {
+        if i % width == 0 {
+            skip = 0;
+        }
+        if skip > 0 {
+            skip -= 1;
+            continue;
+        }
+        if actual_cell.symbol() != expected_cell.symbol() {
+            matches = false;
+        }
+        skip = Span::raw(expected_cell.symbol()).width().saturating_sub(1);
+    }
+
+    assert!(
+        matches,
+        "screen mismatch\nexpected: {:#?}\nactual: {:#?}",
+        expected_buffer, actual
+    );
+}
@@ -1,8 +1,13 @@
 use anyhow::Result;
 use clap::Parser;
-use hail_mary::cli::args::{Cli, Commands, SteeringCommands};
-use hail_mary::cli::commands::{CodeCommand, CompleteCommand, SteeringBackupCommand, completion};
+use hail_mary::application::use_cases::SpecSelection;
+use hail_mary::cli::args::{Cli, Commands, ConfigArgs, SteeringCommands};
+use hail_mary::cli::commands::{
+    CodeCommand, CompleteCommand, ConfigCommand, InitCommand, SlashCommandsCommand, SpecCommand,
+    StatusCommand, SteeringBackupCommand, SteeringCommand, completion,
+};
 use hail_mary::cli::formatters::format_error;
+use hail_mary::domain::value_objects::config_override::ConfigOverrides;
 use std::process;
 
 #[tokio::main]
@@ -20,24 +25,70 @@ async fn run() -> Result<()> {
         Commands::Completion { shell } => {
             completion::handle_completion(&shell)?;
         }
-        Commands::Complete => {
-            let command = CompleteCommand::new();
+        Commands::Complete { on_conflict } => {
+            let command = CompleteCommand::new(on_conflict.into());
+            command.execute()?;
+        }
+        Commands::Init { dry_run } => {
+            let command = InitCommand::new(dry_run);
             command.execute()?;
         }
         Commands::Code {
             no_danger,
             continue_conversation,
+            spec,
+            sbi,
+            new,
+            no_spec,
+            overrides,
         } => {
-            let command = CodeCommand::new(no_danger, continue_conversation);
+            let config_overrides = config_overrides(&overrides)?;
+            let selection = SpecSelection::from_flags(spec, sbi, new, no_spec);
+            let command = CodeCommand::new(no_danger, continue_conversation, selection)
+                .with_config_overrides(config_overrides);
             command.execute()?;
         }
-        Commands::Steering { command } => match command {
-            SteeringCommands::Backup => {
-                let backup_command = SteeringBackupCommand::new();
-                backup_command.execute()?;
+        Commands::Status { spec, json } => {
+            let status_command = StatusCommand::new(spec, json);
+            status_command.execute()?;
+        }
+        Commands::Spec { command, overrides } => {
+            let config_overrides = config_overrides(&overrides)?;
+            let spec_command = SpecCommand::new(command).with_config_overrides(config_overrides);
+            spec_command.execute()?;
+        }
+        Commands::Steering { command, overrides } => {
+            let config_overrides = config_overrides(&overrides)?;
+            match command {
+                SteeringCommands::Backup { command: None } => {
+                    let backup_command =
+                        SteeringBackupCommand::new().with_config_overrides(config_overrides);
+                    backup_command.execute()?;
+                }
+                command => {
+                    let steering_command =
+                        SteeringCommand::new(command).with_config_overrides(config_overrides);
+                    steering_command.execute()?;
+                }
             }
-        },
+        }
+        Commands::Config { command, overrides } => {
+            let config_overrides = config_overrides(&overrides)?;
+            let config_command =
+                ConfigCommand::new(command).with_config_overrides(config_overrides);
+            config_command.execute()?;
+        }
+        Commands::SlashCommands { command } => {
+            let slash_commands_command = SlashCommandsCommand::new(command);
+            slash_commands_command.execute()?;
+        }
     }
 
     Ok(())
 }
+
+/// Only commands that read config parse the overrides, so a bad `HAIL_MARY_*`
+/// variable cannot break the others
+fn config_overrides(overrides: &ConfigArgs) -> Result<ConfigOverrides> {
+    overrides.overrides().map_err(|e| anyhow::anyhow!(e))
+}
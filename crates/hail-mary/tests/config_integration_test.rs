@@ -0,0 +1,248 @@
+use hail_mary::application::repositories::ConfigRepositoryInterface;
+use hail_mary::domain::value_objects::config_source::ConfigSource;
+use hail_mary::domain::value_objects::steering::SteeringConfig;
+use hail_mary::infrastructure::filesystem::path_manager::PathManager;
+use hail_mary::infrastructure::repositories::config::ConfigRepository;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::{TempDir, tempdir};
+
+fn project(config: Option<&str>) -> (TempDir, PathBuf, ConfigRepository) {
+    let temp_dir = tempdir().unwrap();
+    let config_path = temp_dir.path().join(".kiro/config.toml");
+    if let Some(config) = config {
+        fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+        fs::write(&config_path, config).unwrap();
+    }
+    let repo =
+        ConfigRepository::with_user_config(PathManager::new(temp_dir.path().to_path_buf()), None);
+    (temp_dir, config_path, repo)
+}
+
+/// A project plus a user-level config at `<tmp>/xdg/hail-mary/config.toml`
+fn layered_project(user: &str, project: Option<&str>) -> (TempDir, PathBuf, ConfigRepository) {
+    let (temp_dir, config_path, _) = self::project(project);
+    let user_path = temp_dir.path().join("xdg/hail-mary/config.toml");
+    fs::create_dir_all(user_path.parent().unwrap()).unwrap();
+    fs::write(&user_path, user).unwrap();
+
+    let repo = ConfigRepository::with_user_config(
+        PathManager::new(temp_dir.path().to_path_buf()),
+        Some(user_path),
+    );
+    (temp_dir, config_path, repo)
+}
+
+const USER_CONFIG: &str = r#"[spec]
+lang = "en"
+
+[[steering.types]]
+name = "tech"
+purpose = "Shared tech notes"
+criteria = ["Architecture: High-level system design"]
+allowed_operations = ["refresh"]
+
+[[steering.types]]
+name = "security"
+purpose = "Security policies"
+criteria = ["Threat Model: Assets and attackers"]
+include_in_prompt = false
+"#;
+
+fn ensure_all(repo: &ConfigRepository) {
+    repo.ensure_steering_config().unwrap();
+    repo.ensure_steering_backup_config().unwrap();
+    repo.ensure_allowed_operations().unwrap();
+    repo.ensure_spec_config().unwrap();
+}
+
+#[test]
+fn test_ensure_keeps_comments_key_order_and_whitespace() {
+    let config = r#"# hail-mary project settings
+
+[[steering.types]]
+name = "tech"   # the stack
+purpose = "Technical stack"
+criteria = [
+    "Architecture: High-level system design",
+]
+
+[[steering.types]]
+name = "custom"
+purpose = "Team notes"
+criteria = ["Notes: Anything"]
+include_in_prompt = false
+"#;
+    let (_temp_dir, config_path, repo) = project(Some(config));
+
+    ensure_all(&repo);
+
+    let expected = r#"# hail-mary project settings
+
+[[steering.types]]
+name = "tech"   # the stack
+purpose = "Technical stack"
+criteria = [
+    "Architecture: High-level system design",
+]
+allowed_operations = ["refresh", "discover"]
+
+[[steering.types]]
+name = "custom"
+purpose = "Team notes"
+criteria = ["Notes: Anything"]
+include_in_prompt = false
+allowed_operations = []
+
+[steering.backup]
+max = 10
+
+[spec]
+lang = "ja"
+"#;
+    assert_eq!(fs::read_to_string(&config_path).unwrap(), expected);
+
+    // A second pass has nothing to add and leaves the file byte-for-byte alone
+    ensure_all(&repo);
+    assert_eq!(fs::read_to_string(&config_path).unwrap(), expected);
+}
+
+#[test]
+fn test_ensure_on_complete_config_is_a_no_op() {
+    let config = r#"[spec]
+lang = "en" # English
+
+[steering.backup]
+max = 3
+
+# Only one type
+[[steering.types]]
+allowed_operations = []
+name = "tech"
+purpose = "Stack"
+criteria = ["Architecture: Design"]
+"#;
+    let (_temp_dir, config_path, repo) = project(Some(config));
+
+    ensure_all(&repo);
+
+    assert_eq!(fs::read_to_string(&config_path).unwrap(), config);
+    assert_eq!(repo.load_steering_backup_config().unwrap().max, 3);
+}
+
+#[test]
+fn test_ensure_on_new_project_writes_readable_defaults() {
+    let (_temp_dir, config_path, repo) = project(None);
+
+    ensure_all(&repo);
+
+    let written = fs::read_to_string(&config_path).unwrap();
+    assert!(written.starts_with(
+        r#"[[steering.types]]
+name = "product"
+purpose = "Product overview and value proposition"
+criteria = [
+    "Product Overview: Brief description of what the product is",
+"#
+    ));
+    assert_eq!(
+        repo.load_steering_config().unwrap().types,
+        SteeringConfig::default_for_new_project().types
+    );
+    assert_eq!(repo.load_spec_config().unwrap().lang, "ja");
+}
+
+#[test]
+fn test_project_config_layers_over_user_config() {
+    let project = r#"[steering.backup]
+max = 4
+
+[[steering.types]]
+name = "tech"
+purpose = "Project tech"
+criteria = ["Stack: Rust workspace"]
+"#;
+    let (_temp_dir, _config_path, repo) = layered_project(USER_CONFIG, Some(project));
+
+    assert_eq!(repo.load_spec_config().unwrap().lang, "en");
+
+    let steering = repo.load_steering_config().unwrap();
+    assert_eq!(steering.backup.max, 4);
+    let names: Vec<_> = steering.types.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["tech", "security"]);
+    // The project's tech entry replaces the user's entirely
+    assert_eq!(steering.types[0].purpose, "Project tech");
+    assert!(steering.types[0].allowed_operations.is_empty());
+    assert!(!steering.types[1].include_in_prompt);
+
+    let sources: Vec<(String, ConfigSource)> = repo
+        .load_effective_config()
+        .unwrap()
+        .into_iter()
+        .map(|setting| (setting.key, setting.source))
+        .collect();
+    for (key, source) in [
+        ("spec.lang", ConfigSource::User),
+        ("steering.backup.max", ConfigSource::Project),
+        ("steering.types.tech.purpose", ConfigSource::Project),
+        (
+            "steering.types.tech.include_in_prompt",
+            ConfigSource::Default,
+        ),
+        (
+            "steering.types.security.include_in_prompt",
+            ConfigSource::User,
+        ),
+        ("agent.backend", ConfigSource::Default),
+    ] {
+        assert!(
+            sources.contains(&(key.to_string(), source)),
+            "{} from {}: {:?}",
+            key,
+            source,
+            sources
+        );
+    }
+}
+
+#[test]
+fn test_ensure_does_not_shadow_user_config() {
+    let (_temp_dir, config_path, repo) = layered_project(USER_CONFIG, None);
+
+    ensure_all(&repo);
+
+    // Defaults are only written for what the user config leaves unset
+    assert_eq!(
+        fs::read_to_string(&config_path).unwrap(),
+        "[steering.backup]\nmax = 10\n"
+    );
+    let names: Vec<_> = repo
+        .load_steering_config()
+        .unwrap()
+        .types
+        .into_iter()
+        .map(|t| t.name)
+        .collect();
+    assert_eq!(names, vec!["tech", "security"]);
+    assert_eq!(repo.load_spec_config().unwrap().lang, "en");
+}
+
+#[test]
+fn test_validate_reports_problems_per_layer() {
+    let (temp_dir, _config_path, repo) =
+        layered_project("[spec]\nlang = 1\n", Some("[spec]\nlang = \"en\"\n"));
+
+    let results = repo.validate_config().unwrap();
+
+    let paths: Vec<PathBuf> = results.iter().map(|file| file.path.clone()).collect();
+    assert_eq!(
+        paths,
+        vec![
+            temp_dir.path().join("xdg/hail-mary/config.toml"),
+            PathBuf::from(".kiro/config.toml"),
+        ]
+    );
+    assert_eq!(results[0].diagnostics.len(), 1);
+    assert_eq!(results[0].diagnostics[0].line, 2);
+    assert!(results[1].diagnostics.is_empty());
+}
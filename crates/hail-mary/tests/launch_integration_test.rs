@@ -0,0 +1,330 @@
+use hail_mary::application::errors::ApplicationError;
+use hail_mary::application::repositories::SpecSelectionResult;
+use hail_mary::application::test_helpers::MockSpecPrompt;
+use hail_mary::application::use_cases::{
+    LaunchOptions, SpecSelection, initialize_project, launch_claude_with_spec,
+};
+use hail_mary::domain::value_objects::launch_command::{LaunchCommand, SessionFlags};
+use hail_mary::infrastructure::filesystem::path_manager::PathManager;
+use hail_mary::infrastructure::process::recording_launcher::RecordingLauncher;
+use hail_mary::infrastructure::repositories::{
+    config::ConfigRepository, spec::SpecRepository, steering::SteeringRepository,
+};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tempfile::{TempDir, tempdir};
+
+struct Project {
+    _temp_dir: TempDir,
+    root: PathBuf,
+    path_manager: PathManager,
+}
+
+impl Project {
+    /// An initialized project with a single spec and a PBI containing one SBI
+    fn new() -> Self {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        let path_manager = PathManager::new(root.clone());
+
+        let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+        let steering_repo = SteeringRepository::new(path_manager.clone());
+        let spec_repo = SpecRepository::new(path_manager.clone());
+        initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+        let specs_dir = root.join(".kiro/specs");
+        std::fs::create_dir_all(specs_dir.join("2025-01-01-login")).unwrap();
+        std::fs::create_dir_all(specs_dir.join("2025-01-02-payments/sbi-1-api")).unwrap();
+
+        Self {
+            _temp_dir: temp_dir,
+            root,
+            path_manager,
+        }
+    }
+
+    fn launch(
+        &self,
+        prompt: &MockSpecPrompt,
+        selection: SpecSelection,
+        flags: SessionFlags,
+    ) -> (Result<(), ApplicationError>, RecordingLauncher) {
+        let launcher = RecordingLauncher::new();
+        let result = launch_claude_with_spec(
+            &SpecRepository::new(self.path_manager.clone()),
+            &ConfigRepository::with_user_config(self.path_manager.clone(), None),
+            &SteeringRepository::new(self.path_manager.clone()),
+            prompt,
+            &launcher,
+            selection,
+            LaunchOptions {
+                flags,
+                project_root: &self.root,
+            },
+        );
+        (result, launcher)
+    }
+
+    fn launch_interactive(
+        &self,
+        selection: SpecSelectionResult,
+        answers: &[&str],
+    ) -> LaunchCommand {
+        let prompt = answers
+            .iter()
+            .fold(MockSpecPrompt::selecting(selection), |prompt, answer| {
+                prompt.with_answer(answer)
+            });
+        let (result, launcher) =
+            self.launch(&prompt, SpecSelection::Interactive, SessionFlags::default());
+        result.unwrap();
+
+        let launches = launcher.launches();
+        assert_eq!(launches.len(), 1);
+        launches.into_iter().next().unwrap()
+    }
+
+    fn append_config(&self, toml: &str) {
+        let config_path = self.root.join(".kiro/config.toml");
+        let mut config = std::fs::read_to_string(&config_path).unwrap();
+        config.push_str(toml);
+        std::fs::write(config_path, config).unwrap();
+    }
+
+    fn spec_path(&self, relative: &str) -> PathBuf {
+        self.root.join(".kiro/specs").join(relative)
+    }
+}
+
+/// Asserts the full argv for a default (`--dangerously-skip-permissions`) session
+fn assert_argv(command: &LaunchCommand, settings: serde_json::Value) {
+    assert_eq!(command.program, "claude");
+
+    let prompt = command.arg_value("--append-system-prompt").unwrap();
+    assert_eq!(
+        command.args,
+        vec![
+            "--append-system-prompt".to_string(),
+            prompt.to_string(),
+            "--permission-mode".to_string(),
+            "plan".to_string(),
+            "--settings".to_string(),
+            settings.to_string(),
+            "--dangerously-skip-permissions".to_string(),
+        ]
+    );
+    assert_eq!(command.settings(), Some(settings));
+}
+
+fn assert_env(command: &LaunchCommand) {
+    let env: Vec<(&str, &str)> = command
+        .env
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+    assert_eq!(
+        env,
+        vec![
+            ("DISABLE_INTERLEAVED_THINKING", "1"),
+            ("DISABLE_MICROCOMPACT", "1"),
+            ("FORCE_AUTO_BACKGROUND_TASKS", "1"),
+            ("ENABLE_BACKGROUND_TASKS", "1"),
+            ("CLAUDE_BASH_MAINTAIN_PROJECT_WORKING_DIR", "1"),
+        ]
+    );
+}
+
+fn assert_prompt_spec_path(command: &LaunchCommand, path: &Path) {
+    let prompt = command.arg_value("--append-system-prompt").unwrap();
+    assert!(prompt.contains(&format!("<spec-path>{}</spec-path>", path.display())));
+}
+
+#[test]
+fn test_launch_single_spec() {
+    let project = Project::new();
+
+    let command = project.launch_interactive(
+        SpecSelectionResult::SingleSpec("2025-01-01-login".to_string()),
+        &[],
+    );
+
+    assert_argv(
+        &command,
+        json!({ "plansDirectory": ".kiro/specs/2025-01-01-login/plans" }),
+    );
+    assert_env(&command);
+    assert_prompt_spec_path(&command, &project.spec_path("2025-01-01-login"));
+}
+
+#[test]
+fn test_launch_pbi() {
+    let project = Project::new();
+
+    let command = project.launch_interactive(
+        SpecSelectionResult::Pbi("2025-01-02-payments".to_string()),
+        &[],
+    );
+
+    assert_argv(
+        &command,
+        json!({ "plansDirectory": ".kiro/specs/2025-01-02-payments/plans" }),
+    );
+    assert_prompt_spec_path(&command, &project.spec_path("2025-01-02-payments"));
+}
+
+#[test]
+fn test_launch_sbi_generates_missing_files() {
+    let project = Project::new();
+
+    let command = project.launch_interactive(
+        SpecSelectionResult::Sbi("2025-01-02-payments".to_string(), "sbi-1-api".to_string()),
+        &[],
+    );
+
+    assert_argv(
+        &command,
+        json!({ "plansDirectory": ".kiro/specs/2025-01-02-payments/sbi-1-api/plans" }),
+    );
+    assert_prompt_spec_path(
+        &command,
+        &project.spec_path("2025-01-02-payments/sbi-1-api"),
+    );
+    assert!(
+        project
+            .spec_path("2025-01-02-payments/sbi-1-api/tasks.md")
+            .exists()
+    );
+}
+
+#[test]
+fn test_launch_create_new_spec() {
+    let project = Project::new();
+
+    let command = project.launch_interactive(SpecSelectionResult::CreateNew, &["checkout"]);
+
+    let date = chrono::Utc::now().format("%Y-%m-%d");
+    let name = format!("{}-checkout", date);
+    assert_argv(
+        &command,
+        json!({ "plansDirectory": format!(".kiro/specs/{}/plans", name) }),
+    );
+    assert_prompt_spec_path(&command, &project.spec_path(&name));
+    assert!(project.spec_path(&name).join("tasks.md").exists());
+}
+
+#[test]
+fn test_launch_create_new_sbi() {
+    let project = Project::new();
+
+    let command = project.launch_interactive(
+        SpecSelectionResult::CreateNewSbi("2025-01-02-payments".to_string()),
+        &["webhooks"],
+    );
+
+    assert_argv(
+        &command,
+        json!({ "plansDirectory": ".kiro/specs/2025-01-02-payments/sbi-2-webhooks/plans" }),
+    );
+    assert_prompt_spec_path(
+        &command,
+        &project.spec_path("2025-01-02-payments/sbi-2-webhooks"),
+    );
+}
+
+#[test]
+fn test_launch_without_spec_uses_steering_only_prompt() {
+    let project = Project::new();
+
+    let command = project.launch_interactive(SpecSelectionResult::NoSpec, &[]);
+
+    assert_argv(&command, json!({}));
+    let prompt = command.arg_value("--append-system-prompt").unwrap();
+    assert!(!prompt.contains("<spec-path>"));
+}
+
+#[test]
+fn test_launch_cancelled_does_not_launch() {
+    let project = Project::new();
+    let prompt = MockSpecPrompt::selecting(SpecSelectionResult::Cancelled);
+
+    let (result, launcher) =
+        project.launch(&prompt, SpecSelection::Interactive, SessionFlags::default());
+
+    assert!(result.is_ok());
+    assert!(launcher.launches().is_empty());
+}
+
+#[test]
+fn test_launch_flags_from_command_line() {
+    let project = Project::new();
+    let prompt = MockSpecPrompt::selecting(SpecSelectionResult::Cancelled);
+
+    let (result, launcher) = project.launch(
+        &prompt,
+        SpecSelection::Existing {
+            spec: "login".to_string(),
+            sbi: None,
+        },
+        SessionFlags {
+            no_danger: true,
+            continue_conversation: true,
+        },
+    );
+    result.unwrap();
+
+    let command = launcher.last().unwrap();
+    let prompt = command.arg_value("--append-system-prompt").unwrap();
+    assert_eq!(
+        command.args,
+        vec![
+            "--append-system-prompt",
+            prompt,
+            "--permission-mode",
+            "plan",
+            "--settings",
+            r#"{"plansDirectory":".kiro/specs/2025-01-01-login/plans"}"#,
+            "--continue",
+        ]
+    );
+}
+
+#[test]
+fn test_launch_configured_agent_backend() {
+    let project = Project::new();
+    project.append_config(
+        "\n[agent]\nbackend = \"opencode\"\nargs = [\"--model\", \"anthropic/claude-sonnet-4\"]\n",
+    );
+
+    let command = project.launch_interactive(
+        SpecSelectionResult::SingleSpec("2025-01-01-login".to_string()),
+        &[],
+    );
+
+    assert_eq!(command.program, "opencode");
+    let prompt = command.arg_value("--prompt").unwrap();
+    assert_eq!(
+        command.args,
+        vec!["--prompt", prompt, "--model", "anthropic/claude-sonnet-4"]
+    );
+    assert!(command.env.is_empty());
+    assert!(prompt.contains(&format!(
+        "<spec-path>{}</spec-path>",
+        project.spec_path("2025-01-01-login").display()
+    )));
+}
+
+#[test]
+fn test_launch_rejects_unknown_agent_backend() {
+    let project = Project::new();
+    project.append_config("\n[agent]\nbackend = \"cursor\"\n");
+    let prompt = MockSpecPrompt::selecting(SpecSelectionResult::NoSpec);
+
+    let (result, launcher) =
+        project.launch(&prompt, SpecSelection::Interactive, SessionFlags::default());
+
+    assert!(matches!(
+        result,
+        Err(ApplicationError::ConfigurationError(_))
+    ));
+    assert!(launcher.launches().is_empty());
+}
@@ -0,0 +1,145 @@
+use hail_mary::application::repositories::SpecRepositoryInterface;
+use hail_mary::infrastructure::filesystem::path_manager::PathManager;
+use hail_mary::infrastructure::repositories::spec::SpecRepository;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::{TempDir, tempdir};
+
+fn project() -> (TempDir, PathManager, SpecRepository) {
+    let temp_dir = tempdir().unwrap();
+    let path_manager = PathManager::new(temp_dir.path().to_path_buf());
+    let repo = SpecRepository::new(path_manager.clone());
+    (temp_dir, path_manager, repo)
+}
+
+fn write_template(path_manager: &PathManager, set: &str, file_name: &str, content: &str) {
+    let dir = path_manager.templates_dir(true).join(set);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(file_name), content).unwrap();
+}
+
+fn spec_dir(repo: &SpecRepository, name: &str) -> PathBuf {
+    let date = chrono::Utc::now().format("%Y-%m-%d");
+    repo.get_spec_path(&format!("{}-{}", date, name)).unwrap()
+}
+
+#[test]
+fn test_create_spec_uses_builtin_templates_without_project_templates() {
+    let (_temp_dir, _path_manager, repo) = project();
+
+    repo.create_spec("login", "en").unwrap();
+
+    let dir = spec_dir(&repo, "login");
+    let tasks = fs::read_to_string(dir.join("tasks.md")).unwrap();
+    assert!(tasks.contains("**Language**: en"));
+    assert!(tasks.contains("- [x] Spec created → login"));
+    assert_eq!(
+        fs::read_to_string(dir.join("memo.md")).unwrap(),
+        "# Memo: login\n\n"
+    );
+}
+
+#[test]
+fn test_create_spec_renders_project_templates() {
+    let (_temp_dir, path_manager, repo) = project();
+    write_template(
+        &path_manager,
+        "spec",
+        "memo.md",
+        "Notes for {name} ({lang})\n",
+    );
+    write_template(
+        &path_manager,
+        "spec",
+        "prd.md",
+        "# PRD: {name}\n\nCreated {date}\n",
+    );
+    write_template(&path_manager, "spec", ".gitkeep", "");
+
+    repo.create_spec("login", "ja").unwrap();
+
+    let dir = spec_dir(&repo, "login");
+    let date = chrono::Utc::now().format("%Y-%m-%d");
+    assert_eq!(
+        fs::read_to_string(dir.join("memo.md")).unwrap(),
+        "Notes for login (ja)\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("prd.md")).unwrap(),
+        format!("# PRD: login\n\nCreated {}\n", date)
+    );
+    // tasks.md falls back to the built-in
+    assert!(
+        fs::read_to_string(dir.join("tasks.md"))
+            .unwrap()
+            .contains("**Language**: ja")
+    );
+    assert!(!dir.join(".gitkeep").exists());
+}
+
+#[test]
+fn test_create_sbi_uses_sbi_template_set() {
+    let (_temp_dir, path_manager, repo) = project();
+    write_template(&path_manager, "spec", "prd.md", "# PRD\n");
+    write_template(
+        &path_manager,
+        "sbi",
+        "requirements.md",
+        "# {name}\n\nPart of {parent}\n",
+    );
+
+    repo.create_spec("auth", "en").unwrap();
+    let pbi = spec_dir(&repo, "auth");
+    let pbi_name = pbi.file_name().unwrap().to_string_lossy().to_string();
+    repo.create_sbi(&pbi_name, "sbi-1-login", "en").unwrap();
+
+    let sbi = pbi.join("sbi-1-login");
+    assert_eq!(
+        fs::read_to_string(sbi.join("requirements.md")).unwrap(),
+        format!("# sbi-1-login\n\nPart of {}\n", pbi_name)
+    );
+    assert!(sbi.join("tasks.md").exists());
+    assert!(sbi.join("memo.md").exists());
+    assert!(!sbi.join("prd.md").exists());
+}
+
+#[test]
+fn test_ensure_sbi_files_keeps_existing_documents() {
+    let (_temp_dir, _path_manager, repo) = project();
+    repo.create_spec("auth", "en").unwrap();
+    let pbi = spec_dir(&repo, "auth");
+    let pbi_name = pbi.file_name().unwrap().to_string_lossy().to_string();
+    let sbi = pbi.join("sbi-1-login");
+    fs::create_dir_all(&sbi).unwrap();
+    fs::write(sbi.join("tasks.md"), "my tasks\n").unwrap();
+
+    repo.ensure_sbi_files(&pbi_name, "sbi-1-login", "en")
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(sbi.join("tasks.md")).unwrap(),
+        "my tasks\n"
+    );
+    assert_eq!(
+        fs::read_to_string(sbi.join("memo.md")).unwrap(),
+        "# Memo: sbi-1-login\n\n"
+    );
+}
+
+#[test]
+fn test_ensure_sbi_files_adds_missing_project_templates() {
+    let (_temp_dir, path_manager, repo) = project();
+    repo.create_spec("auth", "en").unwrap();
+    let pbi = spec_dir(&repo, "auth");
+    let pbi_name = pbi.file_name().unwrap().to_string_lossy().to_string();
+    repo.create_sbi(&pbi_name, "sbi-1-login", "en").unwrap();
+    write_template(&path_manager, "sbi", "design.md", "# Design: {name}\n");
+
+    repo.ensure_sbi_files(&pbi_name, "sbi-1-login", "en")
+        .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(pbi.join("sbi-1-login").join("design.md")).unwrap(),
+        "# Design: sbi-1-login\n"
+    );
+}
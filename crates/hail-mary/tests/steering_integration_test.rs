@@ -1,5 +1,11 @@
+use hail_mary::application::errors::ApplicationError;
+use hail_mary::application::repositories::config_repository::ConfigRepositoryInterface;
 use hail_mary::application::repositories::steering_repository::SteeringRepositoryInterface;
-use hail_mary::application::use_cases::{backup_steering, initialize_project};
+use hail_mary::application::use_cases::{
+    SteeringFileChange, backup_steering, diff_steering_backup, initialize_project,
+    restore_steering_backup,
+};
+use hail_mary::domain::value_objects::steering_backup::{BackupReason, content_hash};
 use hail_mary::infrastructure::filesystem::path_manager::PathManager;
 use hail_mary::infrastructure::repositories::{
     config::ConfigRepository, spec::SpecRepository, steering::SteeringRepository,
@@ -27,6 +33,7 @@ fn test_steering_display_format() {
                 },
             ],
             allowed_operations: vec!["refresh".to_string()],
+            include_in_prompt: true,
         },
         content: "Test content here".to_string(),
     };
@@ -52,6 +59,7 @@ fn test_steerings_display_format_with_individual_tags() {
             purpose: "Product purpose".to_string(),
             criteria: vec![],
             allowed_operations: vec![],
+            include_in_prompt: true,
         },
         content: "Product content".to_string(),
     };
@@ -62,6 +70,7 @@ fn test_steerings_display_format_with_individual_tags() {
             purpose: "Tech purpose".to_string(),
             criteria: vec![],
             allowed_operations: vec![],
+            include_in_prompt: true,
         },
         content: "Tech content".to_string(),
     };
@@ -91,7 +100,7 @@ fn test_backup_rotation_maintains_max_limit() {
 
     // Initialize project
     let path_manager = PathManager::new(temp_path.to_path_buf());
-    let config_repo = ConfigRepository::new(path_manager.clone());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
     let steering_repo = SteeringRepository::new(path_manager.clone());
     let spec_repo = SpecRepository::new(path_manager.clone());
 
@@ -143,9 +152,139 @@ fn test_backup_rotation_maintains_max_limit() {
     // 1. Starting with 3 backups at max limit
     // 2. Creating new backup deletes oldest and maintains max=3
     // 3. Oldest backup was properly removed
+}
+
+#[test]
+fn test_backup_skips_unchanged_and_records_manifest() {
+    let temp_dir = tempdir().unwrap();
+    let path_manager = PathManager::new(temp_dir.path().to_path_buf());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+    let steering_repo = SteeringRepository::new(path_manager.clone());
+    let spec_repo = SpecRepository::new(path_manager.clone());
+    initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+    let steering_dir = temp_dir.path().join(".kiro").join("steering");
+    fs::write(steering_dir.join("tech.md"), "# Tech v1\n").unwrap();
+
+    let first = backup_steering(&config_repo, &steering_repo).unwrap();
+    assert!(first.contains("Created backup"));
+
+    // Identical content: no new backup
+    let second = backup_steering(&config_repo, &steering_repo).unwrap();
+    assert!(second.contains("unchanged"), "{}", second);
+    assert_eq!(steering_repo.list_steering_backups().unwrap().len(), 1);
+
+    // Changed content right away: a distinct backup, even within the same second
+    fs::write(steering_dir.join("tech.md"), "# Tech v2\n").unwrap();
+    backup_steering(&config_repo, &steering_repo).unwrap();
+    let backups = steering_repo.list_steering_backups().unwrap();
+    assert_eq!(backups.len(), 2);
+    assert_ne!(backups[0].name, backups[1].name);
+
+    let manifest = steering_repo
+        .load_backup_manifest(&backups[1].name)
+        .unwrap()
+        .unwrap();
+    assert_eq!(manifest.name, backups[1].name);
+    assert_eq!(manifest.reason, BackupReason::Manual);
+    let tech = manifest
+        .files
+        .iter()
+        .find(|file| file.path.to_str() == Some("tech.md"))
+        .unwrap();
+    assert_eq!(tech.size, 10);
+    assert_eq!(tech.sha256, content_hash(b"# Tech v2\n"));
+
+    // The manifest is not a steering file of the backup
+    let files = steering_repo.list_backup_files(&backups[1].name).unwrap();
+    assert!(files.iter().all(|file| file.extension().unwrap() == "md"));
+}
+
+#[test]
+fn test_backups_share_objects_and_rotation_collects_garbage() {
+    let temp_dir = tempdir().unwrap();
+    let path_manager = PathManager::new(temp_dir.path().to_path_buf());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+    let steering_repo = SteeringRepository::new(path_manager.clone());
+    let spec_repo = SpecRepository::new(path_manager.clone());
+    initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+    let config_path = temp_dir.path().join(".kiro").join("config.toml");
+    let config_content = fs::read_to_string(&config_path).unwrap();
+    fs::write(
+        &config_path,
+        config_content.replace("[steering.backup]\nmax = 10", "[steering.backup]\nmax = 2"),
+    )
+    .unwrap();
+
+    let steering_dir = temp_dir.path().join(".kiro").join("steering");
+    let objects_dir = steering_dir.join("backup").join("objects");
+    let object_count = || fs::read_dir(&objects_dir).unwrap().count();
+    for entry in fs::read_dir(&steering_dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_file() {
+            fs::remove_file(path).unwrap();
+        }
+    }
+    fs::write(steering_dir.join("product.md"), "# Product\n").unwrap();
+
+    for version in 1..=3 {
+        fs::write(
+            steering_dir.join("tech.md"),
+            format!("# Tech v{}\n", version),
+        )
+        .unwrap();
+        backup_steering(&config_repo, &steering_repo).unwrap();
+    }
 
-    // Note: We don't test second backup here since without seconds in timestamp,
-    // it could create same-named backup in same minute and cause issues
+    // max = 2: the first backup was rotated out and its tech.md v1 blob collected,
+    // while the unchanged product.md is stored once for both remaining backups
+    let backups = steering_repo.list_steering_backups().unwrap();
+    assert_eq!(backups.len(), 2);
+    assert_eq!(object_count(), 3);
+    for backup in &backups {
+        assert_eq!(
+            steering_repo
+                .read_backup_file(&backup.name, std::path::Path::new("product.md"))
+                .unwrap(),
+            "# Product\n"
+        );
+    }
+    assert_eq!(
+        steering_repo
+            .read_backup_file(&backups[0].name, std::path::Path::new("tech.md"))
+            .unwrap(),
+        "# Tech v2\n"
+    );
+}
+
+#[test]
+fn test_backup_name_collision_gets_suffix() {
+    let temp_dir = tempdir().unwrap();
+    let path_manager = PathManager::new(temp_dir.path().to_path_buf());
+    let steering_repo = SteeringRepository::new(path_manager);
+    steering_repo.initialize_steering().unwrap();
+    let steering_dir = temp_dir.path().join(".kiro").join("steering");
+    fs::write(steering_dir.join("tech.md"), "# Tech\n").unwrap();
+    let files = steering_repo.list_steering_files().unwrap();
+
+    let names: Vec<String> = (0..3)
+        .map(|_| {
+            steering_repo
+                .create_steering_backup("2025-01-01-10-00-00", &files, BackupReason::Manual)
+                .unwrap()
+                .name
+        })
+        .collect();
+
+    assert_eq!(
+        names,
+        vec![
+            "2025-01-01-10-00-00",
+            "2025-01-01-10-00-00-2",
+            "2025-01-01-10-00-00-3"
+        ]
+    );
 }
 
 #[test]
@@ -156,7 +295,7 @@ fn test_backup_rotation_with_excess_backups() {
 
     // Initialize project
     let path_manager = PathManager::new(temp_path.to_path_buf());
-    let config_repo = ConfigRepository::new(path_manager.clone());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
     let steering_repo = SteeringRepository::new(path_manager.clone());
     let spec_repo = SpecRepository::new(path_manager.clone());
 
@@ -218,3 +357,446 @@ fn test_backup_rotation_with_excess_backups() {
         "Backup 4 should be deleted"
     );
 }
+
+#[test]
+fn test_prompt_steerings_exclude_disabled_types() {
+    use hail_mary::application::repositories::ConfigRepositoryInterface;
+
+    let temp_dir = tempdir().unwrap();
+    let temp_path = temp_dir.path();
+
+    let path_manager = PathManager::new(temp_path.to_path_buf());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+    let steering_repo = SteeringRepository::new(path_manager.clone());
+
+    let kiro_dir = temp_path.join(".kiro");
+    let steering_dir = kiro_dir.join("steering");
+    fs::create_dir_all(&steering_dir).unwrap();
+    fs::write(
+        kiro_dir.join("config.toml"),
+        r#"
+[[steering.types]]
+name = "product"
+purpose = "Product overview"
+criteria = ["Overview: Description"]
+
+[[steering.types]]
+name = "tech"
+purpose = "Technical stack"
+criteria = ["Architecture: System design"]
+include_in_prompt = false
+"#,
+    )
+    .unwrap();
+    fs::write(steering_dir.join("product.md"), "Product content").unwrap();
+    fs::write(steering_dir.join("tech.md"), "Tech content").unwrap();
+
+    let config = config_repo.load_steering_config().unwrap();
+    assert!(config.types[0].include_in_prompt);
+    assert!(!config.types[1].include_in_prompt);
+
+    let steerings = steering_repo
+        .load_steering_files(&config.prompt_types())
+        .unwrap();
+    assert_eq!(steerings.len(), 1);
+    assert_eq!(steerings[0].steering_type.name, "product");
+}
+
+#[test]
+fn test_diff_and_restore_deleted_steering_file() {
+    let temp_dir = tempdir().unwrap();
+    let path_manager = PathManager::new(temp_dir.path().to_path_buf());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+    let steering_repo = SteeringRepository::new(path_manager.clone());
+    let spec_repo = SpecRepository::new(path_manager.clone());
+    initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+    let steering_dir = temp_dir.path().join(".kiro/steering");
+    fs::write(steering_dir.join("tech.md"), "# Tech\n\n- Rust\n").unwrap();
+    backup_steering(&config_repo, &steering_repo).unwrap();
+    fs::remove_file(steering_dir.join("tech.md")).unwrap();
+
+    let (backup, diffs) = diff_steering_backup(&steering_repo, "latest").unwrap();
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].change, SteeringFileChange::Removed);
+    assert!(diffs[0].diff.contains("-- Rust"));
+
+    let report = restore_steering_backup(&config_repo, &steering_repo, &backup, None).unwrap();
+    assert!(report.safety_backup.is_some());
+    assert_eq!(
+        fs::read_to_string(steering_dir.join("tech.md")).unwrap(),
+        "# Tech\n\n- Rust\n"
+    );
+}
+
+#[test]
+fn test_initialize_project_scaffolds_configured_types() {
+    let temp_dir = tempdir().unwrap();
+    let path_manager = PathManager::new(temp_dir.path().to_path_buf());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+    let steering_repo = SteeringRepository::new(path_manager.clone());
+    let spec_repo = SpecRepository::new(path_manager.clone());
+
+    let kiro_dir = temp_dir.path().join(".kiro");
+    fs::create_dir_all(kiro_dir.join("steering")).unwrap();
+    fs::write(
+        kiro_dir.join("config.toml"),
+        r#"[[steering.types]]
+name = "tech"
+purpose = "Technical stack"
+criteria = ["Architecture: Overall design"]
+
+[[steering.types]]
+name = "rust-dev"
+purpose = "Rust conventions for this repository"
+criteria = ["Error Handling: thiserror vs anyhow", "Testing: Where tests live"]
+"#,
+    )
+    .unwrap();
+    fs::write(
+        kiro_dir.join("steering").join("tech.md"),
+        "# Tech\n\nMine\n",
+    )
+    .unwrap();
+
+    let report = initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+    let created: Vec<_> = report
+        .steering_files
+        .iter()
+        .map(|scaffold| (scaffold.file.display().to_string(), scaffold.created))
+        .collect();
+    assert_eq!(
+        created,
+        vec![
+            ("tech.md".to_string(), false),
+            ("rust-dev.md".to_string(), true)
+        ]
+    );
+    assert_eq!(
+        fs::read_to_string(kiro_dir.join("steering").join("tech.md")).unwrap(),
+        "# Tech\n\nMine\n"
+    );
+    assert_eq!(
+        fs::read_to_string(kiro_dir.join("steering").join("rust-dev.md")).unwrap(),
+        "# rust-dev\n\nRust conventions for this repository\n\n## Error Handling\n\n## Testing\n\n"
+    );
+}
+
+#[test]
+fn test_initialize_project_rejects_steering_type_names_outside_steering_dir() {
+    let temp_dir = tempdir().unwrap();
+    let project_dir = temp_dir.path().to_path_buf();
+    let path_manager = PathManager::new(project_dir.clone());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+    let steering_repo = SteeringRepository::new(path_manager.clone());
+    let spec_repo = SpecRepository::new(path_manager.clone());
+
+    let kiro_dir = project_dir.join(".kiro");
+    fs::create_dir_all(kiro_dir.join("steering")).unwrap();
+    fs::write(
+        kiro_dir.join("config.toml"),
+        r#"[[steering.types]]
+name = "../../escaped"
+purpose = "Not a steering file"
+criteria = []
+"#,
+    )
+    .unwrap();
+
+    let result = initialize_project(&config_repo, &spec_repo, &steering_repo);
+
+    assert!(matches!(
+        result,
+        Err(ApplicationError::InvalidSteeringType(ref message)) if message.contains("../../escaped")
+    ));
+    assert!(!project_dir.join("escaped.md").exists());
+
+    let config = config_repo.load_steering_config().unwrap();
+    assert!(matches!(
+        steering_repo.load_steering_files(&config),
+        Err(ApplicationError::InvalidSteeringType(_))
+    ));
+}
+
+#[test]
+fn test_initialize_project_accepts_existing_non_kebab_case_type_names() {
+    let temp_dir = tempdir().unwrap();
+    let path_manager = PathManager::new(temp_dir.path().to_path_buf());
+    let config_repo = ConfigRepository::with_user_config(path_manager.clone(), None);
+    let steering_repo = SteeringRepository::new(path_manager.clone());
+    let spec_repo = SpecRepository::new(path_manager.clone());
+
+    let kiro_dir = temp_dir.path().join(".kiro");
+    fs::create_dir_all(&kiro_dir).unwrap();
+    fs::write(
+        kiro_dir.join("config.toml"),
+        r#"[[steering.types]]
+name = "api_docs"
+purpose = "API documentation"
+criteria = []
+
+[[steering.types]]
+name = "Tech"
+purpose = "Technical stack"
+criteria = []
+"#,
+    )
+    .unwrap();
+
+    initialize_project(&config_repo, &spec_repo, &steering_repo).unwrap();
+
+    let steering_dir = kiro_dir.join("steering");
+    assert!(steering_dir.join("api_docs.md").is_file());
+    assert!(steering_dir.join("Tech.md").is_file());
+    let config = config_repo.load_steering_config().unwrap();
+    assert_eq!(steering_repo.load_steering_files(&config).unwrap().len(), 2);
+}
+
+#[test]
+fn test_deploy_slash_commands_protects_user_files() {
+    use hail_mary::domain::value_objects::deployment::DeploymentWarning;
+    use hail_mary::infrastructure::embedded_resources::EmbeddedSlashCommands;
+    use std::path::PathBuf;
+
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let steering_repo = SteeringRepository::new(PathManager::new(root.to_path_buf()));
+
+    // A pre-manifest deployment by an older release, plus files the team wrote by hand
+    let hm_dir = root.join(".claude/commands/hm");
+    fs::create_dir_all(&hm_dir).unwrap();
+    let shipped = |file_name: &str| {
+        EmbeddedSlashCommands::get_all()
+            .into_iter()
+            .find(|(name, _)| *name == file_name)
+            .unwrap()
+            .1
+    };
+    fs::write(
+        hm_dir.join("steering-remember.md"),
+        shipped("steering-remember.md"),
+    )
+    .unwrap();
+    fs::write(hm_dir.join("steering.md"), "steering as shipped by 0.0.9").unwrap();
+    fs::write(hm_dir.join("ours.md"), "our command").unwrap();
+    fs::create_dir_all(root.join(".claude/agents")).unwrap();
+    fs::write(root.join(".claude/agents/reviewer.md"), "our subagent").unwrap();
+    fs::create_dir_all(root.join(".claude/commands/spec")).unwrap();
+
+    let report = steering_repo.deploy_claude_resources().unwrap();
+
+    // Shipped commands are upgraded whatever release wrote them; other files survive
+    assert_eq!(
+        fs::read_to_string(hm_dir.join("steering.md")).unwrap(),
+        shipped("steering.md")
+    );
+    assert_eq!(
+        fs::read_to_string(hm_dir.join("steering-remember.md")).unwrap(),
+        shipped("steering-remember.md")
+    );
+    assert_eq!(
+        fs::read_to_string(hm_dir.join("ours.md")).unwrap(),
+        "our command"
+    );
+    assert_eq!(
+        fs::read_to_string(root.join(".claude/agents/reviewer.md")).unwrap(),
+        "our subagent"
+    );
+    assert!(root.join(".claude/commands/spec").is_dir());
+    assert_eq!(
+        report.warnings,
+        vec![DeploymentWarning::Legacy(PathBuf::from(
+            ".claude/commands/spec"
+        ))]
+    );
+    assert!(root.join(".kiro/deployed-files.json").exists());
+
+    // A local edit is kept and reported instead of being overwritten
+    fs::write(hm_dir.join("steering.md"), "our tweaks").unwrap();
+
+    let report = steering_repo.deploy_claude_resources().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(hm_dir.join("steering.md")).unwrap(),
+        "our tweaks"
+    );
+    assert!(hm_dir.join("ours.md").exists());
+    assert!(report.changes.is_empty());
+    assert!(
+        report
+            .warnings
+            .contains(&DeploymentWarning::LocallyModified(PathBuf::from(
+                ".claude/commands/hm/steering.md"
+            )))
+    );
+
+    // Deleting the edited file brings the shipped version back
+    fs::remove_file(hm_dir.join("steering.md")).unwrap();
+    steering_repo.deploy_claude_resources().unwrap();
+    assert_eq!(
+        fs::read_to_string(hm_dir.join("steering.md")).unwrap(),
+        shipped("steering.md")
+    );
+}
+
+#[test]
+fn test_deploy_slash_commands_applies_kiro_commands_overlay() {
+    use hail_mary::domain::value_objects::slash_command::SlashCommandSource;
+
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let steering_repo = SteeringRepository::new(PathManager::new(root.to_path_buf()));
+    let hm_dir = root.join(".claude/commands/hm");
+
+    let commands_dir = root.join(".kiro/commands");
+    fs::create_dir_all(commands_dir.join("drafts")).unwrap();
+    fs::write(commands_dir.join("steering.md"), "our steering").unwrap();
+    fs::write(commands_dir.join("release.md"), "cut a release").unwrap();
+    fs::write(commands_dir.join(".gitkeep"), "").unwrap();
+    fs::write(commands_dir.join("notes.txt"), "not a command").unwrap();
+
+    let commands = steering_repo.list_slash_commands().unwrap();
+    let sources: Vec<(&str, SlashCommandSource)> = commands
+        .iter()
+        .map(|command| (command.file_name.as_str(), command.source))
+        .collect();
+    assert_eq!(
+        sources,
+        vec![
+            ("release.md", SlashCommandSource::Overlay),
+            ("steering-remember.md", SlashCommandSource::Embedded),
+            ("steering.md", SlashCommandSource::Overlay),
+        ]
+    );
+
+    steering_repo.deploy_claude_resources().unwrap();
+    assert_eq!(
+        fs::read_to_string(hm_dir.join("steering.md")).unwrap(),
+        "our steering"
+    );
+    assert_eq!(
+        fs::read_to_string(hm_dir.join("release.md")).unwrap(),
+        "cut a release"
+    );
+    assert!(hm_dir.join("steering-remember.md").exists());
+    assert!(!hm_dir.join("notes.txt").exists());
+
+    // Dropping overlay files restores the embedded command and retires the extra one
+    fs::remove_file(commands_dir.join("steering.md")).unwrap();
+    fs::remove_file(commands_dir.join("release.md")).unwrap();
+    steering_repo.deploy_claude_resources().unwrap();
+    assert_ne!(
+        fs::read_to_string(hm_dir.join("steering.md")).unwrap(),
+        "our steering"
+    );
+    assert!(!hm_dir.join("release.md").exists());
+}
+
+#[test]
+fn test_deploy_claude_resources_installs_and_upgrades_skills() {
+    use hail_mary::domain::value_objects::deployment::{
+        DeploymentManifest, DeploymentWarning, SkillUpgrade,
+    };
+
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let steering_repo = SteeringRepository::new(PathManager::new(root.to_path_buf()));
+    let skill_dir = root.join(".claude/skills/prd");
+    let manifest_path = root.join(".kiro/deployed-files.json");
+
+    let report = steering_repo.deploy_claude_resources().unwrap();
+
+    let skill = fs::read_to_string(skill_dir.join("SKILL.md")).unwrap();
+    assert!(skill.contains("name: prd"));
+    assert!(skill_dir.join("references/plan-story.md").exists());
+    assert!(report.upgrades.is_empty());
+    let manifest: DeploymentManifest =
+        serde_json::from_str(&fs::read_to_string(&manifest_path).unwrap()).unwrap();
+    let current = manifest.skills[0].version.clone();
+    assert_eq!(manifest.skills[0].name, "prd");
+
+    // Pretend an older release deployed a different SKILL.md
+    let mut older = manifest.clone();
+    older.skills[0].version = "0.9.0".to_string();
+    for file in &mut older.files {
+        if file.path.ends_with("prd/SKILL.md") {
+            file.sha256 = content_hash(b"old skill");
+        }
+    }
+    fs::write(&manifest_path, serde_json::to_string(&older).unwrap()).unwrap();
+    fs::write(skill_dir.join("SKILL.md"), "old skill").unwrap();
+
+    let report = steering_repo.deploy_claude_resources().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+        skill
+    );
+    assert_eq!(
+        report.upgrades,
+        vec![SkillUpgrade {
+            name: "prd".to_string(),
+            from: "0.9.0".to_string(),
+            to: current.clone(),
+        }]
+    );
+
+    // With local edits the old version stays recorded and the upgrade is reported as pending
+    fs::write(&manifest_path, serde_json::to_string(&older).unwrap()).unwrap();
+    fs::write(skill_dir.join("SKILL.md"), "our own prd skill").unwrap();
+
+    let report = steering_repo.deploy_claude_resources().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+        "our own prd skill"
+    );
+    assert!(report.upgrades.is_empty());
+    assert!(
+        report
+            .warnings
+            .contains(&DeploymentWarning::SkillNotUpgraded {
+                name: "prd".to_string(),
+                deployed: "0.9.0".to_string(),
+                available: current,
+            })
+    );
+}
+
+#[test]
+fn test_deploy_claude_resources_leaves_users_own_skill_alone() {
+    use hail_mary::domain::value_objects::deployment::{DeploymentManifest, DeploymentWarning};
+
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+    let steering_repo = SteeringRepository::new(PathManager::new(root.to_path_buf()));
+    let skill_dir = root.join(".claude/skills/prd");
+    fs::create_dir_all(&skill_dir).unwrap();
+    fs::write(skill_dir.join("SKILL.md"), "our own prd skill").unwrap();
+
+    let report = steering_repo.deploy_claude_resources().unwrap();
+
+    assert_eq!(
+        fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+        "our own prd skill"
+    );
+    assert!(!skill_dir.join("references").exists());
+    assert!(
+        report
+            .warnings
+            .contains(&DeploymentWarning::NotManaged(std::path::PathBuf::from(
+                ".claude/skills/prd/SKILL.md"
+            )))
+    );
+    let manifest: DeploymentManifest =
+        serde_json::from_str(&fs::read_to_string(root.join(".kiro/deployed-files.json")).unwrap())
+            .unwrap();
+    assert!(manifest.skills.is_empty());
+    assert!(
+        manifest
+            .files
+            .iter()
+            .all(|file| !file.path.starts_with(".claude/skills/prd"))
+    );
+}
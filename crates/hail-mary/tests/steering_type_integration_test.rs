@@ -0,0 +1,224 @@
+use hail_mary::application::errors::ApplicationError;
+use hail_mary::application::repositories::ConfigRepositoryInterface;
+use hail_mary::application::use_cases::{
+    SteeringTypeEdit, add_steering_type, edit_steering_type, remove_steering_type,
+};
+use hail_mary::infrastructure::filesystem::path_manager::PathManager;
+use hail_mary::infrastructure::repositories::{
+    config::ConfigRepository, steering::SteeringRepository,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::{TempDir, tempdir};
+
+const CONFIG: &str = r#"# Project settings for hail-mary
+[spec]
+lang = "en" # English specs
+
+# Keep steering short
+[[steering.types]]
+name = "tech"
+purpose = "Technical stack"
+criteria = [
+    "Architecture: High-level system design",   # most important
+    "Common Commands: Frequently used commands",
+]
+allowed_operations = ["refresh"]
+
+[steering.backup]
+max = 5
+"#;
+
+struct Project {
+    _temp_dir: TempDir,
+    root: PathBuf,
+    config_repo: ConfigRepository,
+    steering_repo: SteeringRepository,
+}
+
+impl Project {
+    fn new() -> Self {
+        let temp_dir = tempdir().unwrap();
+        let root = temp_dir.path().to_path_buf();
+        fs::create_dir_all(root.join(".kiro/steering")).unwrap();
+        fs::write(root.join(".kiro/config.toml"), CONFIG).unwrap();
+        fs::write(root.join(".kiro/steering/tech.md"), "# Tech\n\nnotes\n").unwrap();
+
+        let path_manager = PathManager::new(root.clone());
+        Self {
+            _temp_dir: temp_dir,
+            config_repo: ConfigRepository::with_user_config(path_manager.clone(), None),
+            steering_repo: SteeringRepository::new(path_manager),
+            root,
+        }
+    }
+
+    fn config(&self) -> String {
+        fs::read_to_string(self.root.join(".kiro/config.toml")).unwrap()
+    }
+
+    fn steering_file(&self, name: &str) -> PathBuf {
+        self.root.join(".kiro/steering").join(name)
+    }
+}
+
+fn assert_exists(path: &Path, exists: bool) {
+    assert_eq!(path.exists(), exists, "{}", path.display());
+}
+
+#[test]
+fn test_add_steering_type_appends_without_reformatting() {
+    let project = Project::new();
+
+    add_steering_type(
+        &project.config_repo,
+        &project.steering_repo,
+        "security",
+        "Security policies",
+        vec!["Threat Model: Assets and attackers".to_string()],
+        false,
+    )
+    .unwrap();
+
+    // The new entry joins its siblings; everything else is untouched
+    let added = r#"[[steering.types]]
+name = "security"
+purpose = "Security policies"
+criteria = [
+    "Threat Model: Assets and attackers",
+]
+allowed_operations = []
+include_in_prompt = false
+
+"#;
+    assert_eq!(
+        project.config(),
+        CONFIG.replace("[steering.backup]", &format!("{}[steering.backup]", added))
+    );
+
+    let types = project.config_repo.load_steering_config().unwrap().types;
+    assert_eq!(types.len(), 2);
+    assert!(!types[1].include_in_prompt);
+    assert_exists(&project.steering_file("security.md"), true);
+}
+
+#[test]
+fn test_edit_steering_type_only_touches_changed_keys() {
+    let project = Project::new();
+
+    edit_steering_type(
+        &project.config_repo,
+        &project.steering_repo,
+        "tech",
+        SteeringTypeEdit {
+            name: Some("stack".to_string()),
+            ..SteeringTypeEdit::default()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        project.config(),
+        CONFIG.replace(r#"name = "tech""#, r#"name = "stack""#)
+    );
+    assert_exists(&project.steering_file("tech.md"), false);
+    assert_eq!(
+        fs::read_to_string(project.steering_file("stack.md")).unwrap(),
+        "# Tech\n\nnotes\n"
+    );
+}
+
+#[test]
+fn test_remove_steering_type_keeps_other_sections() {
+    let project = Project::new();
+
+    let removal =
+        remove_steering_type(&project.config_repo, &project.steering_repo, "tech").unwrap();
+
+    assert!(removal.file_removed);
+    assert!(removal.backup.is_some());
+    assert_exists(&project.steering_file("tech.md"), false);
+
+    let config = project.config();
+    assert!(!config.contains("Architecture"));
+    assert!(
+        config.starts_with(
+            "# Project settings for hail-mary\n[spec]\nlang = \"en\" # English specs\n"
+        )
+    );
+    assert!(config.contains("[steering.backup]\nmax = 5\n"));
+
+    let missing = remove_steering_type(&project.config_repo, &project.steering_repo, "tech");
+    assert!(matches!(
+        missing,
+        Err(ApplicationError::SteeringTypeNotFound(_))
+    ));
+}
+
+#[test]
+fn test_user_level_steering_types_are_edited_in_project_and_not_removed() {
+    let project = Project::new();
+    let user_path = project.root.join("xdg/hail-mary/config.toml");
+    fs::create_dir_all(user_path.parent().unwrap()).unwrap();
+    fs::write(
+        &user_path,
+        "[[steering.types]]\nname = \"security\"\npurpose = \"Security policies\"\ncriteria = []\n",
+    )
+    .unwrap();
+    fs::write(project.steering_file("security.md"), "# Security\n").unwrap();
+    let config_repo = ConfigRepository::with_user_config(
+        PathManager::new(project.root.clone()),
+        Some(user_path.clone()),
+    );
+
+    edit_steering_type(
+        &config_repo,
+        &project.steering_repo,
+        "security",
+        SteeringTypeEdit {
+            purpose: Some("Threats and mitigations".to_string()),
+            ..SteeringTypeEdit::default()
+        },
+    )
+    .unwrap();
+
+    // The edited copy lives in the project config; the user file is untouched
+    assert!(project.config().contains("name = \"security\""));
+    assert!(project.config().contains("Threats and mitigations"));
+    let security = config_repo
+        .load_steering_config()
+        .unwrap()
+        .types
+        .into_iter()
+        .find(|t| t.name == "security")
+        .unwrap();
+    assert_eq!(security.purpose, "Threats and mitigations");
+
+    let renamed = edit_steering_type(
+        &config_repo,
+        &project.steering_repo,
+        "security",
+        SteeringTypeEdit {
+            name: Some("safety".to_string()),
+            ..SteeringTypeEdit::default()
+        },
+    );
+    assert!(matches!(
+        renamed,
+        Err(ApplicationError::SteeringTypeInUserConfig(..))
+    ));
+
+    let removal = remove_steering_type(&config_repo, &project.steering_repo, "security");
+    match removal {
+        Err(error @ ApplicationError::SteeringTypeInUserConfig(..)) => {
+            assert!(error.to_string().contains(&format!(
+                "defined in user config at {}",
+                user_path.display()
+            )));
+        }
+        other => panic!("expected a refusal, got {:?}", other),
+    }
+    // Refused before taking a snapshot or touching the file
+    assert_exists(&project.root.join(".kiro/steering/backup"), false);
+    assert_exists(&project.steering_file("security.md"), true);
+}